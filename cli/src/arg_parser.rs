@@ -1,5 +1,6 @@
 extern crate clap;
 use clap::{command, AppSettings, Arg, ArgMatches, SubCommand};
+use chrono::{DateTime, Local, NaiveDate, TimeZone, Utc};
 
 use crate::Error;
 use crate::command_runner::Command;
@@ -12,6 +13,16 @@ pub fn create_app() -> clap::Command<'static> {
         .version("0.1.0")
         .about("can be used to manage every part of your productive life!")
         .setting(AppSettings::SubcommandRequiredElseHelp)
+        .arg(
+            Arg::with_name("storage")
+                .long("storage")
+                .global(true)
+                .takes_value(true)
+                .possible_values(["json", "sqlite", "journal"])
+                .default_value("json"),
+        )
+        .arg(Arg::with_name("file").long("file").global(true).takes_value(true))
+        .arg(Arg::with_name("workspace").long("workspace").global(true).takes_value(true))
         .subcommand(SubCommand::with_name("list").alias("lt"))
         .subcommand(SubCommand::with_name("extended_list").alias("el"))
         .subcommand(
@@ -41,6 +52,196 @@ pub fn create_app() -> clap::Command<'static> {
                 .arg(Arg::with_name("index").required(true))
                 .arg(Arg::with_name("new_priority").required(true)),
         )
+        .subcommand(
+            SubCommand::with_name("estimate")
+                .arg(Arg::with_name("index").required(true))
+                .arg(Arg::with_name("duration").required(true)),
+        )
+        .subcommand(
+            SubCommand::with_name("due")
+                .arg(Arg::with_name("index").required(true))
+                .arg(Arg::with_name("due_date").required(true)),
+        )
+        .subcommand(
+            SubCommand::with_name("move_subtree")
+                .arg(Arg::with_name("index").required(true))
+                .arg(Arg::with_name("new_parent")),
+        )
+        .subcommand(
+            SubCommand::with_name("copy_subtree")
+                .arg(Arg::with_name("index").required(true))
+                .arg(Arg::with_name("new_parent")),
+        )
+        .subcommand(
+            SubCommand::with_name("remove_subtree")
+                .arg(Arg::with_name("index").required(true)),
+        )
+        .subcommand(
+            SubCommand::with_name("move_to_workspace")
+                .alias("move-to-workspace")
+                .arg(Arg::with_name("index").required(true))
+                .arg(Arg::with_name("target").required(true))
+                .arg(Arg::with_name("new_parent")),
+        )
+        .subcommand(
+            SubCommand::with_name("workspace")
+                .alias("ws")
+                .setting(AppSettings::SubcommandRequiredElseHelp)
+                .subcommand(
+                    SubCommand::with_name("create").arg(Arg::with_name("name").required(true)),
+                )
+                .subcommand(
+                    SubCommand::with_name("switch").arg(Arg::with_name("name").required(true)),
+                )
+                .subcommand(SubCommand::with_name("list").alias("lt"))
+                .subcommand(
+                    SubCommand::with_name("delete").arg(Arg::with_name("name").required(true)),
+                )
+                .subcommand(
+                    SubCommand::with_name("rename")
+                        .arg(Arg::with_name("name").required(true))
+                        .arg(Arg::with_name("new_name").required(true)),
+                ),
+        )
+        .subcommand(SubCommand::with_name("critical_path").alias("cp"))
+        .subcommand(
+            SubCommand::with_name("show")
+                .arg(Arg::with_name("index").required(true)),
+        )
+        .subcommand(
+            SubCommand::with_name("create_variant")
+                .arg(Arg::with_name("name").required(true))
+                .arg(Arg::with_name("directionality").required(true))
+                .arg(Arg::with_name("inverse").long("inverse").takes_value(true))
+                .arg(
+                    Arg::with_name("alias")
+                        .long("alias")
+                        .takes_value(true)
+                        .multiple_occurrences(true),
+                ),
+        )
+        .subcommand(SubCommand::with_name("list_variants").alias("lv"))
+        .subcommand(
+            SubCommand::with_name("constrain")
+                .arg(Arg::with_name("constraint").required(true))
+                .arg(
+                    Arg::with_name("state")
+                        .required(true)
+                        .possible_values(["on", "off"]),
+                ),
+        )
+        .subcommand(SubCommand::with_name("validate"))
+        .subcommand(
+            SubCommand::with_name("rollup")
+                .arg(Arg::with_name("option").required(true))
+                .arg(
+                    Arg::with_name("state")
+                        .required(true)
+                        .possible_values(["on", "off"]),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("relationship")
+                .alias("rel")
+                .setting(AppSettings::SubcommandRequiredElseHelp)
+                .subcommand(SubCommand::with_name("list").alias("lt"))
+                .subcommand(
+                    SubCommand::with_name("show")
+                        .arg(Arg::with_name("index").required(true)),
+                )
+                .subcommand(
+                    SubCommand::with_name("remove")
+                        .arg(Arg::with_name("index").required(true)),
+                )
+                .subcommand(
+                    SubCommand::with_name("change_variant")
+                        .alias("change-variant")
+                        .arg(Arg::with_name("index").required(true))
+                        .arg(Arg::with_name("variant").required(true)),
+                )
+                .subcommand(
+                    SubCommand::with_name("retarget")
+                        .arg(Arg::with_name("index").required(true))
+                        .arg(
+                            Arg::with_name("participant")
+                                .required(true)
+                                .possible_values(["1", "2", "p1", "p2", "participant_1", "participant_2"]),
+                        )
+                        .arg(Arg::with_name("action").required(true)),
+                )
+                .subcommand(
+                    SubCommand::with_name("filter")
+                        .arg(Arg::with_name("variant").long("variant").takes_value(true))
+                        .arg(Arg::with_name("participant").long("participant").takes_value(true))
+                        .arg(
+                            Arg::with_name("list")
+                                .long("list")
+                                .takes_value(true)
+                                .possible_values(["p1", "p2", "either"])
+                                .default_value("either"),
+                        ),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("restore_backup")
+                .alias("restore-backup")
+                .arg(Arg::with_name("generation").default_value("1")),
+        )
+        .subcommand(SubCommand::with_name("migrate_to_sqlite").alias("migrate-to-sqlite"))
+        .subcommand(SubCommand::with_name("compact_journal").alias("compact-journal"))
+        .subcommand(
+            SubCommand::with_name("history")
+                .setting(AppSettings::SubcommandRequiredElseHelp)
+                .subcommand(
+                    SubCommand::with_name("show").arg(Arg::with_name("time").required(true)),
+                )
+                .subcommand(SubCommand::with_name("log")),
+        )
+        .subcommand(
+            SubCommand::with_name("diff")
+                .arg(Arg::with_name("from").required(true))
+                .arg(Arg::with_name("to")),
+        )
+        .subcommand(SubCommand::with_name("undo"))
+        .subcommand(SubCommand::with_name("redo"))
+        .subcommand(
+            SubCommand::with_name("undo_depth")
+                .alias("undo-depth")
+                .arg(Arg::with_name("depth").required(true)),
+        )
+        .subcommand(
+            SubCommand::with_name("import")
+                .arg(Arg::with_name("input").value_name("FILE").required(true))
+                .arg(Arg::with_name("relationships").long("relationships"))
+                .arg(
+                    Arg::with_name("format")
+                        .long("format")
+                        .takes_value(true)
+                        .possible_values(["csv", "todo_txt", "ical", "markdown"])
+                        .default_value("csv"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("export")
+                .arg(
+                    Arg::with_name("format")
+                        .long("format")
+                        .takes_value(true)
+                        .possible_values([
+                            "dot",
+                            "mermaid",
+                            "gantt",
+                            "csv",
+                            "relationship_csv",
+                            "todo_txt",
+                            "ical",
+                            "markdown",
+                        ])
+                        .default_value("dot"),
+                )
+                .arg(Arg::with_name("root").long("root").takes_value(true))
+                .arg(Arg::with_name("start").long("start").takes_value(true)),
+        )
 }
 
 pub trait ArgumentParsing {
@@ -48,6 +249,31 @@ pub trait ArgumentParsing {
     fn parse_index_for_subcommand(&self, subcommand_name: String) -> Result<usize, Box<dyn Error>>;
     fn parse_desired_name(&self, subcommand_name: String) -> Option<String>;
     fn parse_desired_priority(&self, subcommand_name: String) -> String;
+    fn parse_desired_estimate(&self, subcommand_name: String) -> Result<u32, Box<dyn Error>>;
+    fn parse_desired_due_date(&self, subcommand_name: String) -> Result<NaiveDate, Box<dyn Error>>;
+    fn parse_new_parent(&self, subcommand_name: String) -> Result<Option<usize>, Box<dyn Error>>;
+    fn parse_relationship_command(&self) -> Result<Command, Box<dyn Error>>;
+    fn parse_history_command(&self) -> Result<Command, Box<dyn Error>>;
+    fn parse_workspace_command(&self) -> Result<Command, Box<dyn Error>>;
+    fn parse_workspace_name(&self, subcommand_name: &str, argument_name: &str) -> Result<String, Box<dyn Error>>;
+}
+
+// bare dates mean the end of that day in local time
+pub fn parse_point_in_time(value: &str) -> Result<DateTime<Utc>, Box<dyn Error>> {
+    if let Ok(time) = DateTime::parse_from_rfc3339(value) {
+        return Ok(time.with_timezone(&Utc));
+    }
+
+    let date = value
+        .parse::<NaiveDate>()
+        .map_err(|_| format!("{} is not a date (YYYY-MM-DD) or an RFC 3339 time", value))?;
+    let end_of_day = date.and_hms_opt(23, 59, 59).ok_or("invalid time of day")?;
+
+    Ok(Local
+        .from_local_datetime(&end_of_day)
+        .earliest()
+        .ok_or_else(|| format!("{} does not exist in the local time zone", value))?
+        .with_timezone(&Utc))
 }
 
 impl ArgumentParsing for ArgMatches {
@@ -89,6 +315,136 @@ impl ArgumentParsing for ArgMatches {
                 index: self.parse_index_for_subcommand("reprioritize".to_string())?,
                 new_priority: self.parse_desired_priority("reprioritize".to_string()),
             }),
+            Some("estimate") => Ok(Command::Estimate {
+                index: self.parse_index_for_subcommand("estimate".to_string())?,
+                estimate: self.parse_desired_estimate("estimate".to_string())?,
+            }),
+            Some("due") => Ok(Command::Due {
+                index: self.parse_index_for_subcommand("due".to_string())?,
+                due_date: self.parse_desired_due_date("due".to_string())?,
+            }),
+            Some("move_subtree") => Ok(Command::MoveSubtree {
+                index: self.parse_index_for_subcommand("move_subtree".to_string())?,
+                new_parent: self.parse_new_parent("move_subtree".to_string())?,
+            }),
+            Some("copy_subtree") => Ok(Command::CopySubtree {
+                index: self.parse_index_for_subcommand("copy_subtree".to_string())?,
+                new_parent: self.parse_new_parent("copy_subtree".to_string())?,
+            }),
+            Some("remove_subtree") => Ok(Command::RemoveSubtree(
+                self.parse_index_for_subcommand("remove_subtree".to_string())?,
+            )),
+            Some("move_to_workspace") => Ok(Command::MoveToWorkspace {
+                index: self.parse_index_for_subcommand("move_to_workspace".to_string())?,
+                target: self.parse_workspace_name("move_to_workspace", "target")?,
+                new_parent: self.parse_new_parent("move_to_workspace".to_string())?,
+            }),
+            Some("workspace") => self
+                .subcommand_matches("workspace")
+                .ok_or("this is not one of the subcommands of the interface")?
+                .parse_workspace_command(),
+            Some("critical_path") => Ok(Command::CriticalPath),
+            Some("create_variant") => {
+                let arg_matches = self
+                    .subcommand_matches("create_variant")
+                    .ok_or("this is not one of the subcommands of the interface")?;
+                Ok(Command::CreateVariant {
+                    name: arg_matches.value_of("name").unwrap().to_string(),
+                    directionality: arg_matches.value_of("directionality").unwrap().to_string(),
+                    inverse_label: arg_matches.value_of("inverse").map(|label| label.to_string()),
+                    aliases: arg_matches
+                        .values_of("alias")
+                        .map(|aliases| aliases.map(|alias| alias.to_string()).collect())
+                        .unwrap_or_default(),
+                })
+            }
+            Some("list_variants") => Ok(Command::ListVariants),
+            Some("constrain") => {
+                let arg_matches = self
+                    .subcommand_matches("constrain")
+                    .ok_or("this is not one of the subcommands of the interface")?;
+                Ok(Command::Constrain {
+                    constraint: arg_matches.value_of("constraint").unwrap().to_string(),
+                    enabled: arg_matches.value_of("state").unwrap() == "on",
+                })
+            }
+            Some("validate") => Ok(Command::Validate),
+            Some("rollup") => {
+                let arg_matches = self
+                    .subcommand_matches("rollup")
+                    .ok_or("this is not one of the subcommands of the interface")?;
+                Ok(Command::Rollup {
+                    option: arg_matches.value_of("option").unwrap().to_string(),
+                    enabled: arg_matches.value_of("state").unwrap() == "on",
+                })
+            }
+            Some("show") => Ok(Command::Show(
+                self.parse_index_for_subcommand("show".to_string())?,
+            )),
+            Some("relationship") => self
+                .subcommand_matches("relationship")
+                .ok_or("this is not one of the subcommands of the interface")?
+                .parse_relationship_command(),
+            Some("restore_backup") => Ok(Command::RestoreBackup(
+                self.subcommand_matches("restore_backup")
+                    .ok_or("this is not one of the subcommands of the interface")?
+                    .value_of("generation")
+                    .ok_or("incompatible value for subcommand")?
+                    .parse::<usize>()?,
+            )),
+            Some("migrate_to_sqlite") => Ok(Command::MigrateToSqlite),
+            Some("compact_journal") => Ok(Command::CompactJournal),
+            Some("history") => self
+                .subcommand_matches("history")
+                .ok_or("this is not one of the subcommands of the interface")?
+                .parse_history_command(),
+            Some("diff") => {
+                let arg_matches = self
+                    .subcommand_matches("diff")
+                    .ok_or("this is not one of the subcommands of the interface")?;
+                Ok(Command::Diff {
+                    from: parse_point_in_time(arg_matches.value_of("from").unwrap())?,
+                    to: match arg_matches.value_of("to") {
+                        Some(to) => Some(parse_point_in_time(to)?),
+                        None => None,
+                    },
+                })
+            }
+            Some("undo") => Ok(Command::Undo),
+            Some("redo") => Ok(Command::Redo),
+            Some("undo_depth") => Ok(Command::UndoDepth(
+                self.subcommand_matches("undo_depth")
+                    .ok_or("this is not one of the subcommands of the interface")?
+                    .value_of("depth")
+                    .ok_or("incompatible value for subcommand")?
+                    .parse::<usize>()?,
+            )),
+            Some("import") => {
+                let arg_matches = self
+                    .subcommand_matches("import")
+                    .ok_or("this is not one of the subcommands of the interface")?;
+                Ok(Command::Import {
+                    file: arg_matches.value_of("input").unwrap().to_string(),
+                    format: arg_matches.value_of("format").unwrap().to_string(),
+                    relationships: arg_matches.is_present("relationships"),
+                })
+            }
+            Some("export") => {
+                let arg_matches = self
+                    .subcommand_matches("export")
+                    .ok_or("this is not one of the subcommands of the interface")?;
+                Ok(Command::Export {
+                    format: arg_matches.value_of("format").unwrap().to_string(),
+                    root: match arg_matches.value_of("root") {
+                        Some(root) => Some(root.parse::<usize>()?),
+                        None => None,
+                    },
+                    start: match arg_matches.value_of("start") {
+                        Some(start) => Some(start.parse::<NaiveDate>()?),
+                        None => None,
+                    },
+                })
+            }
             _ => unreachable!(),
         }
     }
@@ -116,6 +472,116 @@ impl ArgumentParsing for ArgMatches {
             .unwrap()
             .to_string()
     }
+
+    fn parse_desired_estimate(&self, subcommand_name: String) -> Result<u32, Box<dyn Error>> {
+        Ok(self
+            .subcommand_matches(subcommand_name)
+            .ok_or("this is not one of the subcommands of the interface")?
+            .value_of("duration")
+            .ok_or("incompatible value for subcommand")?
+            .parse::<u32>()?)
+    }
+
+    fn parse_desired_due_date(&self, subcommand_name: String) -> Result<NaiveDate, Box<dyn Error>> {
+        Ok(self
+            .subcommand_matches(subcommand_name)
+            .ok_or("this is not one of the subcommands of the interface")?
+            .value_of("due_date")
+            .ok_or("incompatible value for subcommand")?
+            .parse::<NaiveDate>()?)
+    }
+
+    fn parse_relationship_command(&self) -> Result<Command, Box<dyn Error>> {
+        match self.subcommand_name() {
+            Some("list") => Ok(Command::ListRelationships),
+            Some("show") => Ok(Command::ShowRelationship(
+                self.parse_index_for_subcommand("show".to_string())?,
+            )),
+            Some("remove") => Ok(Command::RemoveRelationship(
+                self.parse_index_for_subcommand("remove".to_string())?,
+            )),
+            Some("change_variant") => Ok(Command::ChangeRelationshipVariant {
+                index: self.parse_index_for_subcommand("change_variant".to_string())?,
+                variant: self
+                    .subcommand_matches("change_variant")
+                    .unwrap()
+                    .value_of("variant")
+                    .unwrap()
+                    .to_string(),
+            }),
+            Some("retarget") => {
+                let arg_matches = self
+                    .subcommand_matches("retarget")
+                    .ok_or("this is not one of the subcommands of the interface")?;
+                Ok(Command::RetargetRelationship {
+                    index: self.parse_index_for_subcommand("retarget".to_string())?,
+                    participant: arg_matches.value_of("participant").unwrap().to_string(),
+                    action_index: arg_matches.value_of("action").unwrap().parse::<usize>()?,
+                })
+            }
+            Some("filter") => {
+                let arg_matches = self
+                    .subcommand_matches("filter")
+                    .ok_or("this is not one of the subcommands of the interface")?;
+                Ok(Command::FilterRelationships {
+                    variant: arg_matches.value_of("variant").map(|variant| variant.to_string()),
+                    participant: match arg_matches.value_of("participant") {
+                        Some(participant) => Some(participant.parse::<usize>()?),
+                        None => None,
+                    },
+                    list: arg_matches.value_of("list").unwrap().to_string(),
+                })
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn parse_history_command(&self) -> Result<Command, Box<dyn Error>> {
+        match self.subcommand_name() {
+            Some("show") => Ok(Command::HistoryShow(parse_point_in_time(
+                self.subcommand_matches("show")
+                    .ok_or("this is not one of the subcommands of the interface")?
+                    .value_of("time")
+                    .ok_or("incompatible value for subcommand")?,
+            )?)),
+            Some("log") => Ok(Command::HistoryLog),
+            _ => unreachable!(),
+        }
+    }
+
+    fn parse_workspace_command(&self) -> Result<Command, Box<dyn Error>> {
+        match self.subcommand_name() {
+            Some("create") => Ok(Command::CreateWorkspace(self.parse_workspace_name("create", "name")?)),
+            Some("switch") => Ok(Command::SwitchWorkspace(self.parse_workspace_name("switch", "name")?)),
+            Some("list") => Ok(Command::ListWorkspaces),
+            Some("delete") => Ok(Command::DeleteWorkspace(self.parse_workspace_name("delete", "name")?)),
+            Some("rename") => Ok(Command::RenameWorkspace {
+                name: self.parse_workspace_name("rename", "name")?,
+                new_name: self.parse_workspace_name("rename", "new_name")?,
+            }),
+            _ => unreachable!(),
+        }
+    }
+
+    fn parse_workspace_name(&self, subcommand_name: &str, argument_name: &str) -> Result<String, Box<dyn Error>> {
+        Ok(self
+            .subcommand_matches(subcommand_name)
+            .ok_or("this is not one of the subcommands of the interface")?
+            .value_of(argument_name)
+            .ok_or("incompatible value for subcommand")?
+            .to_string())
+    }
+
+    fn parse_new_parent(&self, subcommand_name: String) -> Result<Option<usize>, Box<dyn Error>> {
+        match self
+            .subcommand_matches(subcommand_name)
+            .ok_or("this is not one of the subcommands of the interface")?
+            .value_of("new_parent")
+        {
+            Some(new_parent) => Ok(Some(new_parent.parse::<usize>()?)),
+            None => Ok(None),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -333,4 +799,599 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn cli_estimate_successful_parse() {
+        let app = create_app();
+        let test_matches = app.get_matches_from(vec!["ClearHeadToDo", "estimate", "1", "3"]);
+
+        let result = test_matches.parse_command().unwrap();
+        assert_eq!(
+            result,
+            Command::Estimate {
+                index: 1,
+                estimate: 3
+            }
+        );
+    }
+
+    #[test]
+    fn cli_estimate_failed_parse() {
+        let app = create_app();
+        let test_matches = app.get_matches_from(vec!["ClearHeadToDo", "estimate", "1", "soon"]);
+
+        let error = test_matches.parse_command().unwrap_err();
+        assert_eq!(error.to_string(), "invalid digit found in string");
+    }
+
+    #[test]
+    fn cli_due_successful_parse() {
+        let app = create_app();
+        let test_matches = app.get_matches_from(vec!["ClearHeadToDo", "due", "0", "2022-10-05"]);
+
+        let result = test_matches.parse_command().unwrap();
+        assert_eq!(
+            result,
+            Command::Due {
+                index: 0,
+                due_date: NaiveDate::from_ymd_opt(2022, 10, 5).unwrap()
+            }
+        );
+    }
+
+    #[test]
+    fn cli_due_failed_parse() {
+        let app = create_app();
+        let test_matches = app.get_matches_from(vec!["ClearHeadToDo", "due", "0", "tomorrow"]);
+
+        let error = test_matches.parse_command().unwrap_err();
+        assert_eq!(error.to_string(), "input contains invalid characters");
+    }
+
+    #[test]
+    fn cli_move_subtree_successful_parse() {
+        let app = create_app();
+        let test_matches = app.get_matches_from(vec!["ClearHeadToDo", "move_subtree", "1", "3"]);
+
+        let result = test_matches.parse_command().unwrap();
+        assert_eq!(
+            result,
+            Command::MoveSubtree {
+                index: 1,
+                new_parent: Some(3)
+            }
+        );
+    }
+
+    #[test]
+    fn cli_copy_subtree_without_parent() {
+        let app = create_app();
+        let test_matches = app.get_matches_from(vec!["ClearHeadToDo", "copy_subtree", "1"]);
+
+        let result = test_matches.parse_command().unwrap();
+        assert_eq!(
+            result,
+            Command::CopySubtree {
+                index: 1,
+                new_parent: None
+            }
+        );
+    }
+
+    #[test]
+    fn cli_remove_subtree_successful_parse() {
+        let app = create_app();
+        let test_matches = app.get_matches_from(vec!["ClearHeadToDo", "remove_subtree", "2"]);
+
+        let result = test_matches.parse_command().unwrap();
+        assert_eq!(result, Command::RemoveSubtree(2));
+    }
+
+    #[test]
+    fn cli_critical_path_successful_parse() {
+        let app = create_app();
+        let test_matches = app.get_matches_from(vec!["ClearHeadToDo", "critical_path"]);
+
+        let result = test_matches.parse_command().unwrap();
+        assert_eq!(result, Command::CriticalPath);
+    }
+
+    #[test]
+    fn cli_critical_path_alias() {
+        let app = create_app();
+        let test_matches = app.get_matches_from(vec!["ClearHeadToDo", "cp"]);
+
+        let result = test_matches.parse_command().unwrap();
+        assert_eq!(result, Command::CriticalPath);
+    }
+
+    #[test]
+    fn cli_show_successful_parse() {
+        let app = create_app();
+        let test_matches = app.get_matches_from(vec!["ClearHeadToDo", "show", "2"]);
+
+        let result = test_matches.parse_command().unwrap();
+        assert_eq!(result, Command::Show(2));
+    }
+
+    #[test]
+    fn cli_create_variant_successful_parse() {
+        let app = create_app();
+        let test_matches = app.get_matches_from(vec![
+            "ClearHeadToDo", "create_variant", "blocks", "directed",
+            "--inverse", "blocked by", "--alias", "blocker", "--alias", "b"]);
+
+        let result = test_matches.parse_command().unwrap();
+        assert_eq!(
+            result,
+            Command::CreateVariant {
+                name: "blocks".to_string(),
+                directionality: "directed".to_string(),
+                inverse_label: Some("blocked by".to_string()),
+                aliases: vec!["blocker".to_string(), "b".to_string()],
+            }
+        );
+    }
+
+    #[test]
+    fn cli_create_variant_without_options() {
+        let app = create_app();
+        let test_matches =
+            app.get_matches_from(vec!["ClearHeadToDo", "create_variant", "duplicates", "undirected"]);
+
+        let result = test_matches.parse_command().unwrap();
+        assert_eq!(
+            result,
+            Command::CreateVariant {
+                name: "duplicates".to_string(),
+                directionality: "undirected".to_string(),
+                inverse_label: None,
+                aliases: Vec::new(),
+            }
+        );
+    }
+
+    #[test]
+    fn cli_list_variants_alias() {
+        let app = create_app();
+        let test_matches = app.get_matches_from(vec!["ClearHeadToDo", "lv"]);
+
+        let result = test_matches.parse_command().unwrap();
+        assert_eq!(result, Command::ListVariants);
+    }
+
+    #[test]
+    fn cli_constrain_successful_parse() {
+        let app = create_app();
+        let test_matches =
+            app.get_matches_from(vec!["ClearHeadToDo", "constrain", "single_parent", "on"]);
+
+        let result = test_matches.parse_command().unwrap();
+        assert_eq!(
+            result,
+            Command::Constrain {
+                constraint: "single_parent".to_string(),
+                enabled: true,
+            }
+        );
+    }
+
+    #[test]
+    fn cli_constrain_invalid_state() {
+        let app = create_app();
+        let result =
+            app.try_get_matches_from(vec!["ClearHeadToDo", "constrain", "single_parent", "yes"]);
+
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidValue);
+    }
+
+    #[test]
+    fn cli_validate_successful_parse() {
+        let app = create_app();
+        let test_matches = app.get_matches_from(vec!["ClearHeadToDo", "validate"]);
+
+        let result = test_matches.parse_command().unwrap();
+        assert_eq!(result, Command::Validate);
+    }
+
+    #[test]
+    fn cli_rollup_successful_parse() {
+        let app = create_app();
+        let test_matches =
+            app.get_matches_from(vec!["ClearHeadToDo", "rollup", "weight_by_estimate", "off"]);
+
+        let result = test_matches.parse_command().unwrap();
+        assert_eq!(
+            result,
+            Command::Rollup {
+                option: "weight_by_estimate".to_string(),
+                enabled: false,
+            }
+        );
+    }
+
+    #[test]
+    fn cli_import_relationships_successful_parse() {
+        let app = create_app();
+        let test_matches = app.get_matches_from(vec![
+            "ClearHeadToDo", "import", "relationships.csv", "--relationships"]);
+
+        let result = test_matches.parse_command().unwrap();
+        assert_eq!(
+            result,
+            Command::Import {
+                file: "relationships.csv".to_string(),
+                format: "csv".to_string(),
+                relationships: true,
+            }
+        );
+    }
+
+    #[test]
+    fn cli_import_todo_txt_successful_parse() {
+        let app = create_app();
+        let test_matches = app.get_matches_from(vec!["ClearHeadToDo", "import", "todo.txt", "--format", "todo_txt"]);
+
+        let result = test_matches.parse_command().unwrap();
+        assert_eq!(
+            result,
+            Command::Import {
+                file: "todo.txt".to_string(),
+                format: "todo_txt".to_string(),
+                relationships: false,
+            }
+        );
+    }
+
+    #[test]
+    fn cli_restore_backup_defaults_to_latest() {
+        let app = create_app();
+        let test_matches = app.get_matches_from(vec!["ClearHeadToDo", "restore-backup"]);
+
+        let result = test_matches.parse_command().unwrap();
+        assert_eq!(result, Command::RestoreBackup(1));
+    }
+
+    #[test]
+    fn cli_restore_backup_failed_parse() {
+        let app = create_app();
+        let test_matches = app.get_matches_from(vec!["ClearHeadToDo", "restore_backup", "latest"]);
+
+        let result = test_matches.parse_command();
+        assert_eq!(result.unwrap_err().to_string(), "invalid digit found in string");
+    }
+
+    #[test]
+    fn cli_migrate_to_sqlite_successful_parse() {
+        let app = create_app();
+        let test_matches = app.get_matches_from(vec!["ClearHeadToDo", "migrate-to-sqlite"]);
+
+        let result = test_matches.parse_command().unwrap();
+        assert_eq!(result, Command::MigrateToSqlite);
+    }
+
+    #[test]
+    fn cli_compact_journal_successful_parse() {
+        let app = create_app();
+        let test_matches =
+            app.get_matches_from(vec!["ClearHeadToDo", "--storage", "journal", "compact-journal"]);
+
+        assert_eq!(test_matches.value_of("storage"), Some("journal"));
+        assert_eq!(test_matches.parse_command().unwrap(), Command::CompactJournal);
+    }
+
+    #[test]
+    fn cli_undo_and_redo_successful_parse() {
+        let undo_matches = create_app().get_matches_from(vec!["ClearHeadToDo", "undo"]);
+        let redo_matches = create_app().get_matches_from(vec!["ClearHeadToDo", "redo"]);
+
+        assert_eq!(undo_matches.parse_command().unwrap(), Command::Undo);
+        assert_eq!(redo_matches.parse_command().unwrap(), Command::Redo);
+    }
+
+    #[test]
+    fn cli_undo_depth_successful_parse() {
+        let app = create_app();
+        let test_matches = app.get_matches_from(vec!["ClearHeadToDo", "undo-depth", "50"]);
+
+        let result = test_matches.parse_command().unwrap();
+        assert_eq!(result, Command::UndoDepth(50));
+    }
+
+    #[test]
+    fn cli_undo_depth_failed_parse() {
+        let app = create_app();
+        let test_matches = app.get_matches_from(vec!["ClearHeadToDo", "undo_depth", "many"]);
+
+        let result = test_matches.parse_command();
+        assert_eq!(result.unwrap_err().to_string(), "invalid digit found in string");
+    }
+
+    #[test]
+    fn cli_history_show_successful_parse() {
+        let app = create_app();
+        let test_matches =
+            app.get_matches_from(vec!["ClearHeadToDo", "history", "show", "2026-10-12T09:30:00Z"]);
+
+        let result = test_matches.parse_command().unwrap();
+        assert_eq!(
+            result,
+            Command::HistoryShow(Utc.with_ymd_and_hms(2026, 10, 12, 9, 30, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn cli_history_show_date_means_end_of_day() {
+        let app = create_app();
+        let test_matches = app.get_matches_from(vec!["ClearHeadToDo", "history", "show", "2026-10-12"]);
+
+        let result = test_matches.parse_command().unwrap();
+        let expected_time = Local
+            .with_ymd_and_hms(2026, 10, 12, 23, 59, 59)
+            .unwrap()
+            .with_timezone(&Utc);
+        assert_eq!(result, Command::HistoryShow(expected_time));
+    }
+
+    #[test]
+    fn cli_history_show_failed_parse() {
+        let app = create_app();
+        let test_matches = app.get_matches_from(vec!["ClearHeadToDo", "history", "show", "last monday"]);
+
+        let result = test_matches.parse_command();
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "last monday is not a date (YYYY-MM-DD) or an RFC 3339 time"
+        );
+    }
+
+    #[test]
+    fn cli_history_log_successful_parse() {
+        let app = create_app();
+        let test_matches = app.get_matches_from(vec!["ClearHeadToDo", "history", "log"]);
+
+        assert_eq!(test_matches.parse_command().unwrap(), Command::HistoryLog);
+    }
+
+    #[test]
+    fn cli_diff_defaults_to_now() {
+        let app = create_app();
+        let test_matches =
+            app.get_matches_from(vec!["ClearHeadToDo", "diff", "2026-10-12T00:00:00+02:00"]);
+
+        let result = test_matches.parse_command().unwrap();
+        assert_eq!(
+            result,
+            Command::Diff {
+                from: Utc.with_ymd_and_hms(2026, 10, 11, 22, 0, 0).unwrap(),
+                to: None,
+            }
+        );
+    }
+
+    #[test]
+    fn cli_workspace_successful_parse() {
+        let app = create_app();
+        let test_matches = app.get_matches_from(vec!["ClearHeadToDo", "workspace", "create", "work"]);
+
+        assert_eq!(
+            test_matches.parse_command().unwrap(),
+            Command::CreateWorkspace("work".to_string())
+        );
+    }
+
+    #[test]
+    fn cli_workspace_rename_successful_parse() {
+        let app = create_app();
+        let test_matches = app.get_matches_from(vec!["ClearHeadToDo", "ws", "rename", "team", "team-board"]);
+
+        assert_eq!(
+            test_matches.parse_command().unwrap(),
+            Command::RenameWorkspace {
+                name: "team".to_string(),
+                new_name: "team-board".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn cli_workspace_list_alias() {
+        let app = create_app();
+        let test_matches = app.get_matches_from(vec!["ClearHeadToDo", "workspace", "lt", "--workspace", "home"]);
+
+        assert_eq!(test_matches.parse_command().unwrap(), Command::ListWorkspaces);
+        assert_eq!(test_matches.value_of("workspace"), Some("home"));
+    }
+
+    #[test]
+    fn cli_move_to_workspace_successful_parse() {
+        let app = create_app();
+        let test_matches = app.get_matches_from(vec!["ClearHeadToDo", "move_to_workspace", "2", "home", "0"]);
+
+        assert_eq!(
+            test_matches.parse_command().unwrap(),
+            Command::MoveToWorkspace {
+                index: 2,
+                target: "home".to_string(),
+                new_parent: Some(0),
+            }
+        );
+    }
+
+    #[test]
+    fn cli_file_successful_parse() {
+        let app = create_app();
+        let test_matches = app.get_matches_from(vec!["ClearHeadToDo", "list", "--file", "work.json"]);
+
+        assert_eq!(test_matches.value_of("file"), Some("work.json"));
+    }
+
+    #[test]
+    fn cli_file_with_import() {
+        let app = create_app();
+        let test_matches =
+            app.get_matches_from(vec!["ClearHeadToDo", "import", "actions.csv", "--file", "work.json"]);
+
+        assert_eq!(test_matches.value_of("file"), Some("work.json"));
+        assert_eq!(
+            test_matches.parse_command().unwrap(),
+            Command::Import {
+                file: "actions.csv".to_string(),
+                format: "csv".to_string(),
+                relationships: false,
+            }
+        );
+    }
+
+    #[test]
+    fn cli_storage_defaults_to_json() {
+        let app = create_app();
+        let test_matches = app.get_matches_from(vec!["ClearHeadToDo", "list"]);
+
+        assert_eq!(test_matches.value_of("storage"), Some("json"));
+    }
+
+    #[test]
+    fn cli_storage_after_subcommand() {
+        let app = create_app();
+        let test_matches = app.get_matches_from(vec!["ClearHeadToDo", "list", "--storage", "sqlite"]);
+
+        assert_eq!(test_matches.value_of("storage"), Some("sqlite"));
+        assert_eq!(test_matches.parse_command().unwrap(), Command::List);
+    }
+
+    #[test]
+    fn cli_export_successful_parse() {
+        let app = create_app();
+        let test_matches =
+            app.get_matches_from(vec!["ClearHeadToDo", "export", "--format", "dot", "--root", "1"]);
+
+        let result = test_matches.parse_command().unwrap();
+        assert_eq!(
+            result,
+            Command::Export {
+                format: "dot".to_string(),
+                root: Some(1),
+                start: None,
+            }
+        );
+    }
+
+    #[test]
+    fn cli_export_defaults_to_whole_graph() {
+        let app = create_app();
+        let test_matches = app.get_matches_from(vec!["ClearHeadToDo", "export"]);
+
+        let result = test_matches.parse_command().unwrap();
+        assert_eq!(
+            result,
+            Command::Export {
+                format: "dot".to_string(),
+                root: None,
+                start: None,
+            }
+        );
+    }
+
+    #[test]
+    fn cli_export_unknown_format() {
+        let app = create_app();
+        let result = app.try_get_matches_from(vec!["ClearHeadToDo", "export", "--format", "png"]);
+
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidValue);
+    }
+
+    #[test]
+    fn cli_export_gantt_with_start_date() {
+        let app = create_app();
+        let test_matches = app.get_matches_from(vec![
+            "ClearHeadToDo", "export", "--format", "gantt", "--start", "2022-10-03"]);
+
+        let result = test_matches.parse_command().unwrap();
+        assert_eq!(
+            result,
+            Command::Export {
+                format: "gantt".to_string(),
+                root: None,
+                start: NaiveDate::from_ymd_opt(2022, 10, 3),
+            }
+        );
+    }
+
+    #[test]
+    fn cli_relationship_list_alias() {
+        let app = create_app();
+        let test_matches = app.get_matches_from(vec!["ClearHeadToDo", "rel", "lt"]);
+
+        let result = test_matches.parse_command().unwrap();
+        assert_eq!(result, Command::ListRelationships);
+    }
+
+    #[test]
+    fn cli_relationship_show_and_remove() {
+        let show_matches = create_app().get_matches_from(vec!["ClearHeadToDo", "relationship", "show", "2"]);
+        let remove_matches = create_app().get_matches_from(vec!["ClearHeadToDo", "relationship", "remove", "1"]);
+
+        assert_eq!(show_matches.parse_command().unwrap(), Command::ShowRelationship(2));
+        assert_eq!(remove_matches.parse_command().unwrap(), Command::RemoveRelationship(1));
+    }
+
+    #[test]
+    fn cli_relationship_change_variant() {
+        let app = create_app();
+        let test_matches = app.get_matches_from(vec![
+            "ClearHeadToDo", "relationship", "change-variant", "0", "sequential"]);
+
+        let result = test_matches.parse_command().unwrap();
+        assert_eq!(
+            result,
+            Command::ChangeRelationshipVariant {
+                index: 0,
+                variant: "sequential".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn cli_relationship_retarget() {
+        let app = create_app();
+        let test_matches = app.get_matches_from(vec![
+            "ClearHeadToDo", "relationship", "retarget", "0", "p2", "3"]);
+
+        let result = test_matches.parse_command().unwrap();
+        assert_eq!(
+            result,
+            Command::RetargetRelationship {
+                index: 0,
+                participant: "p2".to_string(),
+                action_index: 3
+            }
+        );
+    }
+
+    #[test]
+    fn cli_relationship_retarget_bad_participant() {
+        let app = create_app();
+        let result = app.try_get_matches_from(vec![
+            "ClearHeadToDo", "relationship", "retarget", "0", "p3", "3"]);
+
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidValue);
+    }
+
+    #[test]
+    fn cli_relationship_filter() {
+        let app = create_app();
+        let test_matches = app.get_matches_from(vec![
+            "ClearHeadToDo", "relationship", "filter", "--variant", "parental", "--participant", "1"]);
+
+        let result = test_matches.parse_command().unwrap();
+        assert_eq!(
+            result,
+            Command::FilterRelationships {
+                variant: Some("parental".to_string()),
+                participant: Some(1),
+                list: "either".to_string()
+            }
+        );
+    }
 }
@@ -1,7 +1,12 @@
 use crate::ClearHeadApp;
-use clear_head_todo_core::ActionManipulation;
+use clear_head_todo_core::{ActionError, ActionManipulation, JournalBackend, RelationshipListManagement};
+use clear_head_todo_core::extended_action::ExtendedAction;
 
 use std::error::Error;
+use std::fs::File;
+use std::io::BufReader;
+
+use chrono::{DateTime, Local, NaiveDate, Utc};
 
 #[derive(Debug, PartialEq)]
 #[non_exhaustive]
@@ -18,6 +23,69 @@ pub enum Command {
     Remove(usize),
     Rename { index: usize, new_name: String },
     Reprioritize { index: usize, new_priority: String },
+    Estimate { index: usize, estimate: u32 },
+    Due { index: usize, due_date: NaiveDate },
+    MoveSubtree { index: usize, new_parent: Option<usize> },
+    CopySubtree { index: usize, new_parent: Option<usize> },
+    RemoveSubtree(usize),
+    MoveToWorkspace {
+        index: usize,
+        target: String,
+        new_parent: Option<usize>,
+    },
+    CreateWorkspace(String),
+    SwitchWorkspace(String),
+    ListWorkspaces,
+    DeleteWorkspace(String),
+    RenameWorkspace { name: String, new_name: String },
+    CriticalPath,
+    Show(usize),
+    CreateVariant {
+        name: String,
+        directionality: String,
+        inverse_label: Option<String>,
+        aliases: Vec<String>,
+    },
+    ListVariants,
+    Constrain { constraint: String, enabled: bool },
+    Rollup { option: String, enabled: bool },
+    Validate,
+    ListRelationships,
+    ShowRelationship(usize),
+    RemoveRelationship(usize),
+    ChangeRelationshipVariant { index: usize, variant: String },
+    RetargetRelationship {
+        index: usize,
+        participant: String,
+        action_index: usize,
+    },
+    FilterRelationships {
+        variant: Option<String>,
+        participant: Option<usize>,
+        list: String,
+    },
+    Import {
+        file: String,
+        format: String,
+        relationships: bool,
+    },
+    RestoreBackup(usize),
+    MigrateToSqlite,
+    CompactJournal,
+    HistoryShow(DateTime<Utc>),
+    HistoryLog,
+    Diff {
+        from: DateTime<Utc>,
+        to: Option<DateTime<Utc>>,
+    },
+    Undo,
+    Redo,
+    UndoDepth(usize),
+    Export {
+        format: String,
+        root: Option<usize>,
+        start: Option<NaiveDate>,
+    },
 }
 
 impl Command {
@@ -34,6 +102,14 @@ impl Command {
                 app.get_extended_list()?;
                 return Ok(app.clone());
             }
+            Command::CriticalPath => {
+                app.get_critical_path()?;
+                return Ok(app.clone());
+            }
+            Command::Show(index) => {
+                ExtendedAction::from_app_by_index(app, *index)?;
+                return Ok(app.clone());
+            }
             Command::Create(name) => {
                 let updated_list = app.append_default_action();
                 if let Some(name) = name {
@@ -71,6 +147,233 @@ impl Command {
                     app.change_action_priority(*index, new_priority.to_string())?;
                 Ok(updated_list)
             }
+            Command::Estimate { index, estimate } => {
+                let updated_list = app.change_action_estimate(*index, Some(*estimate))?;
+                Ok(updated_list)
+            }
+            Command::Due { index, due_date } => {
+                let updated_list = app.change_action_due_date(*index, Some(*due_date))?;
+                Ok(updated_list)
+            }
+            Command::MoveSubtree { index, new_parent } => {
+                let updated_list = app.move_subtree(*index, *new_parent)?;
+                Ok(updated_list)
+            }
+            Command::CopySubtree { index, new_parent } => {
+                let updated_list = app.copy_subtree(*index, *new_parent)?;
+                Ok(updated_list)
+            }
+            Command::RemoveSubtree(index) => {
+                let updated_list = app.remove_subtree(*index)?;
+                Ok(updated_list)
+            }
+            Command::CreateVariant {
+                name,
+                directionality,
+                inverse_label,
+                aliases,
+            } => {
+                let updated_list = app.register_custom_variant(
+                    name,
+                    directionality,
+                    inverse_label.as_deref(),
+                    aliases.iter().cloned().collect(),
+                )?;
+                Ok(updated_list)
+            }
+            Command::ListVariants => {
+                app.get_custom_variant_list();
+                return Ok(app.clone());
+            }
+            Command::Constrain {
+                constraint,
+                enabled,
+            } => {
+                let updated_list = app.set_graph_constraint(constraint, *enabled)?;
+                Ok(updated_list)
+            }
+            Command::Rollup { option, enabled } => {
+                let updated_list = app.set_rollup_option(option, *enabled)?;
+                Ok(updated_list)
+            }
+            Command::Validate => {
+                app.get_constraint_report();
+                return Ok(app.clone());
+            }
+            Command::Import {
+                file,
+                format,
+                relationships,
+            } => {
+                let file_reader = BufReader::new(File::open(file)?);
+                let updated_list = match (format.as_str(), relationships) {
+                    ("csv", true) => app.import_relationships_from_csv(file_reader)?,
+                    ("csv", false) => app.import_actions_from_csv(file_reader)?,
+                    ("todo_txt", _) => app.import_from_todo_txt(file_reader)?,
+                    ("ical", _) => app.import_from_icalendar(file_reader)?,
+                    ("markdown", _) => app.import_from_markdown(file_reader)?,
+                    _ => return Err(format!("{} is not a supported import format", format).into()),
+                };
+                Ok(updated_list)
+            }
+            Command::RestoreBackup(_) => {
+                Err("restoring a backup replaces the data file directly".into())
+            }
+            Command::MigrateToSqlite => {
+                Err("migrating to SQLite writes a new database file directly".into())
+            }
+            Command::CompactJournal => {
+                Err("compacting the journal rewrites the journal file directly".into())
+            }
+            Command::HistoryShow(_) | Command::HistoryLog | Command::Diff { .. } => {
+                Err("history is read from the journal".into())
+            }
+            Command::Undo | Command::Redo | Command::UndoDepth(_) => {
+                Err("undo history is kept next to the data file".into())
+            }
+            Command::MoveToWorkspace { .. } => {
+                Err("moving to another workspace changes two data files".into())
+            }
+            Command::CreateWorkspace(_)
+            | Command::SwitchWorkspace(_)
+            | Command::ListWorkspaces
+            | Command::DeleteWorkspace(_)
+            | Command::RenameWorkspace { .. } => {
+                Err("workspaces are managed beside the data file".into())
+            }
+            Command::Export { .. } => {
+                self.create_export(app)?;
+                return Ok(app.clone());
+            }
+            Command::ListRelationships
+            | Command::ShowRelationship(_)
+            | Command::FilterRelationships { .. } => {
+                self.create_relationship_report(app)?;
+                return Ok(app.clone());
+            }
+            Command::RemoveRelationship(index) => {
+                let updated_list = app.remove_at_index(*index)?;
+                Ok(updated_list)
+            }
+            Command::ChangeRelationshipVariant { index, variant } => {
+                let updated_list = app.change_relationship_variant(*index, variant)?;
+                Ok(updated_list)
+            }
+            Command::RetargetRelationship {
+                index,
+                participant,
+                action_index,
+            } => {
+                let new_id = app.get_action_id(*action_index)?;
+                let updated_list = match participant.as_str() {
+                    "1" | "p1" | "participant_1" => {
+                        app.update_relationship_participant_1(*index, new_id)?
+                    }
+                    "2" | "p2" | "participant_2" => {
+                        app.update_relationship_participant_2(*index, new_id)?
+                    }
+                    _ => return Err(format!("{} is not a relationship participant", participant).into()),
+                };
+                Ok(updated_list)
+            }
+        }
+    }
+
+    pub fn create_relationship_report(&self, app: &ClearHeadApp) -> Result<String, Box<dyn Error>> {
+        match self {
+            Command::ListRelationships => {
+                app.get_relationship_overview(&(0..app.relationship_list.len()).collect::<Vec<usize>>())
+            }
+            Command::ShowRelationship(index) => app.get_relationship_details(*index),
+            Command::FilterRelationships {
+                variant,
+                participant,
+                list,
+            } => {
+                let participant_id = match participant {
+                    Some(index) => Some(app.get_action_id(*index)?),
+                    None => None,
+                };
+                let indices = app.filter_relationship_indices(variant.as_deref(), participant_id, list)?;
+                app.get_relationship_overview(&indices)
+            }
+            _ => Err("only the relationship list, show and filter commands produce a report".into()),
+        }
+    }
+
+    pub fn create_history_report(
+        &self,
+        journal: &JournalBackend,
+        app: &ClearHeadApp,
+    ) -> Result<String, Box<dyn Error>> {
+        match self {
+            Command::HistoryShow(time) => Ok(format!(
+                "As of {}\n{}",
+                time.with_timezone(&Local).format("%Y-%m-%d %H:%M:%S"),
+                journal.load_as_of(*time)?.get_list()
+            )),
+            Command::HistoryLog => {
+                let entries = journal.read_entries()?;
+                if entries.is_empty() {
+                    return Ok("No Journal Entries".to_string());
+                }
+                Ok(entries
+                    .iter()
+                    .map(|entry| {
+                        format!(
+                            "{} {} {}",
+                            entry.sequence,
+                            entry.recorded.with_timezone(&Local).format("%Y-%m-%d %H:%M:%S"),
+                            entry.event
+                        )
+                    })
+                    .collect::<Vec<String>>()
+                    .join("\n"))
+            }
+            Command::Diff { from, to } => {
+                let before = journal.load_as_of(*from)?;
+                let after = match to {
+                    Some(to) => journal.load_as_of(*to)?,
+                    None => app.clone(),
+                };
+                Ok(before.diff(&after).to_string())
+            }
+            _ => Err("only the history show, history log and diff commands produce a history report".into()),
+        }
+    }
+
+    pub fn create_export(&self, app: &ClearHeadApp) -> Result<String, Box<dyn Error>> {
+        match self {
+            Command::Export {
+                format,
+                root,
+                start,
+            } => {
+                let root_id = match root {
+                    Some(index) => Some(
+                        app.action_list
+                            .get(*index)
+                            .ok_or(ActionError::InvalidIndex(*index))?
+                            .get_id(),
+                    ),
+                    None => None,
+                };
+                match format.as_str() {
+                    "dot" => app.export_to_dot(root_id),
+                    "mermaid" => app.export_to_mermaid_flowchart(root_id),
+                    "gantt" => app.export_to_mermaid_gantt(
+                        root_id,
+                        start.unwrap_or_else(|| Local::now().date_naive()),
+                    ),
+                    "csv" => app.export_actions_to_csv(),
+                    "relationship_csv" => app.export_relationships_to_csv(),
+                    "todo_txt" => Ok(app.export_to_todo_txt()),
+                    "ical" => Ok(app.export_to_icalendar(Utc::now())),
+                    "markdown" => app.export_to_markdown(root_id),
+                    _ => Err(format!("{} is not a supported export format", format).into()),
+                }
+            }
+            _ => Err("only the export command produces an export".into()),
         }
     }
 
@@ -125,8 +428,148 @@ impl Command {
                     new_priority
                 )
             }
+            Command::Estimate { index, estimate } => {
+                format!(
+                    "{} is now estimated to take {} unit(s)",
+                    updated_app.action_list[*index].get_name(),
+                    estimate
+                )
+            }
+            Command::Due { index, due_date } => {
+                format!(
+                    "{} is now due on {}",
+                    updated_app.action_list[*index].get_name(),
+                    due_date
+                )
+            }
+            Command::MoveSubtree { index, new_parent } => {
+                let descendant_count = previous_app.get_subtree_ids(*index).unwrap().len() - 1;
+                match new_parent {
+                    Some(new_parent) => format!(
+                        "{} and {} descendant(s) were moved under {}",
+                        previous_app.action_list[*index].get_name(),
+                        descendant_count,
+                        previous_app.action_list[*new_parent].get_name()
+                    ),
+                    None => format!(
+                        "{} and {} descendant(s) no longer have a parent",
+                        previous_app.action_list[*index].get_name(),
+                        descendant_count
+                    ),
+                }
+            }
+            Command::CopySubtree { index, .. } => {
+                format!(
+                    "Copied {} and {} descendant(s)",
+                    previous_app.action_list[*index].get_name(),
+                    updated_app.action_list.len() - previous_app.action_list.len() - 1
+                )
+            }
+            Command::RemoveSubtree(index) => {
+                format!(
+                    "{} and {} descendant(s) were removed from your Action List",
+                    previous_app.action_list[*index].get_name(),
+                    previous_app.action_list.len() - updated_app.action_list.len() - 1
+                )
+            }
+            Command::RemoveRelationship(index) => {
+                let relationship = &previous_app.relationship_list[*index];
+                format!(
+                    "Removed {} Relationship from {} to {}",
+                    relationship.get_variant(),
+                    previous_app.get_participant_name(relationship.get_participant_1()),
+                    previous_app.get_participant_name(relationship.get_participant_2())
+                )
+            }
+            Command::ChangeRelationshipVariant { index, .. } => {
+                let relationship = &updated_app.relationship_list[*index];
+                format!(
+                    "Relationship {} from {} to {} was changed from {} to {}",
+                    index,
+                    updated_app.get_participant_name(relationship.get_participant_1()),
+                    updated_app.get_participant_name(relationship.get_participant_2()),
+                    previous_app.relationship_list[*index].get_variant(),
+                    relationship.get_variant()
+                )
+            }
+            Command::RetargetRelationship { index, .. } => {
+                let relationship = &updated_app.relationship_list[*index];
+                format!(
+                    "{} Relationship {} now goes from {} to {}",
+                    relationship.get_variant(),
+                    index,
+                    updated_app.get_participant_name(relationship.get_participant_1()),
+                    updated_app.get_participant_name(relationship.get_participant_2())
+                )
+            }
+            Command::CreateVariant { name, .. } => {
+                let custom_variant = updated_app.select_custom_variant(name).unwrap();
+                format!(
+                    "Registered {} as a {} relationship variant",
+                    custom_variant.get_name(),
+                    custom_variant.get_directionality()
+                )
+            }
+            Command::Constrain { enabled, .. } => {
+                let violation_count = updated_app.validate_constraints().len();
+                let state = if *enabled { "enabled" } else { "disabled" };
+                match violation_count {
+                    0 => format!("Graph constraint {}", state),
+                    _ => format!(
+                        "Graph constraint {}, {} existing violation(s) remain",
+                        state, violation_count
+                    ),
+                }
+            }
+            Command::Rollup { option, enabled } => format!(
+                "Rollup option {} {}",
+                option,
+                if *enabled { "enabled" } else { "disabled" }
+            ),
+            Command::Import {
+                format,
+                relationships,
+                ..
+            } => match (format.as_str(), relationships) {
+                ("csv", true) => format!(
+                    "Imported {} Relationship(s)",
+                    updated_app.relationship_list.len() - previous_app.relationship_list.len()
+                ),
+                ("csv", false) => format!(
+                    "Imported {} Action(s)",
+                    updated_app.action_list.len() - previous_app.action_list.len()
+                ),
+                _ => format!(
+                    "Imported {} Action(s) and {} Relationship(s)",
+                    updated_app.action_list.len() - previous_app.action_list.len(),
+                    updated_app.relationship_list.len() - previous_app.relationship_list.len()
+                ),
+            },
             Command::List => unreachable!(),
             Command::ExtendedList => unreachable!(),
+            Command::ListVariants => unreachable!(),
+            Command::Validate => unreachable!(),
+            Command::RestoreBackup(_) => unreachable!(),
+            Command::MigrateToSqlite => unreachable!(),
+            Command::CompactJournal => unreachable!(),
+            Command::HistoryShow(_) => unreachable!(),
+            Command::HistoryLog => unreachable!(),
+            Command::Diff { .. } => unreachable!(),
+            Command::Undo => unreachable!(),
+            Command::Redo => unreachable!(),
+            Command::UndoDepth(_) => unreachable!(),
+            Command::MoveToWorkspace { .. } => unreachable!(),
+            Command::CreateWorkspace(_) => unreachable!(),
+            Command::SwitchWorkspace(_) => unreachable!(),
+            Command::ListWorkspaces => unreachable!(),
+            Command::DeleteWorkspace(_) => unreachable!(),
+            Command::RenameWorkspace { .. } => unreachable!(),
+            Command::CriticalPath => unreachable!(),
+            Command::Show(_) => unreachable!(),
+            Command::Export { .. } => unreachable!(),
+            Command::ListRelationships => unreachable!(),
+            Command::ShowRelationship(_) => unreachable!(),
+            Command::FilterRelationships { .. } => unreachable!(),
         }
     }
 }
@@ -134,7 +577,8 @@ impl Command {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use clear_head_todo_core::{Priority, RelationshipListManagement};
+    use crate::tests::create_temporary_directory;
+    use clear_head_todo_core::{Priority, StorageBackend};
     
 
 
@@ -163,7 +607,7 @@ mod tests {
 
         let error = Command::List.run_subcommand(&empty_list);
 
-        let expected_string = "ClearHeadApp { action_list: [], relationship_list: [] }";
+        let expected_string = "ClearHeadApp { action_list: [], relationship_list: [], custom_variants: [], constraints: GraphConstraints { single_parent: false, acyclic_hierarchy: false, acyclic_sequences: false, same_project_sequences: false, no_self_relationships: false }, rollup: RollupSettings { auto_complete_parents: false, weight_by_estimate: false }, revision: 0 }";
         assert_eq!(format!("{:?}",error.unwrap()), expected_string);
     }
 
@@ -408,4 +852,617 @@ mod tests {
 
         assert_eq!(result, "Created related Relationship from Action 0 to Action 1");
     }
+
+    #[test]
+    fn cli_estimate_successful_run() {
+        let single_action_app = create_single_action_app();
+
+        let result = Command::Estimate {
+            index: 0,
+            estimate: 4,
+        }
+        .run_subcommand(&single_action_app).unwrap();
+
+        assert_eq!(result.get_action_estimate(0).unwrap(), Some(4));
+    }
+
+    #[test]
+    fn cli_estimate_failing_invalid_index() {
+        let empty_list: ClearHeadApp = Default::default();
+
+        let error = Command::Estimate {
+            index: 0,
+            estimate: 4,
+        }
+        .run_subcommand(&empty_list);
+
+        assert_eq!(error.unwrap_err().to_string(), "No Action at Index 0");
+    }
+
+    #[test]
+    fn generate_estimate_message() {
+        let single_action_app = create_single_action_app();
+        let updated_app = single_action_app.change_action_estimate(0, Some(4)).unwrap();
+
+        let message = Command::Estimate {
+            index: 0,
+            estimate: 4,
+        }
+        .create_end_user_message(&single_action_app, &updated_app);
+
+        assert_eq!(message, "Default Action is now estimated to take 4 unit(s)");
+    }
+
+    #[test]
+    fn cli_critical_path_failing_cycle() {
+        let cyclic_app = create_double_action_app()
+            .create_action_relationship("sequential", 0, 1)
+            .unwrap()
+            .create_action_relationship("sequential", 1, 0)
+            .unwrap();
+
+        let error = Command::CriticalPath.run_subcommand(&cyclic_app);
+
+        assert_eq!(
+            error.unwrap_err().to_string(),
+            "Sequential relationships form a cycle, so no critical path exists"
+        );
+    }
+
+    #[test]
+    fn cli_show_failing_invalid_index() {
+        let empty_list: ClearHeadApp = Default::default();
+
+        let error = Command::Show(0).run_subcommand(&empty_list);
+
+        assert_eq!(error.unwrap_err().to_string(), "No Action at Index 0");
+    }
+
+    fn create_blocks_variant_command() -> Command {
+        Command::CreateVariant {
+            name: "blocks".to_string(),
+            directionality: "directed".to_string(),
+            inverse_label: Some("blocked by".to_string()),
+            aliases: vec!["blocker".to_string()],
+        }
+    }
+
+    #[test]
+    fn cli_create_variant_successful_run() {
+        let double_action_app = create_double_action_app();
+
+        let result = create_blocks_variant_command()
+            .run_subcommand(&double_action_app).unwrap();
+
+        assert_eq!(result.custom_variants.len(), 1);
+        assert_eq!(result.select_custom_variant("blocker").unwrap().get_inverse_label(), "blocked by");
+    }
+
+    #[test]
+    fn cli_create_variant_failing_built_in_name() {
+        let empty_app = ClearHeadApp::default();
+
+        let error = Command::CreateVariant {
+            name: "related".to_string(),
+            directionality: "undirected".to_string(),
+            inverse_label: None,
+            aliases: Vec::new(),
+        }
+        .run_subcommand(&empty_app);
+
+        assert_eq!(error.unwrap_err().to_string(), "related is already a relationship variant");
+    }
+
+    #[test]
+    fn generate_create_variant_message() {
+        let empty_app = ClearHeadApp::default();
+        let updated_app = create_blocks_variant_command().run_subcommand(&empty_app).unwrap();
+
+        let message = create_blocks_variant_command().create_end_user_message(&empty_app, &updated_app);
+
+        assert_eq!(message, "Registered blocks as a Directed relationship variant");
+    }
+
+    #[test]
+    fn cli_create_custom_relationship_successful_run() {
+        let custom_variant_app = create_blocks_variant_command()
+            .run_subcommand(&create_double_action_app()).unwrap();
+
+        let result = Command::CreateRelationship {
+            variant: "blocker".to_string(),
+            participant_1: 0,
+            participant_2: 1,
+        }
+        .run_subcommand(&custom_variant_app).unwrap();
+
+        assert_eq!(result.get_relationship_variant(0).unwrap().to_string(), "blocks: Directed");
+    }
+
+    #[test]
+    fn cli_export_dot_successful_run() {
+        let related_app = create_double_action_app()
+            .create_action_relationship("parental", 0, 1)
+            .unwrap();
+
+        let dot_graph = Command::Export {
+            format: "dot".to_string(),
+            root: Some(0),
+            start: None,
+        }
+        .create_export(&related_app)
+        .unwrap();
+
+        assert!(dot_graph.starts_with("digraph ClearHead {"));
+        assert_eq!(dot_graph.matches(" -> ").count(), 1);
+    }
+
+    #[test]
+    fn cli_export_ical_successful_run() {
+        let related_app = create_double_action_app()
+            .create_action_relationship("parental", 0, 1)
+            .unwrap();
+
+        let calendar = Command::Export {
+            format: "ical".to_string(),
+            root: None,
+            start: None,
+        }
+        .create_export(&related_app)
+        .unwrap();
+
+        assert_eq!(calendar.matches("BEGIN:VTODO").count(), 2);
+        assert_eq!(calendar.matches("RELATED-TO;RELTYPE=PARENT").count(), 1);
+    }
+
+    #[test]
+    fn cli_export_markdown_successful_run() {
+        let related_app = create_double_action_app()
+            .create_action_relationship("parental", 0, 1)
+            .unwrap();
+
+        let checklist = Command::Export {
+            format: "markdown".to_string(),
+            root: None,
+            start: None,
+        }
+        .create_export(&related_app)
+        .unwrap();
+
+        assert_eq!(checklist.lines().count(), 2);
+        assert!(checklist.lines().nth(1).unwrap().starts_with("  - [ ] "));
+    }
+
+    #[test]
+    fn cli_export_failing_invalid_root() {
+        let empty_list: ClearHeadApp = Default::default();
+
+        let error = Command::Export {
+            format: "dot".to_string(),
+            root: Some(0),
+            start: None,
+        }
+        .run_subcommand(&empty_list);
+
+        assert_eq!(error.unwrap_err().to_string(), "No Action at Index 0");
+    }
+
+    #[test]
+    fn cli_export_mermaid_successful_run() {
+        let related_app = create_double_action_app()
+            .create_action_relationship("sequential", 0, 1)
+            .unwrap();
+
+        let flowchart = Command::Export {
+            format: "mermaid".to_string(),
+            root: None,
+            start: None,
+        }
+        .create_export(&related_app)
+        .unwrap();
+
+        assert!(flowchart.contains("    A0 ==> A1\n"));
+    }
+
+    #[test]
+    fn cli_export_gantt_successful_run() {
+        let related_app = create_double_action_app()
+            .create_action_relationship("sequential", 0, 1)
+            .unwrap();
+
+        let gantt = Command::Export {
+            format: "gantt".to_string(),
+            root: None,
+            start: NaiveDate::from_ymd_opt(2022, 10, 3),
+        }
+        .create_export(&related_app)
+        .unwrap();
+
+        assert!(gantt.contains("    Default Action :crit, a0, 2022-10-03, 1d\n"));
+        assert!(gantt.contains("    Default Action :crit, a1, after a0, 1d\n"));
+    }
+
+    #[test]
+    fn cli_due_successful_run() {
+        let single_action_app = create_single_action_app();
+        let due_date = NaiveDate::from_ymd_opt(2022, 10, 5).unwrap();
+
+        let result = Command::Due { index: 0, due_date }
+            .run_subcommand(&single_action_app)
+            .unwrap();
+
+        assert_eq!(result.get_action_due_date(0).unwrap(), Some(due_date));
+    }
+
+    #[test]
+    fn generate_due_message() {
+        let single_action_app = create_single_action_app();
+        let due_date = NaiveDate::from_ymd_opt(2022, 10, 5).unwrap();
+        let updated_app = single_action_app.change_action_due_date(0, Some(due_date)).unwrap();
+
+        let message = Command::Due { index: 0, due_date }
+            .create_end_user_message(&single_action_app, &updated_app);
+
+        assert_eq!(message, "Default Action is now due on 2022-10-05");
+    }
+
+    fn create_project_tree_app() -> ClearHeadApp {
+        ClearHeadApp::default()
+            .append_default_action()
+            .rename_action(0, "Project".to_string())
+            .unwrap()
+            .append_default_action()
+            .rename_action(1, "Design".to_string())
+            .unwrap()
+            .append_default_action()
+            .append_default_action()
+            .rename_action(3, "Inbox".to_string())
+            .unwrap()
+            .create_action_relationship("parental", 0, 1)
+            .unwrap()
+            .create_action_relationship("parental", 1, 2)
+            .unwrap()
+    }
+
+    #[test]
+    fn generate_move_subtree_message() {
+        let tree_app = create_project_tree_app();
+        let command = Command::MoveSubtree {
+            index: 1,
+            new_parent: Some(3),
+        };
+        let updated_app = command.run_subcommand(&tree_app).unwrap();
+
+        let message = command.create_end_user_message(&tree_app, &updated_app);
+
+        assert_eq!(message, "Design and 1 descendant(s) were moved under Inbox");
+    }
+
+    #[test]
+    fn generate_detach_subtree_message() {
+        let tree_app = create_project_tree_app();
+        let command = Command::MoveSubtree {
+            index: 1,
+            new_parent: None,
+        };
+        let updated_app = command.run_subcommand(&tree_app).unwrap();
+
+        let message = command.create_end_user_message(&tree_app, &updated_app);
+
+        assert_eq!(message, "Design and 1 descendant(s) no longer have a parent");
+    }
+
+    #[test]
+    fn cli_move_subtree_failing_cycle() {
+        let tree_app = create_project_tree_app();
+
+        let error = Command::MoveSubtree {
+            index: 0,
+            new_parent: Some(2),
+        }
+        .run_subcommand(&tree_app);
+
+        assert_eq!(error.unwrap_err().to_string(), "Project cannot be moved under its own subtree");
+    }
+
+    #[test]
+    fn generate_copy_subtree_message() {
+        let tree_app = create_project_tree_app();
+        let command = Command::CopySubtree {
+            index: 0,
+            new_parent: None,
+        };
+        let updated_app = command.run_subcommand(&tree_app).unwrap();
+
+        let message = command.create_end_user_message(&tree_app, &updated_app);
+
+        assert_eq!(updated_app.action_list.len(), 7);
+        assert_eq!(message, "Copied Project and 2 descendant(s)");
+    }
+
+    #[test]
+    fn generate_remove_subtree_message() {
+        let tree_app = create_project_tree_app();
+        let command = Command::RemoveSubtree(1);
+        let updated_app = command.run_subcommand(&tree_app).unwrap();
+
+        let message = command.create_end_user_message(&tree_app, &updated_app);
+
+        assert_eq!(updated_app.action_list.len(), 2);
+        assert_eq!(message, "Design and 1 descendant(s) were removed from your Action List");
+    }
+
+    #[test]
+    fn generate_constrain_message_with_existing_violations() {
+        let tree_app = create_project_tree_app()
+            .create_action_relationship("parental", 0, 2)
+            .unwrap();
+        let command = Command::Constrain {
+            constraint: "single_parent".to_string(),
+            enabled: true,
+        };
+        let updated_app = command.run_subcommand(&tree_app).unwrap();
+
+        let message = command.create_end_user_message(&tree_app, &updated_app);
+
+        assert!(updated_app.constraints.single_parent);
+        assert_eq!(message, "Graph constraint enabled, 1 existing violation(s) remain");
+    }
+
+    #[test]
+    fn cli_constrain_failing_unknown_constraint() {
+        let empty_list: ClearHeadApp = Default::default();
+
+        let error = Command::Constrain {
+            constraint: "max_depth".to_string(),
+            enabled: true,
+        }
+        .run_subcommand(&empty_list);
+
+        assert_eq!(error.unwrap_err().to_string(), "max_depth is not a graph constraint");
+    }
+
+    #[test]
+    fn generate_rollup_message() {
+        let tree_app = create_project_tree_app();
+        let command = Command::Rollup {
+            option: "auto_complete_parents".to_string(),
+            enabled: true,
+        };
+        let updated_app = command.run_subcommand(&tree_app).unwrap();
+
+        let message = command.create_end_user_message(&tree_app, &updated_app);
+
+        assert!(updated_app.rollup.auto_complete_parents);
+        assert_eq!(message, "Rollup option auto_complete_parents enabled");
+    }
+
+    #[test]
+    fn generate_import_actions_message() {
+        let empty_list: ClearHeadApp = Default::default();
+        let command = Command::Import {
+            file: "../core/data/successful_action_import_test.csv".to_string(),
+            format: "csv".to_string(),
+            relationships: false,
+        };
+        let updated_app = command.run_subcommand(&empty_list).unwrap();
+
+        let message = command.create_end_user_message(&empty_list, &updated_app);
+
+        assert_eq!(updated_app.get_action_name(0).unwrap(), "test csv action");
+        assert_eq!(message, "Imported 1 Action(s)");
+    }
+
+    #[test]
+    fn generate_import_todo_txt_message() {
+        let empty_list: ClearHeadApp = Default::default();
+        let command = Command::Import {
+            file: "../core/data/successful_todo_txt_import_test.txt".to_string(),
+            format: "todo_txt".to_string(),
+            relationships: false,
+        };
+        let updated_app = command.run_subcommand(&empty_list).unwrap();
+
+        let message = command.create_end_user_message(&empty_list, &updated_app);
+
+        assert_eq!(updated_app.get_action_name(2).unwrap(), "Spring");
+        assert_eq!(message, "Imported 3 Action(s) and 2 Relationship(s)");
+    }
+
+    #[test]
+    fn cli_history_log_lists_events() {
+        let mut journal = JournalBackend::new(&create_temporary_directory("history_log").join("app.journal"));
+        let stored_app = journal
+            .update(&|app| app.append_default_action().rename_action(0, "Paint Fence".to_string()))
+            .unwrap();
+
+        let report = Command::HistoryLog.create_history_report(&journal, &stored_app).unwrap();
+
+        assert!(report.starts_with("1 "));
+        assert!(report.ends_with(&format!(
+            "Added Action Paint Fence ({})",
+            stored_app.get_action_id(0).unwrap()
+        )));
+    }
+
+    #[test]
+    fn cli_diff_against_current_app() {
+        let mut journal = JournalBackend::new(&create_temporary_directory("history_diff").join("app.journal"));
+        let before_updates = Utc::now() - chrono::Duration::seconds(1);
+        let stored_app = journal.update(&|app| Ok(app.append_default_action())).unwrap();
+
+        let report = Command::Diff {
+            from: before_updates,
+            to: None,
+        }
+        .create_history_report(&journal, &stored_app)
+        .unwrap();
+
+        assert_eq!(
+            report,
+            format!("+ Action Default Action ({})\n", stored_app.get_action_id(0).unwrap())
+        );
+    }
+
+    #[test]
+    fn cli_history_show_empty_journal() {
+        let journal = JournalBackend::new(&create_temporary_directory("history_show").join("app.journal"));
+
+        let report = Command::HistoryShow(Utc::now())
+            .create_history_report(&journal, &ClearHeadApp::default())
+            .unwrap();
+
+        assert!(report.starts_with("As of "));
+        assert!(report.contains("0 Item(s)"));
+    }
+
+    #[test]
+    fn cli_import_failing_bad_priority() {
+        let empty_list: ClearHeadApp = Default::default();
+
+        let error = Command::Import {
+            file: "../core/data/bad_action_priority_test.csv".to_string(),
+            format: "csv".to_string(),
+            relationships: false,
+        }
+        .run_subcommand(&empty_list);
+
+        assert_eq!(
+            error.unwrap_err().to_string(),
+            "Row 1: bad priority is an Invalid Priority Option"
+        );
+    }
+
+    #[test]
+    fn cli_export_csv_successful_run() {
+        let tree_app = create_project_tree_app();
+
+        let csv_export = Command::Export {
+            format: "csv".to_string(),
+            root: None,
+            start: None,
+        }
+        .create_export(&tree_app)
+        .unwrap();
+
+        assert!(csv_export.starts_with("name,priority,completed,id,estimate,due_date\nProject,Optional,false,"));
+        assert_eq!(csv_export.lines().count(), 5);
+    }
+
+    #[test]
+    fn cli_remove_subtree_failing_invalid_index() {
+        let empty_list: ClearHeadApp = Default::default();
+
+        let error = Command::RemoveSubtree(0).run_subcommand(&empty_list);
+
+        assert_eq!(error.unwrap_err().to_string(), "No Action at Index 0");
+    }
+
+    fn create_named_relationship_app() -> ClearHeadApp {
+        ClearHeadApp::default()
+            .append_default_action()
+            .rename_action(0, "Project".to_string())
+            .unwrap()
+            .append_default_action()
+            .rename_action(1, "Design".to_string())
+            .unwrap()
+            .append_default_action()
+            .rename_action(2, "Inbox".to_string())
+            .unwrap()
+            .create_action_relationship("parental", 0, 1)
+            .unwrap()
+    }
+
+    #[test]
+    fn cli_list_relationships_successful_run() {
+        let related_app = create_named_relationship_app();
+
+        let report = Command::ListRelationships.create_relationship_report(&related_app).unwrap();
+
+        assert!(report.contains("| 0     | Parental: Directed | Project | Design |       |"));
+    }
+
+    #[test]
+    fn cli_filter_relationships_by_participant() {
+        let related_app = create_named_relationship_app()
+            .create_action_relationship("related", 1, 2)
+            .unwrap();
+
+        let report = Command::FilterRelationships {
+            variant: None,
+            participant: Some(2),
+            list: "either".to_string(),
+        }
+        .create_relationship_report(&related_app)
+        .unwrap();
+
+        assert!(report.contains("Related: Undirected"));
+        assert!(!report.contains("Parental: Directed"));
+    }
+
+    #[test]
+    fn cli_show_relationship_failing_invalid_index() {
+        let empty_list: ClearHeadApp = Default::default();
+
+        let error = Command::ShowRelationship(0).run_subcommand(&empty_list);
+
+        assert_eq!(error.unwrap_err().to_string(), "Unable to find Relationship at given Index");
+    }
+
+    #[test]
+    fn generate_remove_relationship_message() {
+        let related_app = create_named_relationship_app();
+        let command = Command::RemoveRelationship(0);
+        let updated_app = command.run_subcommand(&related_app).unwrap();
+
+        let message = command.create_end_user_message(&related_app, &updated_app);
+
+        assert!(updated_app.relationship_list.is_empty());
+        assert_eq!(message, "Removed Parental: Directed Relationship from Project to Design");
+    }
+
+    #[test]
+    fn generate_change_relationship_variant_message() {
+        let related_app = create_named_relationship_app();
+        let command = Command::ChangeRelationshipVariant {
+            index: 0,
+            variant: "sequential".to_string(),
+        };
+        let updated_app = command.run_subcommand(&related_app).unwrap();
+
+        let message = command.create_end_user_message(&related_app, &updated_app);
+
+        assert_eq!(
+            message,
+            "Relationship 0 from Project to Design was changed from Parental: Directed to Sequential: Directed"
+        );
+    }
+
+    #[test]
+    fn generate_retarget_relationship_message() {
+        let related_app = create_named_relationship_app();
+        let command = Command::RetargetRelationship {
+            index: 0,
+            participant: "p2".to_string(),
+            action_index: 2,
+        };
+        let updated_app = command.run_subcommand(&related_app).unwrap();
+
+        let message = command.create_end_user_message(&related_app, &updated_app);
+
+        assert_eq!(message, "Parental: Directed Relationship 0 now goes from Project to Inbox");
+    }
+
+    #[test]
+    fn cli_retarget_relationship_failing_invalid_action() {
+        let related_app = create_named_relationship_app();
+
+        let error = Command::RetargetRelationship {
+            index: 0,
+            participant: "1".to_string(),
+            action_index: 9,
+        }
+        .run_subcommand(&related_app);
+
+        assert_eq!(error.unwrap_err().to_string(), "No Action at Index 9");
+    }
 }
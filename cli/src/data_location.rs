@@ -0,0 +1,236 @@
+use std::error::Error;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+pub const DATA_FILE_VARIABLE: &str = "CLEARHEAD_DATA_FILE";
+const APPLICATION_DIRECTORY: &str = "clearhead";
+const CONFIG_FILE_NAME: &str = "config";
+const DATA_FILE_KEY: &str = "data_file";
+
+pub fn get_default_file_name(storage: &str) -> &'static str {
+    match storage {
+        "sqlite" => "app.db",
+        "journal" => "app.journal",
+        _ => "app.json",
+    }
+}
+
+fn get_base_directory(
+    get_variable: &dyn Fn(&str) -> Option<String>,
+    xdg_variable: &str,
+    home_fallback: &str,
+) -> Result<PathBuf, Box<dyn Error>> {
+    match get_variable(xdg_variable).filter(|directory| Path::new(directory).is_absolute()) {
+        Some(directory) => Ok(PathBuf::from(directory)),
+        None => {
+            let home = get_variable("HOME").ok_or_else(|| {
+                format!("Neither {} nor HOME is set, pass --file to choose a data file", xdg_variable)
+            })?;
+            Ok(Path::new(&home).join(home_fallback))
+        }
+    }
+}
+
+pub fn get_config_path(get_variable: &dyn Fn(&str) -> Option<String>) -> Result<PathBuf, Box<dyn Error>> {
+    Ok(get_base_directory(get_variable, "XDG_CONFIG_HOME", ".config")?
+        .join(APPLICATION_DIRECTORY)
+        .join(CONFIG_FILE_NAME))
+}
+
+// relative data_file paths other than ~/ are relative to the config file
+pub fn read_configured_data_file(
+    config_path: &Path,
+    get_variable: &dyn Fn(&str) -> Option<String>,
+) -> Result<Option<PathBuf>, Box<dyn Error>> {
+    let contents = match fs::read_to_string(config_path) {
+        Ok(contents) => contents,
+        Err(error) if error.kind() == ErrorKind::NotFound => return Ok(None),
+        Err(error) => return Err(error.into()),
+    };
+
+    for (line_number, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let (key, value) = line
+            .split_once('=')
+            .ok_or_else(|| format!("{} line {}: expected key = value", config_path.display(), line_number + 1))?;
+        if key.trim() == DATA_FILE_KEY {
+            let value = value.trim().trim_matches('"');
+            if let Some(home_relative) = value.strip_prefix("~/") {
+                let home = get_variable("HOME")
+                    .ok_or_else(|| format!("{} uses ~ but HOME is not set", config_path.display()))?;
+                return Ok(Some(Path::new(&home).join(home_relative)));
+            }
+            let data_file = PathBuf::from(value);
+            return Ok(Some(match config_path.parent() {
+                Some(config_directory) if data_file.is_relative() => config_directory.join(data_file),
+                _ => data_file,
+            }));
+        }
+    }
+
+    Ok(None)
+}
+
+pub fn resolve_data_path(
+    file_flag: Option<&str>,
+    storage: &str,
+    get_variable: &dyn Fn(&str) -> Option<String>,
+) -> Result<PathBuf, Box<dyn Error>> {
+    if let Some(file) = file_flag {
+        return Ok(PathBuf::from(file));
+    }
+    if let Some(file) = get_variable(DATA_FILE_VARIABLE).filter(|file| !file.is_empty()) {
+        return Ok(PathBuf::from(file));
+    }
+    if let Ok(config_path) = get_config_path(get_variable) {
+        if let Some(file) = read_configured_data_file(&config_path, get_variable)? {
+            return Ok(file);
+        }
+    }
+
+    Ok(get_base_directory(get_variable, "XDG_DATA_HOME", ".local/share")?
+        .join(APPLICATION_DIRECTORY)
+        .join(get_default_file_name(storage)))
+}
+
+pub fn get_environment_variable(name: &str) -> Option<String> {
+    std::env::var(name).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::create_temporary_directory;
+
+    use std::collections::HashMap;
+
+    fn create_environment(variables: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let variables: HashMap<String, String> = variables
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect();
+
+        move |name| variables.get(name).cloned()
+    }
+
+    #[test]
+    fn file_flag_takes_precedence() {
+        let environment = create_environment(&[(DATA_FILE_VARIABLE, "/tmp/from_variable.json")]);
+
+        let data_path = resolve_data_path(Some("todo.json"), "json", &environment).unwrap();
+
+        assert_eq!(data_path, PathBuf::from("todo.json"));
+    }
+
+    #[test]
+    fn environment_variable_before_config() {
+        let environment = create_environment(&[
+            (DATA_FILE_VARIABLE, "/tmp/from_variable.json"),
+            ("HOME", "/home/tester"),
+        ]);
+
+        let data_path = resolve_data_path(None, "json", &environment).unwrap();
+
+        assert_eq!(data_path, PathBuf::from("/tmp/from_variable.json"));
+    }
+
+    #[test]
+    fn read_data_file_from_config() {
+        let config_home = create_temporary_directory("config_data_file");
+        let config_directory = config_home.join(APPLICATION_DIRECTORY);
+        fs::create_dir_all(&config_directory).unwrap();
+        fs::write(
+            config_directory.join(CONFIG_FILE_NAME),
+            "# where my list lives\ndata_file = \"lists/work.json\"\n",
+        )
+        .unwrap();
+        let environment = create_environment(&[("XDG_CONFIG_HOME", config_home.to_str().unwrap())]);
+
+        let data_path = resolve_data_path(None, "json", &environment).unwrap();
+
+        assert_eq!(data_path, config_directory.join("lists/work.json"));
+    }
+
+    #[test]
+    fn expand_home_in_config() {
+        let config_path = create_temporary_directory("config_home_data_file").join(CONFIG_FILE_NAME);
+        fs::write(&config_path, "data_file = ~/todo/app.json\n").unwrap();
+        let environment = create_environment(&[("HOME", "/home/tester")]);
+
+        let data_path = read_configured_data_file(&config_path, &environment).unwrap();
+
+        assert_eq!(data_path, Some(PathBuf::from("/home/tester/todo/app.json")));
+    }
+
+    #[test]
+    fn failed_expand_home_without_home() {
+        let config_path = create_temporary_directory("config_home_unset").join(CONFIG_FILE_NAME);
+        fs::write(&config_path, "data_file = ~/todo/app.json\n").unwrap();
+
+        let config_error = read_configured_data_file(&config_path, &create_environment(&[])).unwrap_err();
+
+        assert_eq!(
+            config_error.to_string(),
+            format!("{} uses ~ but HOME is not set", config_path.display())
+        );
+    }
+
+    #[test]
+    fn failed_read_malformed_config() {
+        let config_path = create_temporary_directory("malformed_config").join(CONFIG_FILE_NAME);
+        fs::write(&config_path, "data_file /tmp/app.json\n").unwrap();
+
+        let config_error = read_configured_data_file(&config_path, &create_environment(&[])).unwrap_err();
+
+        assert_eq!(
+            config_error.to_string(),
+            format!("{} line 1: expected key = value", config_path.display())
+        );
+    }
+
+    #[test]
+    fn default_to_xdg_data_home() {
+        let environment = create_environment(&[
+            ("XDG_DATA_HOME", "/data"),
+            ("XDG_CONFIG_HOME", "/nonexistent/config"),
+        ]);
+
+        let data_path = resolve_data_path(None, "sqlite", &environment).unwrap();
+
+        assert_eq!(data_path, PathBuf::from("/data/clearhead/app.db"));
+    }
+
+    #[test]
+    fn default_to_xdg_data_home_without_config_directory() {
+        let environment = create_environment(&[("XDG_DATA_HOME", "/data")]);
+
+        let data_path = resolve_data_path(None, "json", &environment).unwrap();
+
+        assert_eq!(data_path, PathBuf::from("/data/clearhead/app.json"));
+    }
+
+    #[test]
+    fn fall_back_to_home_directory() {
+        let environment = create_environment(&[("HOME", "/nonexistent/home"), ("XDG_DATA_HOME", "relative")]);
+
+        let data_path = resolve_data_path(None, "json", &environment).unwrap();
+
+        assert_eq!(data_path, PathBuf::from("/nonexistent/home/.local/share/clearhead/app.json"));
+    }
+
+    #[test]
+    fn failed_resolve_without_home() {
+        let environment = create_environment(&[]);
+
+        let resolve_error = resolve_data_path(None, "json", &environment).unwrap_err();
+
+        assert_eq!(
+            resolve_error.to_string(),
+            "Neither XDG_DATA_HOME nor HOME is set, pass --file to choose a data file"
+        );
+    }
+}
@@ -5,18 +5,159 @@ use arg_parser::ArgumentParsing;
 pub mod command_runner;
 use command_runner::Command;
 
+pub mod data_location;
+use data_location::{get_environment_variable, resolve_data_path};
+
+pub mod workspace;
+use workspace::{
+    create_workspace, delete_workspace, get_workspace_path, list_workspaces,
+    rename_workspace, resolve_workspace, switch_workspace,
+};
+
 use clear_head_todo_core::ClearHeadApp;
-use clear_head_todo_core::JSONStorage;
+use clear_head_todo_core::{
+    get_history_path, record_events, FileLock, JSONBackend, JSONStorage, JournalBackend, SQLiteBackend,
+    SQLiteStorage, StorageBackend, UndoHistory, DEFAULT_BACKUP_COUNT, DEFAULT_LOCK_TIMEOUT,
+};
+use clear_head_todo_core::extended_action::ExtendedAction;
 
 use std::error::Error;
+use std::fs;
 use std::path::Path;
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let clear_head_app = ClearHeadApp::read_from_json(Path::new("cli/data/app.json"))?;
+fn create_backend(storage: &str, data_path: &Path) -> Box<dyn StorageBackend> {
+    match storage {
+        "sqlite" => Box::new(SQLiteBackend::new(data_path)),
+        "journal" => Box::new(JournalBackend::new(data_path)),
+        _ => Box::new(JSONBackend::new(data_path).set_backup_count(DEFAULT_BACKUP_COUNT)),
+    }
+}
+
+fn record_undo_step(data_path: &Path, message: &str, previous_app: &ClearHeadApp, updated_app: &ClearHeadApp) {
+    if record_events(previous_app, updated_app).is_empty() {
+        return;
+    }
+    let history_path = get_history_path(data_path);
+    if let Err(e) = UndoHistory::read_from_file(&history_path)
+        .and_then(|history| history.record(message, previous_app).write_to_file(&history_path))
+    {
+        eprintln!("Unable to record Undo History: {}", e);
+    }
+}
+
+fn run_workspace_command(subcommand: &Command, data_path: &Path, current_workspace: &str) -> Result<String, Box<dyn Error>> {
+    match subcommand {
+        Command::CreateWorkspace(name) => {
+            create_workspace(data_path, name)?;
+            Ok(format!("Created Workspace {}", name))
+        }
+        Command::SwitchWorkspace(name) => {
+            switch_workspace(data_path, name)?;
+            Ok(format!("Switched to Workspace {}", name))
+        }
+        Command::ListWorkspaces => Ok(list_workspaces(data_path)?
+            .iter()
+            .map(|name| match name == current_workspace {
+                true => format!("* {}", name),
+                false => format!("  {}", name),
+            })
+            .collect::<Vec<String>>()
+            .join("\n")),
+        Command::DeleteWorkspace(name) => {
+            delete_workspace(data_path, name)?;
+            Ok(format!("Deleted Workspace {}", name))
+        }
+        Command::RenameWorkspace { name, new_name } => {
+            rename_workspace(data_path, name, new_name)?;
+            Ok(format!("Renamed Workspace {} to {}", name, new_name))
+        }
+        _ => Err("only workspace commands manage workspaces".into()),
+    }
+}
 
+fn main() -> Result<(), Box<dyn Error>> {
     let argument_parser = create_app();
     let matches = argument_parser.get_matches();
     let subcommand = matches.parse_command()?;
+    let storage = matches.value_of("storage").unwrap_or("json");
+
+    let data_path = match resolve_data_path(matches.value_of("file"), storage, &get_environment_variable) {
+        Ok(data_path) => data_path,
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    };
+    if let Some(data_directory) = data_path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+        fs::create_dir_all(data_directory)?;
+    }
+
+    let (workspace_name, workspace_path) = match resolve_workspace(&data_path, matches.value_of("workspace")) {
+        Ok(workspace) => workspace,
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    };
+
+    let data_file_lock = match FileLock::acquire(&data_path, DEFAULT_LOCK_TIMEOUT) {
+        Ok(file_lock) => file_lock,
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    };
+
+    if let Command::CreateWorkspace(_)
+        | Command::SwitchWorkspace(_)
+        | Command::ListWorkspaces
+        | Command::DeleteWorkspace(_)
+        | Command::RenameWorkspace { .. } = subcommand
+    {
+        match run_workspace_command(&subcommand, &data_path, &workspace_name) {
+            Ok(message) => println!("{}", message),
+            Err(e) => eprintln!("{}", e),
+        }
+        return Ok(())
+    }
+    if let Command::MoveToWorkspace { target, .. } = &subcommand {
+        if target == &workspace_name {
+            eprintln!("The Action is already in Workspace {}", target);
+            return Ok(())
+        }
+    }
+    let (root_data_path, data_path) = (data_path, workspace_path);
+
+    if subcommand == Command::MigrateToSqlite {
+        if storage != "json" {
+            eprintln!("Migration reads the JSON data file, run it without --storage");
+            return Ok(())
+        }
+        let database_path = data_path.with_extension("db");
+        match ClearHeadApp::migrate_json_to_sqlite(&data_path, &database_path) {
+            Ok(migrated_app) => println!(
+                "Migrated {} Action(s) and {} Relationship(s) to {}",
+                migrated_app.action_list.len(),
+                migrated_app.relationship_list.len(),
+                database_path.display()
+            ),
+            Err(e) => eprintln!("{}", e),
+        }
+        return Ok(())
+    }
+    if subcommand == Command::CompactJournal {
+        if storage != "journal" {
+            eprintln!("Only journal storage can be compacted, pass --storage journal");
+            return Ok(())
+        }
+        match JournalBackend::new(&data_path).compact() {
+            Ok(compacted_entries) => println!("Compacted {} Journal Entry(s) into a Snapshot", compacted_entries),
+            Err(e) => eprintln!("{}", e),
+        }
+        return Ok(())
+    }
+    let mut backend = create_backend(storage, &data_path);
+    let clear_head_app = ClearHeadApp::load_from(backend.as_ref())?;
 
     if subcommand == Command::List {
         println!("{}", clear_head_app.get_list());
@@ -27,14 +168,158 @@ fn main() -> Result<(), Box<dyn Error>> {
             Err(e) => eprintln!("{}", e),
         }
     }
+    else if subcommand == Command::CriticalPath {
+        match clear_head_app.get_critical_path_report() {
+            Ok(s) => println!("{}", s),
+            Err(e) => eprintln!("{}", e),
+        }
+    }
+    else if subcommand == Command::ListVariants {
+        print!("{}", clear_head_app.get_custom_variant_list());
+    }
+    else if subcommand == Command::Validate {
+        print!("{}", clear_head_app.get_constraint_report());
+    }
+    else if let Command::Show(index) = subcommand {
+        match ExtendedAction::from_app_by_index(&clear_head_app, index) {
+            Ok(extended_action) => print!("{}", extended_action),
+            Err(e) => eprintln!("{}", e),
+        }
+    }
+    else if let Command::ListRelationships
+        | Command::ShowRelationship(_)
+        | Command::FilterRelationships { .. } = subcommand
+    {
+        match subcommand.create_relationship_report(&clear_head_app) {
+            Ok(report) => println!("{}", report),
+            Err(e) => eprintln!("{}", e),
+        }
+    }
+    else if let Command::RestoreBackup(generation) = subcommand {
+        if storage != "json" {
+            eprintln!("Backups are only kept for JSON storage");
+            return Ok(())
+        }
+        match ClearHeadApp::restore_backup(&data_path, generation, DEFAULT_BACKUP_COUNT) {
+            Ok(restored_app) => println!(
+                "Restored Backup {} with {} Action(s) and {} Relationship(s)",
+                generation,
+                restored_app.action_list.len(),
+                restored_app.relationship_list.len()
+            ),
+            Err(e) => eprintln!("{}", e),
+        }
+    }
+    else if let Command::HistoryShow(_) | Command::HistoryLog | Command::Diff { .. } = subcommand {
+        if storage != "journal" {
+            eprintln!("History is only recorded with journal storage, pass --storage journal");
+            return Ok(())
+        }
+        let journal = JournalBackend::new(&data_path);
+        match subcommand.create_history_report(&journal, &clear_head_app) {
+            Ok(report) => println!("{}", report.trim_end()),
+            Err(e) => eprintln!("{}", e),
+        }
+    }
+    else if let Command::Undo | Command::Redo = subcommand {
+        let history_path = get_history_path(&data_path);
+        let history = UndoHistory::read_from_file(&history_path)?;
+        let history_step = match subcommand {
+            Command::Undo => history.undo(&clear_head_app),
+            _ => history.redo(&clear_head_app),
+        };
+        match history_step {
+            Ok((updated_history, restored_app, description)) => {
+                if let Err(e) = restored_app
+                    .save_to(backend.as_mut())
+                    .and_then(|_| updated_history.write_to_file(&history_path))
+                {
+                    eprintln!("{}", e);
+                    drop(data_file_lock);
+                    std::process::exit(1);
+                }
+                match subcommand {
+                    Command::Undo => println!("Undid: {}", description),
+                    _ => println!("Redid: {}", description),
+                }
+            }
+            Err(e) => eprintln!("{}", e),
+        }
+    }
+    else if let Command::UndoDepth(depth) = subcommand {
+        let history_path = get_history_path(&data_path);
+        UndoHistory::read_from_file(&history_path)?
+            .set_depth(depth)
+            .write_to_file(&history_path)?;
+        println!("Keeping up to {} Undo Step(s)", depth);
+    }
+    else if let Command::MoveToWorkspace { index, target, new_parent } = &subcommand {
+        let target_path = match get_workspace_path(&root_data_path, target) {
+            Ok(target_path) => target_path,
+            Err(e) => {
+                eprintln!("{}", e);
+                return Ok(())
+            }
+        };
+        let mut target_backend = create_backend(storage, &target_path);
+        let target_app = ClearHeadApp::load_from(target_backend.as_ref())?;
+        let (updated_task_list, updated_target) = match clear_head_app.move_subtree_to_app(*index, &target_app, *new_parent) {
+            Ok(updated_apps) => updated_apps,
+            Err(e) => {
+                eprintln!("{}", e);
+                return Ok(())
+            }
+        };
+        // the target is written first so a failure part way leaves a copy rather than losing the subtree
+        if let Err(e) = updated_target
+            .save_to(target_backend.as_mut())
+            .and_then(|_| updated_task_list.save_to(backend.as_mut()))
+        {
+            eprintln!("{}", e);
+            drop(data_file_lock);
+            std::process::exit(1);
+        }
+        let message = format!(
+            "{} and {} descendant(s) were moved to Workspace {}",
+            clear_head_app.get_action_name(*index)?,
+            updated_target.action_list.len() - target_app.action_list.len() - 1,
+            target
+        );
+        record_undo_step(&data_path, &message, &clear_head_app, &updated_task_list);
+        record_undo_step(&target_path, &message, &target_app, &updated_target);
+        println!("{}", message);
+    }
+    else if let Command::Export { .. } = subcommand {
+        match subcommand.create_export(&clear_head_app) {
+            Ok(export) => print!("{}", export),
+            Err(e) => eprintln!("{}", e),
+        }
+    }
     else {
         let updated_task_list = subcommand.run_subcommand(&clear_head_app)?;
-        updated_task_list.write_to_json(Path::new("cli/data/app.json"),true)?;
-        println!(
-            "{}",
-            &subcommand.create_end_user_message(&clear_head_app, &updated_task_list)
-        );
+        if let Err(e) = updated_task_list.save_to(backend.as_mut()) {
+            eprintln!("{}", e);
+            drop(data_file_lock);
+            std::process::exit(1);
+        }
+        let message = subcommand.create_end_user_message(&clear_head_app, &updated_task_list);
+        record_undo_step(&data_path, &message, &clear_head_app, &updated_task_list);
+        println!("{}", &message);
     }
 
     Ok(())
 }
+
+#[cfg(test)]
+pub mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+
+    pub fn create_temporary_directory(test_name: &str) -> PathBuf {
+        let directory = std::env::temp_dir().join(format!("clear_head_cli_{}_{}", test_name, std::process::id()));
+        let _ = fs::remove_dir_all(&directory);
+        fs::create_dir_all(&directory).unwrap();
+
+        directory
+    }
+}
@@ -0,0 +1,281 @@
+use std::error::Error;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+pub const DEFAULT_WORKSPACE: &str = "default";
+const WORKSPACE_DIRECTORY: &str = "workspaces";
+const CURRENT_WORKSPACE_FILE: &str = "current_workspace";
+
+// the default workspace is the data file itself, named ones live in workspaces/<name>/ beside it
+fn get_data_directory(data_path: &Path) -> &Path {
+    data_path.parent().unwrap_or_else(|| Path::new(""))
+}
+
+fn get_workspace_directory(data_path: &Path, name: &str) -> PathBuf {
+    get_data_directory(data_path).join(WORKSPACE_DIRECTORY).join(name)
+}
+
+fn get_current_workspace_path(data_path: &Path) -> PathBuf {
+    get_data_directory(data_path).join(CURRENT_WORKSPACE_FILE)
+}
+
+fn validate_name(name: &str) -> Result<(), Box<dyn Error>> {
+    if name.is_empty()
+        || !name
+            .chars()
+            .all(|character| character.is_ascii_alphanumeric() || character == '-' || character == '_')
+    {
+        return Err(format!(
+            "{} is not a valid Workspace name, use letters, digits, - and _",
+            name
+        )
+        .into());
+    }
+
+    Ok(())
+}
+
+fn validate_new_name(data_path: &Path, name: &str) -> Result<(), Box<dyn Error>> {
+    validate_name(name)?;
+    if name == DEFAULT_WORKSPACE || get_workspace_directory(data_path, name).exists() {
+        return Err(format!("Workspace {} already exists", name).into());
+    }
+
+    Ok(())
+}
+
+fn validate_existing_name(data_path: &Path, name: &str) -> Result<(), Box<dyn Error>> {
+    validate_name(name)?;
+    if name != DEFAULT_WORKSPACE && !get_workspace_directory(data_path, name).is_dir() {
+        return Err(format!("No Workspace named {}", name).into());
+    }
+
+    Ok(())
+}
+
+pub fn get_workspace_path(data_path: &Path, name: &str) -> Result<PathBuf, Box<dyn Error>> {
+    validate_existing_name(data_path, name)?;
+    if name == DEFAULT_WORKSPACE {
+        return Ok(data_path.to_path_buf());
+    }
+
+    Ok(get_workspace_directory(data_path, name).join(data_path.file_name().ok_or("the data file has no name")?))
+}
+
+pub fn get_current_workspace(data_path: &Path) -> Result<String, Box<dyn Error>> {
+    match fs::read_to_string(get_current_workspace_path(data_path)) {
+        Ok(name) if !name.trim().is_empty() => Ok(name.trim().to_string()),
+        Ok(_) => Ok(DEFAULT_WORKSPACE.to_string()),
+        Err(error) if error.kind() == ErrorKind::NotFound => Ok(DEFAULT_WORKSPACE.to_string()),
+        Err(error) => Err(error.into()),
+    }
+}
+
+pub fn resolve_workspace(data_path: &Path, requested: Option<&str>) -> Result<(String, PathBuf), Box<dyn Error>> {
+    if let Some(name) = requested {
+        return Ok((name.to_string(), get_workspace_path(data_path, name)?));
+    }
+
+    let name = get_current_workspace(data_path)?;
+    match get_workspace_path(data_path, &name) {
+        Ok(workspace_path) => Ok((name, workspace_path)),
+        Err(error) => {
+            eprintln!("{}, using the {} Workspace", error, DEFAULT_WORKSPACE);
+            Ok((DEFAULT_WORKSPACE.to_string(), data_path.to_path_buf()))
+        }
+    }
+}
+
+pub fn switch_workspace(data_path: &Path, name: &str) -> Result<(), Box<dyn Error>> {
+    validate_existing_name(data_path, name)?;
+
+    Ok(fs::write(get_current_workspace_path(data_path), format!("{}\n", name))?)
+}
+
+pub fn list_workspaces(data_path: &Path) -> Result<Vec<String>, Box<dyn Error>> {
+    let mut names = Vec::new();
+    match fs::read_dir(get_data_directory(data_path).join(WORKSPACE_DIRECTORY)) {
+        Ok(entries) => {
+            for entry in entries {
+                let entry = entry?;
+                if entry.file_type()?.is_dir() {
+                    names.push(entry.file_name().to_string_lossy().to_string());
+                }
+            }
+        }
+        Err(error) if error.kind() == ErrorKind::NotFound => {}
+        Err(error) => return Err(error.into()),
+    }
+    names.sort();
+    names.insert(0, DEFAULT_WORKSPACE.to_string());
+
+    Ok(names)
+}
+
+pub fn create_workspace(data_path: &Path, name: &str) -> Result<(), Box<dyn Error>> {
+    validate_new_name(data_path, name)?;
+
+    Ok(fs::create_dir_all(get_workspace_directory(data_path, name))?)
+}
+
+pub fn delete_workspace(data_path: &Path, name: &str) -> Result<(), Box<dyn Error>> {
+    validate_existing_name(data_path, name)?;
+    if name == DEFAULT_WORKSPACE {
+        return Err("The default Workspace cannot be deleted".into());
+    }
+    if get_current_workspace(data_path)? == name {
+        return Err(format!("Workspace {} is in use, switch to another Workspace first", name).into());
+    }
+
+    Ok(fs::remove_dir_all(get_workspace_directory(data_path, name))?)
+}
+
+pub fn rename_workspace(data_path: &Path, name: &str, new_name: &str) -> Result<(), Box<dyn Error>> {
+    validate_existing_name(data_path, name)?;
+    if name == DEFAULT_WORKSPACE {
+        return Err("The default Workspace cannot be renamed".into());
+    }
+    validate_new_name(data_path, new_name)?;
+
+    fs::rename(
+        get_workspace_directory(data_path, name),
+        get_workspace_directory(data_path, new_name),
+    )?;
+    if get_current_workspace(data_path)? == name {
+        switch_workspace(data_path, new_name)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::create_temporary_directory;
+
+    #[test]
+    fn default_workspace_is_data_file() {
+        let data_path = create_temporary_directory("workspace_default").join("app.json");
+
+        assert_eq!(get_current_workspace(&data_path).unwrap(), DEFAULT_WORKSPACE);
+        assert_eq!(get_workspace_path(&data_path, DEFAULT_WORKSPACE).unwrap(), data_path);
+        assert_eq!(list_workspaces(&data_path).unwrap(), vec![DEFAULT_WORKSPACE.to_string()]);
+    }
+
+    #[test]
+    fn create_and_switch_workspace() {
+        let data_path = create_temporary_directory("workspace_switch").join("app.json");
+
+        create_workspace(&data_path, "work").unwrap();
+        create_workspace(&data_path, "home").unwrap();
+        switch_workspace(&data_path, "work").unwrap();
+
+        assert_eq!(get_current_workspace(&data_path).unwrap(), "work");
+        assert_eq!(
+            get_workspace_path(&data_path, "work").unwrap(),
+            data_path.with_file_name("workspaces").join("work").join("app.json")
+        );
+        assert_eq!(list_workspaces(&data_path).unwrap(), vec!["default", "home", "work"]);
+    }
+
+    #[test]
+    fn resolve_requested_or_current_workspace() {
+        let data_path = create_temporary_directory("workspace_resolve").join("app.json");
+        create_workspace(&data_path, "work").unwrap();
+        switch_workspace(&data_path, "work").unwrap();
+
+        assert_eq!(
+            resolve_workspace(&data_path, None).unwrap(),
+            ("work".to_string(), get_workspace_path(&data_path, "work").unwrap())
+        );
+        assert_eq!(
+            resolve_workspace(&data_path, Some(DEFAULT_WORKSPACE)).unwrap(),
+            (DEFAULT_WORKSPACE.to_string(), data_path.clone())
+        );
+    }
+
+    #[test]
+    fn resolve_removed_current_workspace_to_default() {
+        let data_path = create_temporary_directory("workspace_resolve_removed").join("app.json");
+        create_workspace(&data_path, "work").unwrap();
+        switch_workspace(&data_path, "work").unwrap();
+        fs::remove_dir_all(get_workspace_directory(&data_path, "work")).unwrap();
+
+        assert_eq!(
+            resolve_workspace(&data_path, None).unwrap(),
+            (DEFAULT_WORKSPACE.to_string(), data_path.clone())
+        );
+        switch_workspace(&data_path, DEFAULT_WORKSPACE).unwrap();
+
+        assert_eq!(get_current_workspace(&data_path).unwrap(), DEFAULT_WORKSPACE);
+    }
+
+    #[test]
+    fn failed_resolve_unknown_workspace() {
+        let data_path = create_temporary_directory("workspace_resolve_unknown").join("app.json");
+
+        let resolve_error = resolve_workspace(&data_path, Some("holiday")).unwrap_err();
+
+        assert_eq!(resolve_error.to_string(), "No Workspace named holiday");
+    }
+
+    #[test]
+    fn rename_current_workspace() {
+        let data_path = create_temporary_directory("workspace_rename").join("app.json");
+        create_workspace(&data_path, "team").unwrap();
+        switch_workspace(&data_path, "team").unwrap();
+
+        rename_workspace(&data_path, "team", "team-board").unwrap();
+
+        assert_eq!(get_current_workspace(&data_path).unwrap(), "team-board");
+        assert_eq!(list_workspaces(&data_path).unwrap(), vec!["default", "team-board"]);
+    }
+
+    #[test]
+    fn delete_workspace_removes_its_data() {
+        let data_path = create_temporary_directory("workspace_delete").join("app.json");
+        create_workspace(&data_path, "home").unwrap();
+        fs::write(get_workspace_path(&data_path, "home").unwrap(), "{}").unwrap();
+
+        delete_workspace(&data_path, "home").unwrap();
+
+        assert_eq!(
+            get_workspace_path(&data_path, "home").unwrap_err().to_string(),
+            "No Workspace named home"
+        );
+    }
+
+    #[test]
+    fn failed_delete_current_workspace() {
+        let data_path = create_temporary_directory("workspace_delete_current").join("app.json");
+        create_workspace(&data_path, "work").unwrap();
+        switch_workspace(&data_path, "work").unwrap();
+
+        let delete_error = delete_workspace(&data_path, "work").unwrap_err();
+
+        assert_eq!(
+            delete_error.to_string(),
+            "Workspace work is in use, switch to another Workspace first"
+        );
+    }
+
+    #[test]
+    fn failed_create_invalid_or_duplicate_name() {
+        let data_path = create_temporary_directory("workspace_invalid").join("app.json");
+        create_workspace(&data_path, "work").unwrap();
+
+        assert_eq!(
+            create_workspace(&data_path, "../work").unwrap_err().to_string(),
+            "../work is not a valid Workspace name, use letters, digits, - and _"
+        );
+        assert_eq!(
+            create_workspace(&data_path, "work").unwrap_err().to_string(),
+            "Workspace work already exists"
+        );
+        assert_eq!(
+            create_workspace(&data_path, DEFAULT_WORKSPACE).unwrap_err().to_string(),
+            "Workspace default already exists"
+        );
+    }
+}
@@ -0,0 +1,70 @@
+use clear_head_todo_core::{
+    Action, ActionManipulation, ClearHeadApp, RelationshipIndex, RelationshipListManagement,
+    RelationshipVariant,
+};
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use im::Vector;
+
+const ACTION_COUNT: usize = 50_000;
+const CHILDREN_PER_PARENT: usize = 4;
+
+fn create_large_app() -> ClearHeadApp {
+    let mut app = ClearHeadApp::default();
+    let action_list: Vector<Action> = (0..ACTION_COUNT).map(|_| Action::default()).collect();
+    let mut relationship_list = Vector::new();
+
+    for index in 1..ACTION_COUNT {
+        relationship_list = relationship_list
+            .append_parental_relationship(
+                action_list[(index - 1) / CHILDREN_PER_PARENT].get_id(),
+                action_list[index].get_id(),
+            )
+            .unwrap();
+        relationship_list = relationship_list
+            .append_sequential_relationship(action_list[index - 1].get_id(), action_list[index].get_id())
+            .unwrap();
+    }
+
+    app.action_list = action_list;
+    app.relationship_list = relationship_list;
+
+    app
+}
+
+fn relationship_index_benchmarks(c: &mut Criterion) {
+    let app = create_large_app();
+    let parental_variant = RelationshipVariant::create_parental();
+    let middle_id = app.action_list[ACTION_COUNT / 2].get_id();
+
+    c.bench_function("build index for 50k actions", |b| {
+        b.iter(|| RelationshipIndex::new(black_box(&app.relationship_list)))
+    });
+
+    let relationship_index = app.get_relationship_index();
+    c.bench_function("indexed children lookup", |b| {
+        b.iter(|| relationship_index.get_outgoing_ids(black_box(middle_id), &parental_variant))
+    });
+
+    c.bench_function("linear children lookup", |b| {
+        b.iter(|| app.relationship_list.get_children_for_id(black_box(middle_id)))
+    });
+
+    c.bench_function("cached app children lookup", |b| {
+        b.iter(|| app.get_children_for_id(black_box(middle_id)))
+    });
+
+    c.bench_function("descendants of root for 50k actions", |b| {
+        b.iter(|| app.get_descendant_ids(black_box(app.action_list[0].get_id())))
+    });
+
+    let mut group = c.benchmark_group("extended list");
+    group.sample_size(10);
+    group.bench_function("extended list for 50k actions", |b| {
+        b.iter(|| app.get_extended_list().unwrap())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, relationship_index_benchmarks);
+criterion_main!(benches);
@@ -6,6 +6,8 @@ use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 use uuid::Uuid;
 
+use chrono::NaiveDate;
+
 use serde::{Deserialize, Serialize};
 use tabled::Tabled;
 
@@ -21,6 +23,14 @@ pub struct Action {
 
     #[tabled(skip)]
     id: Uuid,
+
+    #[tabled(skip)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    estimate: Option<u32>,
+
+    #[tabled(skip)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    due_date: Option<NaiveDate>,
 }
 
 impl Default for Action {
@@ -30,6 +40,17 @@ impl Default for Action {
             name: "Default Action".to_string(),
             completed: false,
             priority: Default::default(),
+            estimate: None,
+            due_date: None,
+        }
+    }
+}
+
+impl Action {
+    pub(crate) fn set_id(&self, id: Uuid) -> Action {
+        Action {
+            id,
+            ..self.to_owned()
         }
     }
 }
@@ -48,11 +69,16 @@ pub trait ActionManipulation {
     fn rename(&self, new_action_name: &str) -> Action;
     fn toggle_completion_status(&self) -> Action;
     fn change_priority(&self, new_priority: &str) -> Result<Action, Box<dyn Error>>;
+    fn set_estimate(&self, estimate: Option<u32>) -> Action;
+    fn set_due_date(&self, due_date: Option<NaiveDate>) -> Action;
+    fn create_copy(&self) -> Action;
 
     fn get_id(&self) -> Uuid;
     fn get_name(&self) -> String;
     fn get_priority(&self) -> String;
     fn get_completion_status(&self) -> bool;
+    fn get_estimate(&self) -> Option<u32>;
+    fn get_due_date(&self) -> Option<NaiveDate>;
 }
 
 
@@ -78,6 +104,27 @@ impl ActionManipulation for Action {
         });
     }
 
+    fn set_estimate(&self, estimate: Option<u32>) -> Action {
+        Action {
+            estimate,
+            ..self.to_owned()
+        }
+    }
+
+    fn set_due_date(&self, due_date: Option<NaiveDate>) -> Action {
+        Action {
+            due_date,
+            ..self.to_owned()
+        }
+    }
+
+    fn create_copy(&self) -> Action {
+        Action {
+            id: Uuid::new_v4(),
+            ..self.to_owned()
+        }
+    }
+
     fn get_id(&self) -> Uuid {
         self.id.clone()
     }
@@ -93,6 +140,14 @@ impl ActionManipulation for Action {
     fn get_completion_status(&self) -> bool {
         self.completed.clone()
     }
+
+    fn get_estimate(&self) -> Option<u32> {
+        self.estimate
+    }
+
+    fn get_due_date(&self) -> Option<NaiveDate> {
+        self.due_date
+    }
 }
 
 
@@ -211,6 +266,43 @@ mod tests {
         );
     }
 
+    #[test]
+    fn get_default_estimate() {
+        let test_action = Action::default();
+
+        assert_eq!(test_action.get_estimate(), None);
+    }
+
+    #[test]
+    fn set_action_estimate() {
+        let test_action = Action::default();
+
+        let estimated_action = test_action.set_estimate(Some(3));
+
+        assert_eq!(estimated_action.get_estimate(), Some(3));
+    }
+
+    #[test]
+    fn set_action_due_date() {
+        let test_action = Action::default();
+        let due_date = NaiveDate::from_ymd_opt(2022, 9, 30).unwrap();
+
+        let scheduled_action = test_action.set_due_date(Some(due_date));
+
+        assert_eq!(scheduled_action.get_due_date(), Some(due_date));
+    }
+
+    #[test]
+    fn copy_action_with_new_id() {
+        let test_action = create_nil_action().set_estimate(Some(2));
+
+        let copied_action = test_action.create_copy();
+
+        assert_ne!(copied_action.get_id(), test_action.get_id());
+        assert_eq!(copied_action.get_name(), test_action.get_name());
+        assert_eq!(copied_action.get_estimate(), Some(2));
+    }
+
     #[test]
     fn successfully_serialize_action() {
         let test_action = Action {
@@ -270,4 +362,71 @@ mod tests {
         )
     }
 
+    #[test]
+    fn successfully_serialize_estimated_action() {
+        let test_action = Action {
+            id: Uuid::nil(),
+            estimate: Some(2),
+            ..Default::default()
+        };
+
+        assert_ser_tokens(
+            &test_action.readable(),
+            &[
+                Token::Struct {
+                    name: "Action",
+                    len: 5,
+                },
+                Token::Str("name"),
+                Token::Str("Default Action"),
+                Token::Str("priority"),
+                Token::UnitVariant {
+                    name: "Priority",
+                    variant: "Optional",
+                },
+                Token::Str("completed"),
+                Token::Bool(false),
+                Token::Str("id"),
+                Token::Str("00000000-0000-0000-0000-000000000000"),
+                Token::Str("estimate"),
+                Token::Some,
+                Token::U32(2),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn successfully_serialize_action_with_due_date() {
+        let test_action = Action {
+            id: Uuid::nil(),
+            due_date: Some(NaiveDate::from_ymd_opt(2022, 9, 30).unwrap()),
+            ..Default::default()
+        };
+
+        assert_ser_tokens(
+            &test_action.readable(),
+            &[
+                Token::Struct {
+                    name: "Action",
+                    len: 5,
+                },
+                Token::Str("name"),
+                Token::Str("Default Action"),
+                Token::Str("priority"),
+                Token::UnitVariant {
+                    name: "Priority",
+                    variant: "Optional",
+                },
+                Token::Str("completed"),
+                Token::Bool(false),
+                Token::Str("id"),
+                Token::Str("00000000-0000-0000-0000-000000000000"),
+                Token::Str("due_date"),
+                Token::Some,
+                Token::Str("2022-09-30"),
+                Token::StructEnd,
+            ],
+        );
+    }
 }
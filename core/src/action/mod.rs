@@ -10,9 +10,12 @@ pub use item::*;
 pub mod builder;
 pub use builder::*;
 
+pub mod storage;
+
 use std::error::Error;
 use uuid::Uuid;
 use im::Vector;
+use chrono::NaiveDate;
 
 use crate::action_implementation::ActionListManipulation;
 
@@ -75,6 +78,30 @@ impl ActionListManipulation for Vector<Action> {
         Ok(cloned_list.update(index, updated_action))
     }
 
+    fn change_action_estimate(
+        &self,
+        index: usize,
+        new_estimate: Option<u32>,
+    ) -> Result<im::Vector<Action>, Box<dyn Error>> {
+        let cloned_list = self.clone();
+
+        let updated_action = self.select_action_by_index(index)?.set_estimate(new_estimate);
+
+        Ok(cloned_list.update(index, updated_action))
+    }
+
+    fn change_action_due_date(
+        &self,
+        index: usize,
+        new_due_date: Option<NaiveDate>,
+    ) -> Result<im::Vector<Action>, Box<dyn Error>> {
+        let cloned_list = self.clone();
+
+        let updated_action = self.select_action_by_index(index)?.set_due_date(new_due_date);
+
+        Ok(cloned_list.update(index, updated_action))
+    }
+
     fn select_action_by_id(&self, id: Uuid) -> Result<Action, Box<dyn Error>> {
         let search_action_result = self.clone().into_iter()
             .find(|actions| actions.get_id() == id);
@@ -109,4 +136,12 @@ impl ActionListManipulation for Vector<Action> {
     fn get_action_completion_status(&self, index: usize) -> Result<bool, Box<dyn Error>> {
         Ok(self.select_action_by_index(index)?.get_completion_status())
     }
+
+    fn get_action_estimate(&self, index: usize) -> Result<Option<u32>, Box<dyn Error>> {
+        Ok(self.select_action_by_index(index)?.get_estimate())
+    }
+
+    fn get_action_due_date(&self, index: usize) -> Result<Option<NaiveDate>, Box<dyn Error>> {
+        Ok(self.select_action_by_index(index)?.get_due_date())
+    }
 }
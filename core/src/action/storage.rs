@@ -0,0 +1,152 @@
+use crate::action::Action;
+use crate::csv_storage::{describe_row_error, get_field, CSVRows, CSVStorage};
+use crate::ActionManipulation;
+
+use std::error::Error;
+use std::io::{Read, Write};
+
+use chrono::NaiveDate;
+use csv::Writer;
+use im::Vector;
+use uuid::Uuid;
+
+impl CSVStorage for Vector<Action> {
+    fn write_csv<W: Write>(&self, writer: W) -> Result<(), Box<dyn Error>> {
+        let mut csv_writer = Writer::from_writer(writer);
+
+        csv_writer.write_record(["name", "priority", "completed", "id", "estimate", "due_date"])?;
+        for action in self.iter() {
+            csv_writer.write_record([
+                action.get_name(),
+                action.get_priority(),
+                action.get_completion_status().to_string(),
+                action.get_id().to_string(),
+                action.get_estimate().map(|estimate| estimate.to_string()).unwrap_or_default(),
+                action.get_due_date().map(|due_date| due_date.to_string()).unwrap_or_default(),
+            ])?;
+        }
+        csv_writer.flush()?;
+
+        Ok(())
+    }
+
+    fn read_csv<R: Read>(reader: R) -> Result<Self, Box<dyn Error>> {
+        let rows = CSVRows::read(reader)?;
+        let name_column = rows.require_column(&["name"])?;
+        let priority_column = rows.require_column(&["priority"])?;
+        let completed_column = rows.require_column(&["completed", "complete"])?;
+        let id_column = rows.require_column(&["id", "uuid"])?;
+        let estimate_column = rows.find_column(&["estimate"]);
+        let due_date_column = rows.find_column(&["due_date", "due"]);
+
+        let mut action_list = Vector::new();
+        for (row, record) in rows.get_records() {
+            let id_field = get_field(record, Some(id_column)).unwrap_or_default();
+            let id = Uuid::parse_str(id_field)
+                .map_err(|_| describe_row_error(*row, format!("{} is an Invalid Id", id_field)))?;
+
+            let completed_field = get_field(record, Some(completed_column)).unwrap_or_default();
+            let completed = completed_field.to_lowercase().parse::<bool>().map_err(|_| {
+                describe_row_error(*row, format!("{} is an Invalid Completion Status", completed_field))
+            })?;
+
+            let mut action = Action::default()
+                .set_id(id)
+                .rename(get_field(record, Some(name_column)).unwrap_or_default())
+                .change_priority(get_field(record, Some(priority_column)).unwrap_or_default())
+                .map_err(|error| describe_row_error(*row, error))?;
+            if completed {
+                action = action.toggle_completion_status();
+            }
+
+            if let Some(estimate_field) = get_field(record, estimate_column) {
+                let estimate = estimate_field.parse::<u32>().map_err(|_| {
+                    describe_row_error(*row, format!("{} is an Invalid Estimate", estimate_field))
+                })?;
+                action = action.set_estimate(Some(estimate));
+            }
+
+            if let Some(due_date_field) = get_field(record, due_date_column) {
+                let due_date = due_date_field.parse::<NaiveDate>().map_err(|_| {
+                    describe_row_error(*row, format!("{} is an Invalid Due Date", due_date_field))
+                })?;
+                action = action.set_due_date(Some(due_date));
+            }
+
+            action_list.push_back(action);
+        }
+
+        Ok(action_list)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::functionality::tests::create_temporary_directory;
+
+    use std::fs;
+    use std::path::Path;
+
+    #[test]
+    fn successfully_read_action_csv() {
+        let action_list =
+            Vector::<Action>::read_from_csv(Path::new("data/successful_action_import_test.csv")).unwrap();
+
+        assert_eq!(action_list.len(), 1);
+        assert_eq!(action_list[0].get_name(), "test csv action");
+        assert_eq!(action_list[0].get_priority(), "Optional");
+        assert!(!action_list[0].get_completion_status());
+        assert!(action_list[0].get_id().is_nil());
+    }
+
+    #[test]
+    fn read_action_csv_with_reordered_columns() {
+        let action_list = Vector::<Action>::read_from_csv(Path::new("data/actions.csv")).unwrap();
+
+        assert_eq!(action_list[0].get_name(), "Default Action");
+        assert_eq!(
+            action_list[0].get_id().to_string(),
+            "dbb4ae71-810d-49ea-bd8a-d1f8a9ae0057"
+        );
+    }
+
+    #[test]
+    fn failed_read_bad_id() {
+        let error = Vector::<Action>::read_from_csv(Path::new("data/bad_id_test.csv")).unwrap_err();
+
+        assert_eq!(error.to_string(), "Row 1: 00000000-0000-0000-0000- is an Invalid Id");
+    }
+
+    #[test]
+    fn failed_read_bad_priority() {
+        let error = Vector::<Action>::read_from_csv(Path::new("data/bad_priority_test.csv")).unwrap_err();
+
+        assert_eq!(error.to_string(), "Row 1: bad priority is an Invalid Priority Option");
+    }
+
+    #[test]
+    fn failed_read_bad_completion_status() {
+        let error =
+            Vector::<Action>::read_from_csv(Path::new("data/bad_completion_status.csv")).unwrap_err();
+
+        assert_eq!(error.to_string(), "Row 1: bad complete is an Invalid Completion Status");
+    }
+
+    #[test]
+    fn successfully_write_action_csv() {
+        let action_list = Vector::<Action>::read_from_csv(Path::new("data/successful_import_test.csv"))
+            .unwrap()
+            .iter()
+            .map(|action| action.rename("Default Task").set_estimate(Some(3)))
+            .collect::<Vector<Action>>();
+        let file_path = create_temporary_directory("action_csv_export").join("actions.csv");
+
+        action_list.write_to_csv(&file_path).unwrap();
+
+        assert_eq!(
+            fs::read_to_string(&file_path).unwrap(),
+            fs::read_to_string("data/successful_export_test.csv").unwrap()
+        );
+    }
+}
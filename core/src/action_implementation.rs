@@ -4,6 +4,7 @@ use crate::action::Action;
 
 use std::error::Error;
 use uuid::Uuid;
+use chrono::NaiveDate;
 
 pub trait ActionListManipulation {
     type Item;
@@ -20,6 +21,20 @@ pub trait ActionListManipulation {
         index: usize,
         new_priority: String,
     ) -> Result<Self, Box<dyn Error>>
+    where
+        Self: Sized;
+    fn change_action_estimate(
+        &self,
+        index: usize,
+        new_estimate: Option<u32>,
+    ) -> Result<Self, Box<dyn Error>>
+    where
+        Self: Sized;
+    fn change_action_due_date(
+        &self,
+        index: usize,
+        new_due_date: Option<NaiveDate>,
+    ) -> Result<Self, Box<dyn Error>>
     where
         Self: Sized;
 
@@ -30,6 +45,8 @@ pub trait ActionListManipulation {
     fn get_action_priority(&self, index: usize) -> Result<String, Box<dyn Error>>;
     fn get_action_completion_status(&self, index: usize) -> Result<bool, Box<dyn Error>>;
     fn get_action_id(&self, index: usize) -> Result<Uuid, Box<dyn Error>>;
+    fn get_action_estimate(&self, index: usize) -> Result<Option<u32>, Box<dyn Error>>;
+    fn get_action_due_date(&self, index: usize) -> Result<Option<NaiveDate>, Box<dyn Error>>;
 
     fn remove_action(&self, index: usize) -> Result<Self, Box<dyn Error>>
     where
@@ -59,6 +76,34 @@ impl ClearHeadApp {
         Ok(new_app)
     }
 
+    pub fn change_action_estimate(
+            &self,
+            index: usize,
+            new_estimate: Option<u32>,
+        ) -> Result<Self, Box<dyn Error>>
+        where
+            Self: Sized {
+        let mut new_app = self.clone();
+
+        new_app.action_list = new_app.action_list.change_action_estimate(index, new_estimate)?;
+
+        Ok(new_app)
+    }
+
+    pub fn change_action_due_date(
+            &self,
+            index: usize,
+            new_due_date: Option<NaiveDate>,
+        ) -> Result<Self, Box<dyn Error>>
+        where
+            Self: Sized {
+        let mut new_app = self.clone();
+
+        new_app.action_list = new_app.action_list.change_action_due_date(index, new_due_date)?;
+
+        Ok(new_app)
+    }
+
     pub fn rename_action(&self, index: usize, new_name: String) -> Result<Self, Box<dyn Error>>
         where
             Self: Sized {
@@ -75,7 +120,7 @@ impl ClearHeadApp {
         let mut updated_list = self.clone();
 
         updated_list.action_list = updated_list.action_list.toggle_action_completion_status(index)?;
-        Ok(updated_list)
+        Ok(updated_list.roll_up_completion(self.get_action_id(index)?))
     }
 
     pub fn select_action_by_id(&self, id: Uuid) -> Result<Action, Box<dyn Error>> {
@@ -102,6 +147,14 @@ impl ClearHeadApp {
         self.action_list.get_action_id(index)
     }
 
+    pub fn get_action_estimate(&self, index: usize) -> Result<Option<u32>, Box<dyn Error>> {
+        self.action_list.get_action_estimate(index)
+    }
+
+    pub fn get_action_due_date(&self, index: usize) -> Result<Option<NaiveDate>, Box<dyn Error>> {
+        self.action_list.get_action_due_date(index)
+    }
+
     pub fn remove_action(&self, index: usize) -> Result<Self, Box<dyn Error>>
         where
             Self: Sized {
@@ -319,5 +372,41 @@ mod tests{
 
         assert_eq!(index_error.to_string(), "invalid is an Invalid Priority Option");
     }
-}
 
+    #[test]
+    fn change_action_estimate(){
+        let test_app = create_app_with_single_action();
+
+        let updated_app = test_app.change_action_estimate(0, Some(5)).unwrap();
+
+        assert_eq!(updated_app.get_action_estimate(0).unwrap(), Some(5));
+    }
+
+    #[test]
+    fn failed_change_action_estimate(){
+        let empty_app = ClearHeadApp::default();
+
+        let index_error = empty_app.change_action_estimate(0, Some(5)).unwrap_err();
+
+        assert_eq!(index_error.to_string(), failed_action_index_error(0));
+    }
+
+    #[test]
+    fn change_action_due_date(){
+        let test_app = create_app_with_single_action();
+        let due_date = NaiveDate::from_ymd_opt(2022, 10, 1).unwrap();
+
+        let updated_app = test_app.change_action_due_date(0, Some(due_date)).unwrap();
+
+        assert_eq!(updated_app.get_action_due_date(0).unwrap(), Some(due_date));
+    }
+
+    #[test]
+    fn failed_change_action_due_date(){
+        let empty_app = ClearHeadApp::default();
+
+        let index_error = empty_app.change_action_due_date(0, None).unwrap_err();
+
+        assert_eq!(index_error.to_string(), failed_action_index_error(0));
+    }
+}
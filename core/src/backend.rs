@@ -0,0 +1,233 @@
+use crate::functionality::ClearHeadApp;
+use crate::sqlite_storage::SQLiteStorage;
+use crate::storage::{JSONStorage, StorageError};
+
+use std::error::Error;
+use std::path::{Path, PathBuf};
+
+pub type AppChange<'a> = &'a dyn Fn(&ClearHeadApp) -> Result<ClearHeadApp, Box<dyn Error>>;
+
+pub trait StorageBackend {
+    fn load(&self) -> Result<ClearHeadApp, Box<dyn Error>>;
+    fn save(&mut self, app: &ClearHeadApp) -> Result<(), Box<dyn Error>>;
+    fn save_if_unchanged(&mut self, app: &ClearHeadApp) -> Result<ClearHeadApp, Box<dyn Error>>;
+
+    fn update(&mut self, change: AppChange) -> Result<ClearHeadApp, Box<dyn Error>> {
+        let stored_app = self.load()?;
+        let updated_app = change(&stored_app)?;
+
+        self.save_if_unchanged(&updated_app)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct JSONBackend {
+    file_path: PathBuf,
+    pretty_print: bool,
+    backup_count: usize,
+}
+
+impl JSONBackend {
+    pub fn new(file_path: &Path) -> JSONBackend {
+        JSONBackend {
+            file_path: file_path.to_path_buf(),
+            pretty_print: true,
+            backup_count: 0,
+        }
+    }
+
+    pub fn set_pretty_print(&self, pretty_print: bool) -> JSONBackend {
+        JSONBackend {
+            pretty_print,
+            ..self.clone()
+        }
+    }
+
+    pub fn set_backup_count(&self, backup_count: usize) -> JSONBackend {
+        JSONBackend {
+            backup_count,
+            ..self.clone()
+        }
+    }
+
+    pub fn get_file_path(&self) -> &Path {
+        &self.file_path
+    }
+}
+
+impl StorageBackend for JSONBackend {
+    fn load(&self) -> Result<ClearHeadApp, Box<dyn Error>> {
+        if !self.file_path.exists() {
+            return Ok(ClearHeadApp::default());
+        }
+
+        ClearHeadApp::read_from_json(&self.file_path)
+    }
+
+    fn save(&mut self, app: &ClearHeadApp) -> Result<(), Box<dyn Error>> {
+        app.write_to_json_with_backups(&self.file_path, self.pretty_print, self.backup_count)
+    }
+
+    fn save_if_unchanged(&mut self, app: &ClearHeadApp) -> Result<ClearHeadApp, Box<dyn Error>> {
+        app.write_to_json_if_unchanged(&self.file_path, self.pretty_print, self.backup_count)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SQLiteBackend {
+    database_path: PathBuf,
+}
+
+impl SQLiteBackend {
+    pub fn new(database_path: &Path) -> SQLiteBackend {
+        SQLiteBackend {
+            database_path: database_path.to_path_buf(),
+        }
+    }
+
+    pub fn get_database_path(&self) -> &Path {
+        &self.database_path
+    }
+}
+
+impl StorageBackend for SQLiteBackend {
+    fn load(&self) -> Result<ClearHeadApp, Box<dyn Error>> {
+        ClearHeadApp::read_from_sqlite(&self.database_path)
+    }
+
+    fn save(&mut self, app: &ClearHeadApp) -> Result<(), Box<dyn Error>> {
+        app.write_to_sqlite(&self.database_path)
+    }
+
+    fn save_if_unchanged(&mut self, app: &ClearHeadApp) -> Result<ClearHeadApp, Box<dyn Error>> {
+        app.write_to_sqlite_if_unchanged(&self.database_path)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MemoryBackend {
+    app: ClearHeadApp,
+}
+
+impl MemoryBackend {
+    pub fn new(app: ClearHeadApp) -> MemoryBackend {
+        MemoryBackend { app }
+    }
+}
+
+impl StorageBackend for MemoryBackend {
+    fn load(&self) -> Result<ClearHeadApp, Box<dyn Error>> {
+        Ok(self.app.clone())
+    }
+
+    fn save(&mut self, app: &ClearHeadApp) -> Result<(), Box<dyn Error>> {
+        self.app = app.clone();
+
+        Ok(())
+    }
+
+    fn save_if_unchanged(&mut self, app: &ClearHeadApp) -> Result<ClearHeadApp, Box<dyn Error>> {
+        if self.app.revision != app.revision {
+            return Err(StorageError::RevisionConflict {
+                expected: app.revision,
+                found: self.app.revision,
+            }
+            .into());
+        }
+
+        let mut updated_app = app.clone();
+        updated_app.revision += 1;
+        self.app = updated_app.clone();
+
+        Ok(updated_app)
+    }
+}
+
+impl ClearHeadApp {
+    pub fn load_from(backend: &dyn StorageBackend) -> Result<ClearHeadApp, Box<dyn Error>> {
+        backend.load()
+    }
+
+    pub fn save_to(&self, backend: &mut dyn StorageBackend) -> Result<ClearHeadApp, Box<dyn Error>> {
+        backend.save_if_unchanged(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::functionality::tests::{create_app_with_two_actions, create_temporary_directory};
+
+    fn round_trip(backend: &mut dyn StorageBackend) {
+        let test_app = create_app_with_two_actions();
+
+        backend.save(&test_app).unwrap();
+
+        assert_eq!(ClearHeadApp::load_from(backend).unwrap(), test_app);
+    }
+
+    #[test]
+    fn round_trip_every_backend() {
+        let directory = create_temporary_directory("backend_round_trip");
+
+        round_trip(&mut MemoryBackend::default());
+        round_trip(&mut JSONBackend::new(&directory.join("app.json")).set_pretty_print(false));
+        round_trip(&mut SQLiteBackend::new(&directory.join("app.db")));
+    }
+
+    #[test]
+    fn update_bumps_revision() {
+        let mut backend = MemoryBackend::default();
+
+        let updated_app = backend.update(&|app| Ok(app.append_default_action())).unwrap();
+
+        assert_eq!(updated_app.revision, 1);
+        assert_eq!(backend.load().unwrap(), updated_app);
+    }
+
+    #[test]
+    fn failed_save_after_concurrent_change() {
+        let mut backend = MemoryBackend::default();
+        let stale_app = backend.load().unwrap();
+        backend.update(&|app| Ok(app.append_default_action())).unwrap();
+
+        let conflict_error = stale_app.save_to(&mut backend).unwrap_err();
+
+        assert_eq!(
+            conflict_error.to_string(),
+            "Data file changed while this command ran (expected revision 0, found 1), please retry"
+        );
+    }
+
+    #[test]
+    fn failed_update_keeps_stored_app() {
+        let test_app = create_app_with_two_actions();
+        let mut backend = MemoryBackend::new(test_app.clone());
+
+        let update_error = backend
+            .update(&|app| app.rename_action(5, "Missing".to_string()))
+            .unwrap_err();
+
+        assert_eq!(update_error.to_string(), "No Action at Index 5");
+        assert_eq!(backend.load().unwrap(), test_app);
+    }
+
+    #[test]
+    fn load_empty_app_before_first_save() {
+        let directory = create_temporary_directory("backend_first_run");
+
+        assert_eq!(JSONBackend::new(&directory.join("app.json")).load().unwrap(), ClearHeadApp::default());
+        assert_eq!(SQLiteBackend::new(&directory.join("app.db")).load().unwrap(), ClearHeadApp::default());
+    }
+
+    #[test]
+    fn json_backend_keeps_backups() {
+        let file_path = create_temporary_directory("backend_backups").join("app.json");
+        let mut backend = JSONBackend::new(&file_path).set_backup_count(2);
+
+        backend.save(&ClearHeadApp::default()).unwrap();
+        backend.update(&|app| Ok(app.append_default_action())).unwrap();
+
+        assert_eq!(crate::storage::list_backups(&file_path).len(), 1);
+    }
+}
@@ -0,0 +1,471 @@
+use crate::functionality::ClearHeadApp;
+use crate::relationship::{RelationshipIndex, RelationshipVariant};
+use crate::ActionManipulation;
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, PartialEq, Eq, Default, Clone, Copy, Serialize, Deserialize)]
+pub struct GraphConstraints {
+    #[serde(default)]
+    pub single_parent: bool,
+    #[serde(default)]
+    pub acyclic_hierarchy: bool,
+    #[serde(default)]
+    pub acyclic_sequences: bool,
+    #[serde(default)]
+    pub same_project_sequences: bool,
+    #[serde(default)]
+    pub no_self_relationships: bool,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ConstraintViolation {
+    MultipleParents { child: Uuid, parent_count: usize },
+    ParentalCycle,
+    SequentialCycle,
+    CrossProjectSequence { predecessor: Uuid, successor: Uuid },
+    SelfRelationship { participant: Uuid },
+}
+
+impl GraphConstraints {
+    pub fn is_unconstrained(&self) -> bool {
+        *self == GraphConstraints::default()
+    }
+
+    pub fn set_constraint(&self, name: &str, enabled: bool) -> Result<GraphConstraints, Box<dyn Error>> {
+        let mut updated_constraints = *self;
+
+        match name.trim().to_lowercase().replace('-', "_").as_str() {
+            "single_parent" => updated_constraints.single_parent = enabled,
+            "acyclic_hierarchy" => updated_constraints.acyclic_hierarchy = enabled,
+            "acyclic_sequences" => updated_constraints.acyclic_sequences = enabled,
+            "same_project_sequences" => updated_constraints.same_project_sequences = enabled,
+            "no_self_relationships" => updated_constraints.no_self_relationships = enabled,
+            _ => return Err(format!("{} is not a graph constraint", name).into()),
+        }
+
+        Ok(updated_constraints)
+    }
+
+    pub fn validate(&self, app: &ClearHeadApp) -> Vec<ConstraintViolation> {
+        let relationship_index = app.get_relationship_index();
+        let mut violations = Vec::new();
+
+        if self.no_self_relationships {
+            violations.extend(
+                app.relationship_list
+                    .iter()
+                    .filter(|relationship| relationship.get_participant_1() == relationship.get_participant_2())
+                    .map(|relationship| ConstraintViolation::SelfRelationship {
+                        participant: relationship.get_participant_1(),
+                    }),
+            );
+        }
+
+        if self.single_parent {
+            let mut parent_counts: HashMap<Uuid, usize> = HashMap::new();
+            for relationship in app
+                .relationship_list
+                .iter()
+                .filter(|relationship| relationship.get_variant() == RelationshipVariant::create_parental())
+            {
+                *parent_counts.entry(relationship.get_participant_2()).or_default() += 1;
+            }
+            violations.extend(
+                app.action_list
+                    .iter()
+                    .filter_map(|action| parent_counts.get(&action.get_id()).map(|count| (action.get_id(), *count)))
+                    .filter(|(_, parent_count)| *parent_count > 1)
+                    .map(|(child, parent_count)| ConstraintViolation::MultipleParents { child, parent_count }),
+            );
+        }
+
+        if self.acyclic_hierarchy && contains_cycle(app, &relationship_index, &RelationshipVariant::create_parental()) {
+            violations.push(ConstraintViolation::ParentalCycle);
+        }
+
+        if self.acyclic_sequences && contains_cycle(app, &relationship_index, &RelationshipVariant::create_sequential()) {
+            violations.push(ConstraintViolation::SequentialCycle);
+        }
+
+        if self.same_project_sequences {
+            violations.extend(
+                app.relationship_list
+                    .iter()
+                    .filter(|relationship| relationship.get_variant() == RelationshipVariant::create_sequential())
+                    .filter(|relationship| {
+                        match (
+                            get_project_id(&relationship_index, relationship.get_participant_1()),
+                            get_project_id(&relationship_index, relationship.get_participant_2()),
+                        ) {
+                            (Some(project_1), Some(project_2)) => project_1 != project_2,
+                            _ => false,
+                        }
+                    })
+                    .map(|relationship| ConstraintViolation::CrossProjectSequence {
+                        predecessor: relationship.get_participant_1(),
+                        successor: relationship.get_participant_2(),
+                    }),
+            );
+        }
+
+        violations
+    }
+}
+
+impl Display for GraphConstraints {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let describe = |enabled: bool| if enabled { "on" } else { "off" };
+
+        writeln!(f, "single_parent: {}", describe(self.single_parent))?;
+        writeln!(f, "acyclic_hierarchy: {}", describe(self.acyclic_hierarchy))?;
+        writeln!(f, "acyclic_sequences: {}", describe(self.acyclic_sequences))?;
+        writeln!(f, "same_project_sequences: {}", describe(self.same_project_sequences))?;
+        writeln!(f, "no_self_relationships: {}", describe(self.no_self_relationships))
+    }
+}
+
+impl ConstraintViolation {
+    pub fn describe(&self, app: &ClearHeadApp) -> String {
+        match self {
+            ConstraintViolation::MultipleParents { child, parent_count } => format!(
+                "{} has {} parents, but actions may only have one parent",
+                app.get_participant_name(*child),
+                parent_count
+            ),
+            ConstraintViolation::ParentalCycle => "Parental relationships form a cycle".to_string(),
+            ConstraintViolation::SequentialCycle => "Sequential relationships form a cycle".to_string(),
+            ConstraintViolation::CrossProjectSequence {
+                predecessor,
+                successor,
+            } => format!(
+                "{} and {} belong to different projects, so they cannot be sequential",
+                app.get_participant_name(*predecessor),
+                app.get_participant_name(*successor)
+            ),
+            ConstraintViolation::SelfRelationship { participant } => format!(
+                "{} cannot have a relationship with itself",
+                app.get_participant_name(*participant)
+            ),
+        }
+    }
+}
+
+fn contains_cycle(
+    app: &ClearHeadApp,
+    relationship_index: &RelationshipIndex,
+    variant: &RelationshipVariant,
+) -> bool {
+    let mut participants: HashSet<Uuid> = HashSet::new();
+    let mut remaining_predecessors: HashMap<Uuid, usize> = HashMap::new();
+
+    for relationship in app
+        .relationship_list
+        .iter()
+        .filter(|relationship| relationship.get_variant() == *variant)
+    {
+        participants.insert(relationship.get_participant_1());
+        participants.insert(relationship.get_participant_2());
+        *remaining_predecessors.entry(relationship.get_participant_2()).or_default() += 1;
+    }
+
+    let mut ready: VecDeque<Uuid> = participants
+        .iter()
+        .filter(|id| !remaining_predecessors.contains_key(id))
+        .copied()
+        .collect();
+    let mut visited_count = 0;
+    while let Some(id) = ready.pop_front() {
+        visited_count += 1;
+        for successor in relationship_index.get_outgoing_ids(id, variant) {
+            let remaining = remaining_predecessors.get_mut(&successor).unwrap();
+            *remaining -= 1;
+            if *remaining == 0 {
+                ready.push_back(successor);
+            }
+        }
+    }
+
+    visited_count != participants.len()
+}
+
+fn get_project_id(relationship_index: &RelationshipIndex, id: Uuid) -> Option<Uuid> {
+    let parental_variant = RelationshipVariant::create_parental();
+    if relationship_index.get_incoming(id, &parental_variant).is_empty()
+        && relationship_index.get_outgoing(id, &parental_variant).is_empty()
+    {
+        return None;
+    }
+
+    let mut current_id = id;
+    let mut visited_ids: HashSet<Uuid> = HashSet::from([id]);
+    while let Some(parent_id) = relationship_index
+        .get_incoming_ids(current_id, &parental_variant)
+        .first()
+        .copied()
+    {
+        if !visited_ids.insert(parent_id) {
+            break;
+        }
+        current_id = parent_id;
+    }
+
+    Some(current_id)
+}
+
+impl ClearHeadApp {
+    pub fn set_graph_constraint(&self, name: &str, enabled: bool) -> Result<ClearHeadApp, Box<dyn Error>> {
+        let mut updated_app = self.clone();
+        updated_app.constraints = self.constraints.set_constraint(name, enabled)?;
+
+        Ok(updated_app)
+    }
+
+    pub fn validate_constraints(&self) -> Vec<ConstraintViolation> {
+        self.constraints.validate(self)
+    }
+
+    pub fn get_constraint_report(&self) -> String {
+        let violations = self.validate_constraints();
+        let mut report = self.constraints.to_string();
+
+        match violations.is_empty() {
+            true => report.push_str("No constraint violations found\n"),
+            false => {
+                report.push_str(&format!("{} constraint violation(s):\n", violations.len()));
+                for violation in violations {
+                    report.push_str(&format!("  - {}\n", violation.describe(self)));
+                }
+            }
+        }
+
+        report
+    }
+
+    pub(crate) fn enforce_constraints(&self, updated_app: ClearHeadApp) -> Result<ClearHeadApp, Box<dyn Error>> {
+        if updated_app.constraints.is_unconstrained() {
+            return Ok(updated_app);
+        }
+
+        let existing_violations = updated_app.constraints.validate(self);
+        match updated_app
+            .validate_constraints()
+            .into_iter()
+            .find(|violation| !existing_violations.contains(violation))
+        {
+            Some(violation) => Err(violation.describe(&updated_app).into()),
+            None => Ok(updated_app),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::functionality::tests::create_app_with_two_actions;
+    use crate::relationship::Relationship;
+    use crate::RelationshipListManagement;
+
+    fn create_constrained_app() -> ClearHeadApp {
+        ClearHeadApp::default()
+            .append_default_action()
+            .rename_action(0, "Home".to_string())
+            .unwrap()
+            .append_default_action()
+            .rename_action(1, "Work".to_string())
+            .unwrap()
+            .append_default_action()
+            .rename_action(2, "Paint Fence".to_string())
+            .unwrap()
+            .append_default_action()
+            .rename_action(3, "Write Report".to_string())
+            .unwrap()
+            .create_action_relationship("parental", 0, 2)
+            .unwrap()
+            .create_action_relationship("parental", 1, 3)
+            .unwrap()
+            .set_graph_constraint("single_parent", true)
+            .unwrap()
+            .set_graph_constraint("acyclic-hierarchy", true)
+            .unwrap()
+            .set_graph_constraint("same_project_sequences", true)
+            .unwrap()
+            .set_graph_constraint("no_self_relationships", true)
+            .unwrap()
+    }
+
+    #[test]
+    fn default_constraints_allow_everything() {
+        let test_app = create_app_with_two_actions()
+            .create_action_relationship("parental", 0, 1)
+            .unwrap()
+            .create_action_relationship("parental", 1, 0)
+            .unwrap();
+
+        assert!(test_app.constraints.is_unconstrained());
+        assert!(test_app.validate_constraints().is_empty());
+    }
+
+    #[test]
+    fn failed_set_unknown_constraint() {
+        let test_app = ClearHeadApp::default();
+
+        let constraint_error = test_app.set_graph_constraint("max_depth", true).unwrap_err();
+
+        assert_eq!(constraint_error.to_string(), "max_depth is not a graph constraint");
+    }
+
+    #[test]
+    fn reject_second_parent() {
+        let test_app = create_constrained_app();
+
+        let parent_error = test_app.create_action_relationship("parental", 1, 2).unwrap_err();
+
+        assert_eq!(
+            parent_error.to_string(),
+            "Paint Fence has 2 parents, but actions may only have one parent"
+        );
+    }
+
+    #[test]
+    fn reject_parental_cycle() {
+        let test_app = create_constrained_app();
+
+        let cycle_error = test_app.create_action_relationship("parental", 2, 0).unwrap_err();
+
+        assert_eq!(cycle_error.to_string(), "Parental relationships form a cycle");
+    }
+
+    #[test]
+    fn reject_appended_relationship() {
+        let test_app = create_constrained_app();
+        let home = test_app.get_action_id(0).unwrap();
+        let (paint_fence, write_report) = (test_app.get_action_id(2).unwrap(), test_app.get_action_id(3).unwrap());
+
+        let parental_error = test_app.append_parental_relationship(home, home).unwrap_err();
+        let sequential_error = test_app.append_sequential_relationship(paint_fence, write_report).unwrap_err();
+
+        assert_eq!(parental_error.to_string(), "Home cannot have a relationship with itself");
+        assert_eq!(
+            sequential_error.to_string(),
+            test_app.create_action_relationship("sequential", 2, 3).unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn reject_sequence_across_projects() {
+        let test_app = create_constrained_app();
+
+        let project_error = test_app.create_action_relationship("sequential", 2, 3).unwrap_err();
+
+        assert_eq!(
+            project_error.to_string(),
+            "Paint Fence and Write Report belong to different projects, so they cannot be sequential"
+        );
+    }
+
+    #[test]
+    fn allow_sequence_within_project() {
+        let test_app = create_constrained_app()
+            .append_default_action()
+            .create_action_relationship("parental", 0, 4)
+            .unwrap();
+
+        let updated_app = test_app.create_action_relationship("sequential", 2, 4).unwrap();
+
+        assert_eq!(updated_app.relationship_list.len(), 4);
+    }
+
+    #[test]
+    fn reject_self_relationship_on_retarget() {
+        let test_app = create_constrained_app();
+        let home_id = test_app.get_action_id(0).unwrap();
+
+        let self_error = test_app.update_relationship_participant_2(0, home_id).unwrap_err();
+
+        assert_eq!(self_error.to_string(), "Home cannot have a relationship with itself");
+    }
+
+    #[test]
+    fn reject_variant_change_creating_second_parent() {
+        let test_app = create_constrained_app()
+            .create_action_relationship("related", 1, 2)
+            .unwrap();
+
+        let parent_error = test_app.change_relationship_variant(2, "parental").unwrap_err();
+
+        assert_eq!(
+            parent_error.to_string(),
+            "Paint Fence has 2 parents, but actions may only have one parent"
+        );
+    }
+
+    #[test]
+    fn existing_violations_do_not_block_unrelated_changes() {
+        let test_app = create_app_with_two_actions()
+            .append_default_action()
+            .create_action_relationship("parental", 0, 2)
+            .unwrap()
+            .create_action_relationship("parental", 1, 2)
+            .unwrap()
+            .set_graph_constraint("single_parent", true)
+            .unwrap();
+
+        let updated_app = test_app.create_action_relationship("related", 0, 1).unwrap();
+
+        assert_eq!(updated_app.relationship_list.len(), 3);
+        assert_eq!(updated_app.validate_constraints().len(), 1);
+    }
+
+    #[test]
+    fn validate_existing_app() {
+        let test_app = ClearHeadApp {
+            constraints: GraphConstraints::default(),
+            ..create_constrained_app()
+        }
+        .create_action_relationship("parental", 1, 2)
+        .unwrap()
+        .create_action_relationship("parental", 2, 2)
+        .unwrap();
+
+        let violations = create_constrained_app().constraints.validate(&test_app);
+
+        assert_eq!(
+            violations,
+            vec![
+                ConstraintViolation::SelfRelationship {
+                    participant: test_app.get_action_id(2).unwrap()
+                },
+                ConstraintViolation::MultipleParents {
+                    child: test_app.get_action_id(2).unwrap(),
+                    parent_count: 3
+                },
+                ConstraintViolation::ParentalCycle,
+            ]
+        );
+    }
+
+    #[test]
+    fn print_constraint_report() {
+        let mut test_app = create_constrained_app()
+            .set_graph_constraint("same_project_sequences", false)
+            .unwrap()
+            .set_graph_constraint("acyclic_sequences", true)
+            .unwrap();
+        test_app
+            .relationship_list
+            .push_back(Relationship::create_new_sequential(Uuid::nil(), Uuid::nil()));
+
+        let report = test_app.get_constraint_report();
+
+        assert_eq!(
+            report,
+            "single_parent: on\nacyclic_hierarchy: on\nacyclic_sequences: on\nsame_project_sequences: off\nno_self_relationships: on\n2 constraint violation(s):\n  - 00000000-0000-0000-0000-000000000000 cannot have a relationship with itself\n  - Sequential relationships form a cycle\n"
+        );
+    }
+}
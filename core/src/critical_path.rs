@@ -0,0 +1,357 @@
+use crate::action::{Action, ActionError};
+use crate::functionality::ClearHeadApp;
+use crate::relationship::RelationshipVariant;
+use crate::ActionManipulation;
+
+use std::collections::{HashMap, VecDeque};
+use std::error::Error;
+
+use im::Vector;
+use tabled::object::Rows;
+use tabled::{Alignment, Footer, Header, Modify, Table, Tabled};
+use uuid::Uuid;
+
+pub const DEFAULT_DURATION: u32 = 1;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct ScheduledAction {
+    pub action: Action,
+    pub duration: u32,
+    pub earliest_start: u32,
+    pub earliest_finish: u32,
+    pub latest_start: u32,
+    pub latest_finish: u32,
+    pub slack: u32,
+}
+
+impl ScheduledAction {
+    pub fn is_critical(&self) -> bool {
+        self.slack == 0
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct CriticalPath {
+    pub schedule: Vector<ScheduledAction>,
+    pub critical_chain: Vector<Action>,
+    pub total_duration: u32,
+}
+
+#[derive(Tabled)]
+struct CriticalPathRow {
+    #[tabled(rename = "Name")]
+    name: String,
+    #[tabled(rename = "Duration")]
+    duration: u32,
+    #[tabled(rename = "Earliest Start")]
+    earliest_start: u32,
+    #[tabled(rename = "Latest Start")]
+    latest_start: u32,
+    #[tabled(rename = "Slack")]
+    slack: u32,
+    #[tabled(rename = "Critical")]
+    critical: String,
+}
+
+impl ClearHeadApp {
+    pub fn get_critical_path(&self) -> Result<CriticalPath, Box<dyn Error>> {
+        let action_count = self.action_list.len();
+        let positions: HashMap<Uuid, usize> = self
+            .action_list
+            .iter()
+            .enumerate()
+            .map(|(index, action)| (action.get_id(), index))
+            .collect();
+
+        let mut successors: Vec<Vec<usize>> = vec![Vec::new(); action_count];
+        let mut predecessors: Vec<Vec<usize>> = vec![Vec::new(); action_count];
+        for relationship in self
+            .relationship_list
+            .iter()
+            .filter(|relationship| relationship.get_variant() == RelationshipVariant::create_sequential())
+        {
+            let predecessor = *positions
+                .get(&relationship.get_participant_1())
+                .ok_or(ActionError::InvalidId(relationship.get_participant_1()))?;
+            let successor = *positions
+                .get(&relationship.get_participant_2())
+                .ok_or(ActionError::InvalidId(relationship.get_participant_2()))?;
+
+            successors[predecessor].push(successor);
+            predecessors[successor].push(predecessor);
+        }
+
+        let durations: Vec<u32> = self
+            .action_list
+            .iter()
+            .map(|action| action.get_estimate().unwrap_or(DEFAULT_DURATION))
+            .collect();
+
+        let mut remaining_predecessors: Vec<usize> =
+            predecessors.iter().map(|list| list.len()).collect();
+        let mut ready: VecDeque<usize> = (0..action_count)
+            .filter(|index| remaining_predecessors[*index] == 0)
+            .collect();
+        let mut topological_order = Vec::with_capacity(action_count);
+        while let Some(index) = ready.pop_front() {
+            topological_order.push(index);
+            for successor in &successors[index] {
+                remaining_predecessors[*successor] -= 1;
+                if remaining_predecessors[*successor] == 0 {
+                    ready.push_back(*successor);
+                }
+            }
+        }
+        if topological_order.len() != action_count {
+            return Err("Sequential relationships form a cycle, so no critical path exists".into());
+        }
+
+        let mut earliest_start: Vec<u32> = vec![0; action_count];
+        let mut earliest_finish = vec![0; action_count];
+        for index in &topological_order {
+            earliest_start[*index] = predecessors[*index]
+                .iter()
+                .map(|predecessor| earliest_finish[*predecessor])
+                .max()
+                .unwrap_or(0);
+            earliest_finish[*index] = earliest_start[*index]
+                .checked_add(durations[*index])
+                .ok_or("Estimates along a sequence of Actions overflow, so no critical path exists")?;
+        }
+
+        let total_duration = earliest_finish.iter().copied().max().unwrap_or(0);
+
+        let mut latest_finish = vec![total_duration; action_count];
+        for index in topological_order.iter().rev() {
+            latest_finish[*index] = successors[*index]
+                .iter()
+                .map(|successor| latest_finish[*successor] - durations[*successor])
+                .min()
+                .unwrap_or(total_duration);
+        }
+
+        let schedule: Vector<ScheduledAction> = self
+            .action_list
+            .iter()
+            .enumerate()
+            .map(|(index, action)| {
+                let latest_start = latest_finish[index] - durations[index];
+                ScheduledAction {
+                    action: action.clone(),
+                    duration: durations[index],
+                    earliest_start: earliest_start[index],
+                    earliest_finish: earliest_finish[index],
+                    latest_start,
+                    latest_finish: latest_finish[index],
+                    slack: latest_start - earliest_start[index],
+                }
+            })
+            .collect();
+
+        let mut critical_chain = Vector::new();
+        let mut current = topological_order
+            .iter()
+            .copied()
+            .find(|index| schedule[*index].is_critical() && predecessors[*index].is_empty());
+        while let Some(index) = current {
+            critical_chain.push_back(schedule[index].action.clone());
+            current = successors[index].iter().copied().find(|successor| {
+                schedule[*successor].is_critical()
+                    && schedule[*successor].earliest_start == schedule[index].earliest_finish
+            });
+        }
+
+        Ok(CriticalPath {
+            schedule,
+            critical_chain,
+            total_duration,
+        })
+    }
+
+    pub fn get_critical_path_report(&self) -> Result<String, Box<dyn Error>> {
+        let critical_path = self.get_critical_path()?;
+
+        let rows: Vec<CriticalPathRow> = critical_path
+            .schedule
+            .iter()
+            .map(|scheduled_action| CriticalPathRow {
+                name: scheduled_action.action.get_name(),
+                duration: scheduled_action.duration,
+                earliest_start: scheduled_action.earliest_start,
+                latest_start: scheduled_action.latest_start,
+                slack: scheduled_action.slack,
+                critical: if scheduled_action.is_critical() { "*".to_string() } else { String::new() },
+            })
+            .collect();
+
+        let chain = critical_path
+            .critical_chain
+            .iter()
+            .map(|action| action.get_name())
+            .collect::<Vec<String>>()
+            .join(" -> ");
+
+        Ok(Table::builder(&rows)
+            .index()
+            .build()
+            .with(Header("Critical Path"))
+            .with(Modify::new(Rows::first()).with(Alignment::center()))
+            .with(Footer(format!(
+                "Critical Chain: {} ({} unit(s))",
+                chain, critical_path.total_duration
+            )))
+            .with(Modify::new(Rows::last()).with(Alignment::center()))
+            .to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::functionality::tests::create_app_with_two_actions;
+    use crate::RelationshipListManagement;
+    use indoc::indoc;
+
+    fn create_app_with_branching_sequence() -> ClearHeadApp {
+        let app = ClearHeadApp::default()
+            .append_default_action()
+            .rename_action(0, "Design".to_string())
+            .unwrap()
+            .append_default_action()
+            .rename_action(1, "Build".to_string())
+            .unwrap()
+            .change_action_estimate(1, Some(3))
+            .unwrap()
+            .append_default_action()
+            .rename_action(2, "Write Docs".to_string())
+            .unwrap()
+            .append_default_action()
+            .rename_action(3, "Release".to_string())
+            .unwrap();
+
+        app.create_action_relationship("sequential", 0, 1)
+            .unwrap()
+            .create_action_relationship("sequential", 0, 2)
+            .unwrap()
+            .create_action_relationship("sequential", 1, 3)
+            .unwrap()
+            .create_action_relationship("sequential", 2, 3)
+            .unwrap()
+    }
+
+    #[test]
+    fn empty_app_has_empty_critical_path() {
+        let test_app = ClearHeadApp::default();
+
+        let critical_path = test_app.get_critical_path().unwrap();
+
+        assert_eq!(critical_path.total_duration, 0);
+        assert!(critical_path.critical_chain.is_empty());
+    }
+
+    #[test]
+    fn unrelated_actions_default_to_unit_duration() {
+        let test_app = create_app_with_two_actions();
+
+        let critical_path = test_app.get_critical_path().unwrap();
+
+        assert_eq!(critical_path.total_duration, DEFAULT_DURATION);
+        assert_eq!(critical_path.schedule[1].slack, 0);
+    }
+
+    #[test]
+    fn compute_schedule_for_branching_sequence() {
+        let test_app = create_app_with_branching_sequence();
+
+        let critical_path = test_app.get_critical_path().unwrap();
+
+        assert_eq!(critical_path.total_duration, 5);
+        assert_eq!(critical_path.schedule[2].earliest_start, 1);
+        assert_eq!(critical_path.schedule[2].latest_start, 3);
+        assert_eq!(critical_path.schedule[2].slack, 2);
+        assert_eq!(critical_path.schedule[3].earliest_start, 4);
+        assert_eq!(
+            critical_path
+                .critical_chain
+                .iter()
+                .map(|action| action.get_name())
+                .collect::<Vec<String>>(),
+            vec!["Design", "Build", "Release"]
+        );
+    }
+
+    #[test]
+    fn failed_critical_path_with_cycle() {
+        let test_app = create_app_with_two_actions()
+            .create_action_relationship("sequential", 0, 1)
+            .unwrap()
+            .create_action_relationship("sequential", 1, 0)
+            .unwrap();
+
+        let cycle_error = test_app.get_critical_path().unwrap_err();
+
+        assert_eq!(
+            cycle_error.to_string(),
+            "Sequential relationships form a cycle, so no critical path exists"
+        );
+    }
+
+    #[test]
+    fn failed_critical_path_with_overflowing_estimates() {
+        let test_app = create_app_with_two_actions()
+            .change_action_estimate(0, Some(u32::MAX))
+            .unwrap()
+            .change_action_estimate(1, Some(u32::MAX))
+            .unwrap()
+            .create_action_relationship("sequential", 0, 1)
+            .unwrap();
+
+        let overflow_error = test_app.get_critical_path().unwrap_err();
+
+        assert_eq!(
+            overflow_error.to_string(),
+            "Estimates along a sequence of Actions overflow, so no critical path exists"
+        );
+    }
+
+    #[test]
+    fn failed_critical_path_with_missing_action() {
+        let test_app = ClearHeadApp::default().append_sequential_relationship(Uuid::nil(), Uuid::nil()).unwrap();
+
+        let missing_error = test_app.get_critical_path().unwrap_err();
+
+        assert_eq!(
+            missing_error.to_string(),
+            format!("No Action with Id {}", Uuid::nil())
+        );
+    }
+
+    #[test]
+    fn print_critical_path_report() {
+        let test_app = create_app_with_branching_sequence();
+
+        let report = test_app.get_critical_path_report().unwrap();
+
+        assert_eq!(
+            report,
+            indoc!(
+                "
+                +---+------------+----------+----------------+--------------+-------+----------+
+                |                                Critical Path                                 |
+                +---+------------+----------+----------------+--------------+-------+----------+
+                |   | Name       | Duration | Earliest Start | Latest Start | Slack | Critical |
+                +---+------------+----------+----------------+--------------+-------+----------+
+                | 0 | Design     | 1        | 0              | 0            | 0     | *        |
+                +---+------------+----------+----------------+--------------+-------+----------+
+                | 1 | Build      | 3        | 1              | 1            | 0     | *        |
+                +---+------------+----------+----------------+--------------+-------+----------+
+                | 2 | Write Docs | 1        | 1              | 3            | 2     |          |
+                +---+------------+----------+----------------+--------------+-------+----------+
+                | 3 | Release    | 1        | 4              | 4            | 0     | *        |
+                +---+------------+----------+----------------+--------------+-------+----------+
+                |            Critical Chain: Design -> Build -> Release (5 unit(s))            |
+                +---+------------+----------+----------------+--------------+-------+----------+"
+            )
+        );
+    }
+}
@@ -0,0 +1,242 @@
+use crate::action::{Action, ActionError};
+use crate::functionality::ClearHeadApp;
+use crate::relationship::storage::read_relationships_from_csv;
+use crate::relationship::Relationship;
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+use csv::{ReaderBuilder, StringRecord, Trim};
+use im::Vector;
+
+pub trait CSVStorage: Sized {
+    fn write_csv<W: Write>(&self, writer: W) -> Result<(), Box<dyn Error>>;
+    fn read_csv<R: Read>(reader: R) -> Result<Self, Box<dyn Error>>;
+
+    fn write_to_csv(&self, file_path: &Path) -> Result<(), Box<dyn Error>> {
+        let file = File::create(file_path)?;
+
+        self.write_csv(BufWriter::new(file))
+    }
+
+    fn read_from_csv(file_path: &Path) -> Result<Self, Box<dyn Error>> {
+        let file = File::open(file_path)?;
+
+        Self::read_csv(BufReader::new(file))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum CSVError {
+    MissingColumn(String),
+    InvalidRow { row: usize, message: String },
+}
+
+impl Display for CSVError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            CSVError::MissingColumn(column) => write!(f, "CSV file has no {} column", column),
+            CSVError::InvalidRow { row, message } => write!(f, "Row {}: {}", row, message),
+        }
+    }
+}
+
+impl Error for CSVError {}
+
+pub(crate) struct CSVRows {
+    columns: HashMap<String, usize>,
+    records: Vec<(usize, StringRecord)>,
+}
+
+impl CSVRows {
+    pub(crate) fn read<R: Read>(reader: R) -> Result<CSVRows, Box<dyn Error>> {
+        let mut csv_reader = ReaderBuilder::new().trim(Trim::All).from_reader(reader);
+        let columns = csv_reader
+            .headers()?
+            .iter()
+            .enumerate()
+            .map(|(position, header)| (header.to_lowercase(), position))
+            .collect();
+
+        let mut records = Vec::new();
+        for (index, record) in csv_reader.records().enumerate() {
+            records.push((index + 1, record?));
+        }
+
+        Ok(CSVRows { columns, records })
+    }
+
+    pub(crate) fn find_column(&self, aliases: &[&str]) -> Option<usize> {
+        aliases.iter().find_map(|alias| self.columns.get(*alias).copied())
+    }
+
+    pub(crate) fn require_column(&self, aliases: &[&str]) -> Result<usize, CSVError> {
+        self.find_column(aliases)
+            .ok_or_else(|| CSVError::MissingColumn(aliases[0].to_string()))
+    }
+
+    pub(crate) fn get_records(&self) -> &Vec<(usize, StringRecord)> {
+        &self.records
+    }
+}
+
+pub(crate) fn get_field(record: &StringRecord, column: Option<usize>) -> Option<&str> {
+    column
+        .and_then(|position| record.get(position))
+        .filter(|field| !field.is_empty())
+}
+
+pub(crate) fn describe_row_error(row: usize, error: impl Display) -> Box<dyn Error> {
+    CSVError::InvalidRow {
+        row,
+        message: error.to_string(),
+    }
+    .into()
+}
+
+impl ClearHeadApp {
+    pub fn export_actions_to_csv(&self) -> Result<String, Box<dyn Error>> {
+        let mut buffer = Vec::new();
+        self.action_list.write_csv(&mut buffer)?;
+
+        Ok(String::from_utf8(buffer)?)
+    }
+
+    pub fn export_relationships_to_csv(&self) -> Result<String, Box<dyn Error>> {
+        let mut buffer = Vec::new();
+        self.relationship_list.write_csv(&mut buffer)?;
+
+        Ok(String::from_utf8(buffer)?)
+    }
+
+    pub fn import_actions_from_csv<R: Read>(&self, reader: R) -> Result<ClearHeadApp, Box<dyn Error>> {
+        let imported_actions = Vector::<Action>::read_csv(reader)?;
+        self.check_imported_ids(
+            imported_actions.iter().enumerate().map(|(row, action)| (row + 1, action)),
+            describe_row_error,
+        )?;
+
+        let mut updated_app = self.clone();
+        updated_app.action_list.append(imported_actions);
+
+        Ok(updated_app)
+    }
+
+    pub fn import_relationships_from_csv<R: Read>(&self, reader: R) -> Result<ClearHeadApp, Box<dyn Error>> {
+        let imported_relationships: Vector<Relationship> =
+            read_relationships_from_csv(reader, &self.custom_variants)?;
+
+        for (row, relationship) in imported_relationships.iter().enumerate() {
+            for participant in [relationship.get_participant_1(), relationship.get_participant_2()] {
+                self.select_action_by_id(participant)
+                    .map_err(|_| describe_row_error(row + 1, ActionError::InvalidId(participant)))?;
+            }
+        }
+
+        let mut updated_app = self.clone();
+        updated_app.relationship_list.append(imported_relationships);
+
+        self.enforce_constraints(updated_app)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::functionality::tests::create_app_with_two_actions;
+    use crate::RelationshipListManagement;
+
+    #[test]
+    fn missing_column_error() {
+        let error = Vector::<Action>::read_csv("name,priority\ntest,Optional\n".as_bytes()).unwrap_err();
+
+        assert_eq!(error.to_string(), "CSV file has no completed column");
+    }
+
+    #[test]
+    fn import_actions_into_app() {
+        let test_app = create_app_with_two_actions();
+
+        let updated_app = test_app
+            .import_actions_from_csv(
+                "name,priority,completed,id\nImported,High,true,00000000-0000-0000-0000-000000000000\n".as_bytes(),
+            )
+            .unwrap();
+
+        assert_eq!(updated_app.action_list.len(), 3);
+        assert_eq!(updated_app.get_action_name(2).unwrap(), "Imported");
+        assert!(updated_app.get_action_completion_status(2).unwrap());
+    }
+
+    #[test]
+    fn failed_import_duplicate_action_id() {
+        let test_app = create_app_with_two_actions();
+        let csv_contents = format!(
+            "name,priority,completed,id\nDuplicate,High,false,{}\n",
+            test_app.get_action_id(1).unwrap()
+        );
+
+        let error = test_app.import_actions_from_csv(csv_contents.as_bytes()).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            format!("Row 1: an Action with Id {} already exists", test_app.get_action_id(1).unwrap())
+        );
+    }
+
+    #[test]
+    fn import_relationships_into_app() {
+        let test_app = create_app_with_two_actions();
+        let csv_contents = format!(
+            "id,variant,participant_1,participant_2\n00000000-0000-0000-0000-000000000000,Parental: Directed,{},{}\n",
+            test_app.get_action_id(0).unwrap(),
+            test_app.get_action_id(1).unwrap()
+        );
+
+        let updated_app = test_app.import_relationships_from_csv(csv_contents.as_bytes()).unwrap();
+
+        assert_eq!(updated_app.relationship_list.len(), 1);
+        assert_eq!(updated_app.get_children_for_id(test_app.get_action_id(0).unwrap()).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn failed_import_relationship_with_unknown_participant() {
+        let test_app = create_app_with_two_actions();
+        let csv_contents = format!(
+            "id,variant,participant_1,participant_2\n00000000-0000-0000-0000-000000000000,related,{},00000000-0000-0000-0000-000000000000\n",
+            test_app.get_action_id(0).unwrap()
+        );
+
+        let error = test_app.import_relationships_from_csv(csv_contents.as_bytes()).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Row 1: No Action with Id 00000000-0000-0000-0000-000000000000"
+        );
+    }
+
+    #[test]
+    fn export_relationships_from_app() {
+        let test_app = create_app_with_two_actions()
+            .create_action_relationship("sequential", 0, 1)
+            .unwrap()
+            .set_relationship_label(0, Some("then"))
+            .unwrap();
+
+        let csv_contents = test_app.export_relationships_to_csv().unwrap();
+
+        assert_eq!(
+            csv_contents,
+            format!(
+                "id,variant,participant_1,participant_2,label,weight\n{},Sequential: Directed,{},{},then,\n",
+                test_app.relationship_list[0].get_id(),
+                test_app.get_action_id(0).unwrap(),
+                test_app.get_action_id(1).unwrap()
+            )
+        );
+    }
+}
@@ -0,0 +1,334 @@
+use crate::action::Action;
+use crate::functionality::ClearHeadApp;
+use crate::relationship::Relationship;
+use crate::ActionManipulation;
+
+use std::collections::{HashMap, HashSet};
+use std::fmt::{self, Display, Formatter};
+
+use uuid::Uuid;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldChange {
+    pub field: String,
+    pub before: String,
+    pub after: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModifiedItem {
+    pub id: Uuid,
+    pub changes: Vec<FieldChange>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AppDiff {
+    pub added_actions: Vec<Action>,
+    pub removed_actions: Vec<Action>,
+    pub modified_actions: Vec<ModifiedItem>,
+    pub added_relationships: Vec<Relationship>,
+    pub removed_relationships: Vec<Relationship>,
+    pub modified_relationships: Vec<ModifiedItem>,
+}
+
+impl AppDiff {
+    pub fn between(before: &ClearHeadApp, after: &ClearHeadApp) -> AppDiff {
+        let mut diff = AppDiff::default();
+
+        let previous_actions: HashMap<Uuid, &Action> = before
+            .action_list
+            .iter()
+            .map(|action| (action.get_id(), action))
+            .collect();
+        for action in after.action_list.iter() {
+            match previous_actions.get(&action.get_id()) {
+                Some(previous_action) => {
+                    let changes = compare_actions(previous_action, action);
+                    if !changes.is_empty() {
+                        diff.modified_actions.push(ModifiedItem {
+                            id: action.get_id(),
+                            changes,
+                        });
+                    }
+                }
+                None => diff.added_actions.push(action.clone()),
+            }
+        }
+        let kept_action_ids: HashSet<Uuid> = after.action_list.iter().map(|action| action.get_id()).collect();
+        diff.removed_actions = before
+            .action_list
+            .iter()
+            .filter(|previous| !kept_action_ids.contains(&previous.get_id()))
+            .cloned()
+            .collect();
+
+        let previous_relationships: HashMap<Uuid, &Relationship> = before
+            .relationship_list
+            .iter()
+            .map(|relationship| (relationship.get_id(), relationship))
+            .collect();
+        for relationship in after.relationship_list.iter() {
+            match previous_relationships.get(&relationship.get_id()) {
+                Some(previous_relationship) => {
+                    let changes = compare_relationships(previous_relationship, relationship);
+                    if !changes.is_empty() {
+                        diff.modified_relationships.push(ModifiedItem {
+                            id: relationship.get_id(),
+                            changes,
+                        });
+                    }
+                }
+                None => diff.added_relationships.push(relationship.clone()),
+            }
+        }
+        let kept_relationship_ids: HashSet<Uuid> = after
+            .relationship_list
+            .iter()
+            .map(|relationship| relationship.get_id())
+            .collect();
+        diff.removed_relationships = before
+            .relationship_list
+            .iter()
+            .filter(|previous| !kept_relationship_ids.contains(&previous.get_id()))
+            .cloned()
+            .collect();
+
+        diff
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self == &AppDiff::default()
+    }
+}
+
+fn compare_field(changes: &mut Vec<FieldChange>, field: &str, before: String, after: String) {
+    if before != after {
+        changes.push(FieldChange {
+            field: field.to_string(),
+            before,
+            after,
+        });
+    }
+}
+
+fn describe_optional<T: Display>(value: Option<T>) -> String {
+    value.map(|value| value.to_string()).unwrap_or_else(|| "none".to_string())
+}
+
+fn compare_actions(before: &Action, after: &Action) -> Vec<FieldChange> {
+    let mut changes = Vec::new();
+
+    compare_field(&mut changes, "name", before.get_name(), after.get_name());
+    compare_field(&mut changes, "priority", before.get_priority(), after.get_priority());
+    compare_field(
+        &mut changes,
+        "completed",
+        before.get_completion_status().to_string(),
+        after.get_completion_status().to_string(),
+    );
+    compare_field(
+        &mut changes,
+        "estimate",
+        describe_optional(before.get_estimate()),
+        describe_optional(after.get_estimate()),
+    );
+    compare_field(
+        &mut changes,
+        "due_date",
+        describe_optional(before.get_due_date()),
+        describe_optional(after.get_due_date()),
+    );
+
+    changes
+}
+
+fn compare_relationships(before: &Relationship, after: &Relationship) -> Vec<FieldChange> {
+    let mut changes = Vec::new();
+
+    compare_field(
+        &mut changes,
+        "variant",
+        before.get_variant().to_string(),
+        after.get_variant().to_string(),
+    );
+    compare_field(
+        &mut changes,
+        "participant_1",
+        before.get_participant_1().to_string(),
+        after.get_participant_1().to_string(),
+    );
+    compare_field(
+        &mut changes,
+        "participant_2",
+        before.get_participant_2().to_string(),
+        after.get_participant_2().to_string(),
+    );
+    compare_field(
+        &mut changes,
+        "label",
+        describe_optional(before.get_label()),
+        describe_optional(after.get_label()),
+    );
+    compare_field(
+        &mut changes,
+        "weight",
+        describe_optional(before.get_weight()),
+        describe_optional(after.get_weight()),
+    );
+
+    changes
+}
+
+fn describe_changes(changes: &[FieldChange]) -> String {
+    changes
+        .iter()
+        .map(|change| format!("{}: {} -> {}", change.field, change.before, change.after))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+impl Display for AppDiff {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "No Changes");
+        }
+
+        for action in &self.added_actions {
+            writeln!(f, "+ Action {} ({})", action.get_name(), action.get_id())?;
+        }
+        for action in &self.removed_actions {
+            writeln!(f, "- Action {} ({})", action.get_name(), action.get_id())?;
+        }
+        for modified_action in &self.modified_actions {
+            writeln!(
+                f,
+                "~ Action {}: {}",
+                modified_action.id,
+                describe_changes(&modified_action.changes)
+            )?;
+        }
+        for relationship in &self.added_relationships {
+            writeln!(
+                f,
+                "+ Relationship {} ({}: {} -> {})",
+                relationship.get_id(),
+                relationship.get_variant(),
+                relationship.get_participant_1(),
+                relationship.get_participant_2()
+            )?;
+        }
+        for relationship in &self.removed_relationships {
+            writeln!(
+                f,
+                "- Relationship {} ({}: {} -> {})",
+                relationship.get_id(),
+                relationship.get_variant(),
+                relationship.get_participant_1(),
+                relationship.get_participant_2()
+            )?;
+        }
+        for modified_relationship in &self.modified_relationships {
+            writeln!(
+                f,
+                "~ Relationship {}: {}",
+                modified_relationship.id,
+                describe_changes(&modified_relationship.changes)
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl ClearHeadApp {
+    pub fn diff(&self, other: &ClearHeadApp) -> AppDiff {
+        AppDiff::between(self, other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::functionality::tests::create_app_with_two_actions;
+    use crate::RelationshipListManagement;
+
+    #[test]
+    fn diff_identical_apps() {
+        let test_app = create_app_with_two_actions();
+
+        let diff = test_app.diff(&test_app);
+
+        assert!(diff.is_empty());
+        assert_eq!(diff.to_string(), "No Changes\n");
+    }
+
+    #[test]
+    fn diff_added_removed_and_modified_actions() {
+        let test_app = create_app_with_two_actions();
+        let updated_app = test_app
+            .remove_action(0)
+            .unwrap()
+            .rename_action(0, "Paint Fence".to_string())
+            .unwrap()
+            .append_default_action();
+
+        let diff = test_app.diff(&updated_app);
+
+        assert_eq!(diff.added_actions, vec![updated_app.action_list[1].clone()]);
+        assert_eq!(diff.removed_actions, vec![test_app.action_list[0].clone()]);
+        assert_eq!(
+            diff.modified_actions,
+            vec![ModifiedItem {
+                id: test_app.get_action_id(1).unwrap(),
+                changes: vec![FieldChange {
+                    field: "name".to_string(),
+                    before: "Default Action".to_string(),
+                    after: "Paint Fence".to_string(),
+                }],
+            }]
+        );
+    }
+
+    #[test]
+    fn diff_relationships() {
+        let test_app = create_app_with_two_actions()
+            .create_action_relationship("related", 0, 1)
+            .unwrap();
+        let updated_app = test_app
+            .set_relationship_label(0, Some("first coat"))
+            .unwrap()
+            .create_action_relationship("sequential", 0, 1)
+            .unwrap();
+
+        let diff = test_app.diff(&updated_app);
+
+        assert_eq!(diff.added_relationships, vec![updated_app.relationship_list[1].clone()]);
+        assert_eq!(
+            diff.to_string().lines().last().unwrap(),
+            format!(
+                "~ Relationship {}: label: none -> first coat",
+                test_app.relationship_list[0].get_id()
+            )
+        );
+    }
+
+    #[test]
+    fn display_field_changes() {
+        let test_app = create_app_with_two_actions();
+        let updated_app = test_app
+            .toggle_action_completion_status(0)
+            .unwrap()
+            .change_action_estimate(0, Some(2))
+            .unwrap();
+
+        let diff = test_app.diff(&updated_app);
+
+        assert_eq!(
+            diff.to_string(),
+            format!(
+                "~ Action {}: completed: false -> true, estimate: none -> 2\n",
+                test_app.get_action_id(0).unwrap()
+            )
+        );
+    }
+}
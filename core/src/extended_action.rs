@@ -1,9 +1,15 @@
 use crate::action::Action;
-use crate::Relationship;
+use crate::functionality::ClearHeadApp;
+use crate::relationship::RelationshipVariant;
+use crate::ActionManipulation;
+
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
 
 use im::Vector;
-use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
+#[derive(Debug, PartialEq, Clone)]
 pub struct ExtendedAction {
     pub action: Action,
 
@@ -12,9 +18,27 @@ pub struct ExtendedAction {
     pub predecessors: Vector<Action>,
     pub successors: Vector<Action>,
     pub related_actions: Vector<Action>,
+    pub custom_relationships: Vector<(String, Action)>,
 }
 
 impl ExtendedAction {
+    pub fn from_app_by_id(app: &ClearHeadApp, id: Uuid) -> Result<Self, Box<dyn Error>> {
+        let action = app.select_action_by_id(id)?;
+
+        ExtendedAction::default()
+            .set_action(&action)
+            .set_parent(app)?
+            .set_children(app)?
+            .set_predecessors(app)?
+            .set_successors(app)?
+            .set_related_actions(app)?
+            .set_custom_relationships(app)
+    }
+
+    pub fn from_app_by_index(app: &ClearHeadApp, index: usize) -> Result<Self, Box<dyn Error>> {
+        ExtendedAction::from_app_by_id(app, app.get_action_id(index)?)
+    }
+
     pub fn set_action(&self, action: &Action) -> Self {
         ExtendedAction {
             action: action.clone(),
@@ -23,18 +47,108 @@ impl ExtendedAction {
             predecessors: self.predecessors.clone(),
             successors: self.successors.clone(),
             related_actions: self.related_actions.clone(),
+            custom_relationships: self.custom_relationships.clone(),
         }
     }
 
-    pub fn set_parent(&self, relationship_list: Vector<Relationship>) -> Self {
-        ExtendedAction {
-            action: self.action.clone(),
-            parent: self.parent.clone(),
-            children: self.children.clone(),
-            predecessors: self.predecessors.clone(),
-            successors: self.successors.clone(),
-            related_actions: self.related_actions.clone(),
+    pub fn set_parent(&self, app: &ClearHeadApp) -> Result<Self, Box<dyn Error>> {
+        let parent = self
+            .get_incoming_actions(app, RelationshipVariant::create_parental())?
+            .pop_front();
+
+        Ok(ExtendedAction {
+            parent,
+            ..self.clone()
+        })
+    }
+
+    pub fn set_children(&self, app: &ClearHeadApp) -> Result<Self, Box<dyn Error>> {
+        Ok(ExtendedAction {
+            children: self.get_outgoing_actions(app, RelationshipVariant::create_parental())?,
+            ..self.clone()
+        })
+    }
+
+    pub fn set_predecessors(&self, app: &ClearHeadApp) -> Result<Self, Box<dyn Error>> {
+        Ok(ExtendedAction {
+            predecessors: self.get_incoming_actions(app, RelationshipVariant::create_sequential())?,
+            ..self.clone()
+        })
+    }
+
+    pub fn set_successors(&self, app: &ClearHeadApp) -> Result<Self, Box<dyn Error>> {
+        Ok(ExtendedAction {
+            successors: self.get_outgoing_actions(app, RelationshipVariant::create_sequential())?,
+            ..self.clone()
+        })
+    }
+
+    pub fn set_related_actions(&self, app: &ClearHeadApp) -> Result<Self, Box<dyn Error>> {
+        let mut related_actions = self.get_outgoing_actions(app, RelationshipVariant::create_related())?;
+        related_actions.append(self.get_incoming_actions(app, RelationshipVariant::create_related())?);
+
+        Ok(ExtendedAction {
+            related_actions,
+            ..self.clone()
+        })
+    }
+
+    pub fn set_custom_relationships(&self, app: &ClearHeadApp) -> Result<Self, Box<dyn Error>> {
+        let id = self.action.get_id();
+        let mut custom_relationships = Vector::new();
+
+        for relationship in app
+            .get_relationship_index()
+            .get_all_connected(id)
+            .into_iter()
+            .filter(|relationship| relationship.get_variant().is_custom())
+        {
+            let variant = relationship.get_variant();
+            if relationship.get_participant_1() == id {
+                let label = match &variant {
+                    RelationshipVariant::Custom(name, _) => name.clone(),
+                    built_in_variant => built_in_variant.to_string(),
+                };
+                custom_relationships.push_back((
+                    label,
+                    app.select_action_by_id(relationship.get_participant_2())?,
+                ));
+            } else if relationship.get_participant_2() == id {
+                custom_relationships.push_back((
+                    app.get_inverse_label(&variant),
+                    app.select_action_by_id(relationship.get_participant_1())?,
+                ));
+            }
         }
+
+        Ok(ExtendedAction {
+            custom_relationships,
+            ..self.clone()
+        })
+    }
+
+    fn get_outgoing_actions(
+        &self,
+        app: &ClearHeadApp,
+        variant: RelationshipVariant,
+    ) -> Result<Vector<Action>, Box<dyn Error>> {
+        app.get_relationship_index()
+            .get_outgoing_ids(self.action.get_id(), &variant)
+            .into_iter()
+            .map(|id| app.select_action_by_id(id))
+            .collect()
+    }
+
+    fn get_incoming_actions(
+        &self,
+        app: &ClearHeadApp,
+        variant: RelationshipVariant,
+    ) -> Result<Vector<Action>, Box<dyn Error>> {
+        app.get_relationship_index()
+            .get_incoming_ids(self.action.get_id(), &variant)
+            .into_iter()
+            .map(|id| app.select_action_by_id(id))
+            .collect()
     }
 }
 
@@ -47,7 +161,44 @@ impl Default for ExtendedAction {
             predecessors: Vector::new(),
             successors: Vector::new(),
             related_actions: Vector::new(),
+            custom_relationships: Vector::new(),
+        }
+    }
+}
+
+fn write_action_section(f: &mut Formatter, title: &str, actions: &Vector<Action>) -> fmt::Result {
+    writeln!(f, "{}:", title)?;
+    for action in actions {
+        writeln!(f, "  - {}", action)?;
+    }
+    Ok(())
+}
+
+impl Display for ExtendedAction {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        writeln!(f, "Name: {}", self.action.get_name())?;
+        writeln!(f, "Priority: {}", self.action.get_priority())?;
+        writeln!(f, "Completed: {}", self.action.get_completion_status())?;
+        match self.action.get_estimate() {
+            Some(estimate) => writeln!(f, "Estimate: {}", estimate)?,
+            None => writeln!(f, "Estimate: None")?,
+        }
+        writeln!(f, "Id: {}", self.action.get_id())?;
+        match &self.parent {
+            Some(parent) => writeln!(f, "Parent: {}", parent)?,
+            None => writeln!(f, "Parent: None")?,
+        }
+        write_action_section(f, "Children", &self.children)?;
+        write_action_section(f, "Predecessors", &self.predecessors)?;
+        write_action_section(f, "Successors", &self.successors)?;
+        write_action_section(f, "Related Actions", &self.related_actions)?;
+        if !self.custom_relationships.is_empty() {
+            writeln!(f, "Other Relationships:")?;
+            for (label, action) in &self.custom_relationships {
+                writeln!(f, "  - {}: {}", label, action)?;
+            }
         }
+        Ok(())
     }
 }
 
@@ -55,7 +206,28 @@ impl Default for ExtendedAction {
 mod tests {
     use super::*;
 
-    use crate::ActionManipulation;
+    use crate::functionality::tests::create_minimal_related_app;
+    use crate::RelationshipListManagement;
+    use indoc::formatdoc;
+
+    fn create_app_with_every_variant() -> ClearHeadApp {
+        ClearHeadApp::default()
+            .append_default_action()
+            .append_default_action()
+            .append_default_action()
+            .append_default_action()
+            .append_default_action()
+            .create_action_relationship("parental", 0, 1)
+            .unwrap()
+            .create_action_relationship("parental", 1, 2)
+            .unwrap()
+            .create_action_relationship("sequential", 3, 1)
+            .unwrap()
+            .create_action_relationship("sequential", 1, 4)
+            .unwrap()
+            .create_action_relationship("related", 4, 1)
+            .unwrap()
+    }
 
     #[test]
     fn create_default_extended_action() {
@@ -83,9 +255,137 @@ mod tests {
 
     #[test]
     fn populate_parent() {
-        let test_action = ExtendedAction::default();
+        let test_app = create_minimal_related_app("parental");
+
+        let test_extended_action = ExtendedAction::default()
+            .set_action(&test_app.action_list[1])
+            .set_parent(&test_app)
+            .unwrap();
+
+        assert_eq!(test_extended_action.parent, Some(test_app.action_list[0].clone()));
+    }
+
+    #[test]
+    fn populate_children() {
+        let test_app = create_minimal_related_app("parental");
+
+        let test_extended_action = ExtendedAction::default()
+            .set_action(&test_app.action_list[0])
+            .set_children(&test_app)
+            .unwrap();
+
+        assert_eq!(test_extended_action.children, Vector::unit(test_app.action_list[1].clone()));
+    }
+
+    #[test]
+    fn populate_sequence() {
+        let test_app = create_minimal_related_app("sequential");
+
+        let first_action = ExtendedAction::from_app_by_index(&test_app, 0).unwrap();
+        let second_action = ExtendedAction::from_app_by_index(&test_app, 1).unwrap();
+
+        assert_eq!(first_action.successors, Vector::unit(test_app.action_list[1].clone()));
+        assert_eq!(second_action.predecessors, Vector::unit(test_app.action_list[0].clone()));
+        assert_eq!(first_action.predecessors, Vector::new());
+    }
+
+    #[test]
+    fn populate_related_actions_in_both_directions() {
+        let test_app = create_minimal_related_app("related");
+
+        let first_action = ExtendedAction::from_app_by_index(&test_app, 0).unwrap();
+        let second_action = ExtendedAction::from_app_by_index(&test_app, 1).unwrap();
+
+        assert_eq!(first_action.related_actions, Vector::unit(test_app.action_list[1].clone()));
+        assert_eq!(second_action.related_actions, Vector::unit(test_app.action_list[0].clone()));
+    }
+
+    #[test]
+    fn hydrate_every_field() {
+        let test_app = create_app_with_every_variant();
+
+        let test_extended_action = ExtendedAction::from_app_by_id(
+            &test_app, test_app.action_list[1].get_id()).unwrap();
+
+        assert_eq!(test_extended_action.action, test_app.action_list[1]);
+        assert_eq!(test_extended_action.parent, Some(test_app.action_list[0].clone()));
+        assert_eq!(test_extended_action.children, Vector::unit(test_app.action_list[2].clone()));
+        assert_eq!(test_extended_action.predecessors, Vector::unit(test_app.action_list[3].clone()));
+        assert_eq!(test_extended_action.successors, Vector::unit(test_app.action_list[4].clone()));
+        assert_eq!(test_extended_action.related_actions, Vector::unit(test_app.action_list[4].clone()));
+    }
+
+    #[test]
+    fn populate_custom_relationships_with_inverse_label() {
+        let test_app = ClearHeadApp::default()
+            .append_default_action()
+            .append_default_action()
+            .register_custom_variant("blocks", "directed", Some("blocked by"), Vector::new())
+            .unwrap()
+            .create_action_relationship("blocks", 0, 1)
+            .unwrap();
+
+        let blocking_action = ExtendedAction::from_app_by_index(&test_app, 0).unwrap();
+        let blocked_action = ExtendedAction::from_app_by_index(&test_app, 1).unwrap();
+
+        assert_eq!(
+            blocking_action.custom_relationships,
+            Vector::unit(("blocks".to_string(), test_app.action_list[1].clone()))
+        );
+        assert_eq!(
+            blocked_action.custom_relationships,
+            Vector::unit(("blocked by".to_string(), test_app.action_list[0].clone()))
+        );
+        assert_eq!(blocked_action.related_actions, Vector::new());
+    }
+
+    #[test]
+    fn failed_hydrate_with_bad_index() {
+        let empty_app = ClearHeadApp::default();
+
+        let index_error = ExtendedAction::from_app_by_index(&empty_app, 0).unwrap_err();
+
+        assert_eq!(index_error.to_string(), "No Action at Index 0");
+    }
+
+    #[test]
+    fn failed_hydrate_with_missing_participant() {
+        let missing_id = Uuid::new_v4();
+        let test_app = ClearHeadApp::default().append_default_action();
+        let test_app = test_app
+            .append_parental_relationship(test_app.action_list[0].get_id(), missing_id)
+            .unwrap();
+
+        let missing_error = ExtendedAction::from_app_by_index(&test_app, 0).unwrap_err();
+
+        assert_eq!(missing_error.to_string(), format!("No Action with Id {}", missing_id));
+    }
+
+    #[test]
+    fn print_extended_action() {
+        let test_app = create_minimal_related_app("parental");
 
-        //let test_parent = test_action.populate_parent();
+        let test_extended_action = ExtendedAction::from_app_by_index(&test_app, 0).unwrap();
 
+        assert_eq!(
+            test_extended_action.to_string(),
+            formatdoc!(
+                "
+                Name: Default Action
+                Priority: Optional
+                Completed: false
+                Estimate: None
+                Id: {}
+                Parent: None
+                Children:
+                  - {}
+                Predecessors:
+                Successors:
+                Related Actions:
+                ",
+                test_app.action_list[0].get_id(),
+                test_app.action_list[1]
+            )
+        );
     }
 }
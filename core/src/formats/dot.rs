@@ -0,0 +1,253 @@
+use crate::action::{Action, Priority};
+use crate::functionality::ClearHeadApp;
+use crate::relationship::item::EdgeDirectionality;
+use crate::relationship::{Relationship, RelationshipVariant};
+use crate::ActionManipulation;
+
+use std::error::Error;
+use std::str::FromStr;
+
+use uuid::Uuid;
+
+pub fn escape_dot_string(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+fn get_priority_color(priority: Priority) -> &'static str {
+    match priority {
+        Priority::Critical => "tomato",
+        Priority::High => "orange",
+        Priority::Medium => "khaki",
+        Priority::Low => "lightblue",
+        Priority::Optional => "lightgrey",
+    }
+}
+
+fn get_variant_style(variant: &RelationshipVariant) -> &'static str {
+    match variant {
+        RelationshipVariant::Parental(_) => "style=solid, color=black",
+        RelationshipVariant::Sequential(_) => "style=dashed, color=blue",
+        RelationshipVariant::Related(_) => "style=dotted, color=darkgreen",
+        RelationshipVariant::Custom(_, _) => "style=bold, color=purple",
+    }
+}
+
+fn get_direction_attribute(variant: &RelationshipVariant) -> &'static str {
+    match variant {
+        RelationshipVariant::Parental(direction)
+        | RelationshipVariant::Sequential(direction)
+        | RelationshipVariant::Related(direction)
+        | RelationshipVariant::Custom(_, direction) => match direction {
+            EdgeDirectionality::Directed => "dir=forward",
+            EdgeDirectionality::Undirected => "dir=none",
+        },
+    }
+}
+
+fn create_dot_node(action: &Action) -> Result<String, Box<dyn Error>> {
+    let priority = Priority::from_str(&action.get_priority())?;
+    let completion_style = match action.get_completion_status() {
+        true => ", style=\"filled,dashed\", fontcolor=gray40",
+        false => "",
+    };
+
+    Ok(format!(
+        "  \"{}\" [label=\"{}\\n{}\", fillcolor={}{}];\n",
+        action.get_id(),
+        escape_dot_string(&action.get_name()),
+        priority,
+        get_priority_color(priority),
+        completion_style
+    ))
+}
+
+fn create_dot_edge(relationship: &Relationship) -> String {
+    let variant = relationship.get_variant();
+    let label = match (relationship.get_label(), &variant) {
+        (Some(label), _) => format!(", label=\"{}\"", escape_dot_string(&label)),
+        (None, RelationshipVariant::Custom(name, _)) => {
+            format!(", label=\"{}\"", escape_dot_string(name))
+        }
+        (None, _) => String::new(),
+    };
+
+    format!(
+        "  \"{}\" -> \"{}\" [{}, {}{}];\n",
+        relationship.get_participant_1(),
+        relationship.get_participant_2(),
+        get_variant_style(&variant),
+        get_direction_attribute(&variant),
+        label
+    )
+}
+
+impl ClearHeadApp {
+    pub fn export_to_dot(&self, root: Option<Uuid>) -> Result<String, Box<dyn Error>> {
+        let included_ids = self.get_export_ids(root)?;
+
+        let mut dot_graph = String::new();
+        dot_graph.push_str("digraph ClearHead {\n");
+        dot_graph.push_str("  node [shape=box, style=filled];\n");
+
+        for action in self
+            .action_list
+            .iter()
+            .filter(|action| included_ids.contains(&action.get_id()))
+        {
+            dot_graph.push_str(&create_dot_node(action)?);
+        }
+
+        for relationship in self.relationship_list.iter().filter(|relationship| {
+            included_ids.contains(&relationship.get_participant_1())
+                && included_ids.contains(&relationship.get_participant_2())
+        }) {
+            dot_graph.push_str(&create_dot_edge(relationship));
+        }
+
+        dot_graph.push_str("}\n");
+        Ok(dot_graph)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::functionality::tests::{create_app_with_two_actions, create_minimal_related_app};
+    use crate::RelationshipListManagement;
+    use indoc::formatdoc;
+
+    #[test]
+    fn export_empty_app() {
+        let test_app = ClearHeadApp::default();
+
+        let dot_graph = test_app.export_to_dot(None).unwrap();
+
+        assert_eq!(
+            dot_graph,
+            "digraph ClearHead {\n  node [shape=box, style=filled];\n}\n"
+        );
+    }
+
+    #[test]
+    fn export_styled_nodes() {
+        let test_app = create_app_with_two_actions()
+            .change_action_priority(0, "critical".to_string())
+            .unwrap()
+            .toggle_action_completion_status(1)
+            .unwrap();
+
+        let dot_graph = test_app.export_to_dot(None).unwrap();
+
+        assert_eq!(
+            dot_graph,
+            formatdoc!(
+                "
+                digraph ClearHead {{
+                  node [shape=box, style=filled];
+                  \"{}\" [label=\"Default Action\\nCritical\", fillcolor=tomato];
+                  \"{}\" [label=\"Default Action\\nOptional\", fillcolor=lightgrey, style=\"filled,dashed\", fontcolor=gray40];
+                }}
+                ",
+                test_app.action_list[0].get_id(),
+                test_app.action_list[1].get_id()
+            )
+        );
+    }
+
+    #[test]
+    fn export_parental_edge() {
+        let test_app = create_minimal_related_app("parental");
+
+        let dot_graph = test_app.export_to_dot(None).unwrap();
+
+        assert!(dot_graph.contains(&format!(
+            "  \"{}\" -> \"{}\" [style=solid, color=black, dir=forward];\n",
+            test_app.action_list[0].get_id(),
+            test_app.action_list[1].get_id()
+        )));
+    }
+
+    #[test]
+    fn export_sequential_edge() {
+        let test_app = create_minimal_related_app("sequential");
+
+        let dot_graph = test_app.export_to_dot(None).unwrap();
+
+        assert!(dot_graph.contains("[style=dashed, color=blue, dir=forward];"));
+    }
+
+    #[test]
+    fn export_related_edge_without_direction() {
+        let test_app = create_minimal_related_app("related");
+
+        let dot_graph = test_app.export_to_dot(None).unwrap();
+
+        assert!(dot_graph.contains("[style=dotted, color=darkgreen, dir=none];"));
+    }
+
+    #[test]
+    fn export_custom_and_labelled_edges() {
+        let test_app = create_app_with_two_actions()
+            .register_custom_variant("blocks", "directed", None, im::Vector::new())
+            .unwrap()
+            .create_action_relationship("blocks", 0, 1)
+            .unwrap()
+            .create_action_relationship("related", 0, 1)
+            .unwrap()
+            .set_relationship_label(1, Some("same \"client\""))
+            .unwrap();
+
+        let dot_graph = test_app.export_to_dot(None).unwrap();
+
+        assert!(dot_graph.contains("[style=bold, color=purple, dir=forward, label=\"blocks\"];"));
+        assert!(dot_graph.contains("dir=none, label=\"same \\\"client\\\"\"];"));
+    }
+
+    #[test]
+    fn export_subgraph_rooted_at_action() {
+        let test_app = ClearHeadApp::default()
+            .append_default_action()
+            .append_default_action()
+            .append_default_action()
+            .create_action_relationship("parental", 1, 2)
+            .unwrap()
+            .create_action_relationship("sequential", 0, 1)
+            .unwrap();
+
+        let dot_graph = test_app
+            .export_to_dot(Some(test_app.action_list[1].get_id()))
+            .unwrap();
+
+        assert!(!dot_graph.contains(&test_app.action_list[0].get_id().to_string()));
+        assert!(dot_graph.contains(&test_app.action_list[2].get_id().to_string()));
+        assert_eq!(dot_graph.matches(" -> ").count(), 1);
+    }
+
+    #[test]
+    fn failed_export_with_unknown_root() {
+        let test_app = create_app_with_two_actions();
+
+        let missing_error = test_app.export_to_dot(Some(Uuid::nil())).unwrap_err();
+
+        assert_eq!(missing_error.to_string(), format!("No Action with Id {}", Uuid::nil()));
+    }
+
+    #[test]
+    fn skip_edges_to_missing_actions() {
+        let test_app = ClearHeadApp::default()
+            .append_default_action()
+            .append_related_relationship(Uuid::nil(), Uuid::nil())
+            .unwrap();
+
+        let dot_graph = test_app.export_to_dot(None).unwrap();
+
+        assert_eq!(dot_graph.matches(" -> ").count(), 0);
+    }
+
+    #[test]
+    fn escape_special_characters() {
+        let escaped = escape_dot_string("say \"hi\"\\\n");
+
+        assert_eq!(escaped, "say \\\"hi\\\"\\\\\\n");
+    }
+}
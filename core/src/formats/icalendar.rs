@@ -0,0 +1,439 @@
+use crate::action::{Action, Priority};
+use crate::formats::describe_line_error;
+use crate::functionality::ClearHeadApp;
+use crate::relationship::{Relationship, RelationshipVariant};
+use crate::ActionManipulation;
+
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+use std::io::{BufRead, BufReader, Read};
+use std::str::FromStr;
+
+use chrono::{DateTime, NaiveDate, Utc};
+use uuid::Uuid;
+
+const ICALENDAR_PRODUCT_ID: &str = "-//ClearHead//ClearHead Todo//EN";
+const ESTIMATE_PROPERTY: &str = "X-CLEARHEAD-ESTIMATE";
+const MAXIMUM_LINE_OCTETS: usize = 75;
+
+fn get_icalendar_priority(priority: &str) -> u8 {
+    match Priority::from_str(priority).unwrap_or_default() {
+        Priority::Critical => 1,
+        Priority::High => 3,
+        Priority::Medium => 5,
+        Priority::Low => 7,
+        Priority::Optional => 9,
+    }
+}
+
+fn get_priority_from_icalendar(level: u8) -> Option<Priority> {
+    match level {
+        1..=2 => Some(Priority::Critical),
+        3..=4 => Some(Priority::High),
+        5 => Some(Priority::Medium),
+        6..=7 => Some(Priority::Low),
+        0 | 8..=9 => Some(Priority::Optional),
+        _ => None,
+    }
+}
+
+pub fn escape_icalendar_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+fn unescape_icalendar_text(text: &str) -> String {
+    let mut unescaped = String::new();
+    let mut characters = text.chars();
+    while let Some(character) = characters.next() {
+        if character != '\\' {
+            unescaped.push(character);
+            continue;
+        }
+        match characters.next() {
+            Some('n') | Some('N') => unescaped.push('\n'),
+            Some(escaped) => unescaped.push(escaped),
+            None => unescaped.push('\\'),
+        }
+    }
+
+    unescaped
+}
+
+// content lines longer than 75 octets continue on the next line after a single space
+fn fold_line(line: &str) -> String {
+    let mut folded = String::new();
+    let mut line_octets = 0;
+    for character in line.chars() {
+        if line_octets + character.len_utf8() > MAXIMUM_LINE_OCTETS {
+            folded.push_str("\r\n ");
+            line_octets = 1;
+        }
+        folded.push(character);
+        line_octets += character.len_utf8();
+    }
+    folded.push_str("\r\n");
+
+    folded
+}
+
+fn format_icalendar_time(time: DateTime<Utc>) -> String {
+    time.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+struct ContentLine {
+    line: usize,
+    name: String,
+    parameters: HashMap<String, String>,
+    value: String,
+}
+
+fn parse_content_line(line_number: usize, line: &str) -> Result<ContentLine, Box<dyn Error>> {
+    let mut in_quotes = false;
+    let separator = line
+        .char_indices()
+        .find(|(_, character)| {
+            if *character == '"' {
+                in_quotes = !in_quotes;
+            }
+            *character == ':' && !in_quotes
+        })
+        .map(|(position, _)| position)
+        .ok_or_else(|| describe_line_error(line_number, "expected NAME:VALUE"))?;
+
+    let mut parts = line[..separator].split(';');
+    let name = parts.next().unwrap_or_default().to_ascii_uppercase();
+    let parameters = parts
+        .filter_map(|parameter| parameter.split_once('='))
+        .map(|(key, value)| (key.to_ascii_uppercase(), value.trim_matches('"').to_string()))
+        .collect();
+
+    Ok(ContentLine {
+        line: line_number,
+        name,
+        parameters,
+        value: line[separator + 1..].to_string(),
+    })
+}
+
+fn unfold_lines<R: Read>(reader: R) -> Result<Vec<(usize, String)>, Box<dyn Error>> {
+    let mut lines: Vec<(usize, String)> = Vec::new();
+    for (index, line) in BufReader::new(reader).lines().enumerate() {
+        let line = line?;
+        let line = line.trim_end_matches('\r');
+        match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(continuation), Some((_, previous))) => previous.push_str(continuation),
+            _ if line.is_empty() => {}
+            _ => lines.push((index + 1, line.to_string())),
+        }
+    }
+
+    Ok(lines)
+}
+
+struct VTodo {
+    line: usize,
+    uid: Option<String>,
+    action: Action,
+    related: Vec<(String, String)>,
+}
+
+fn read_vtodo(line_number: usize, properties: &[ContentLine]) -> Result<VTodo, Box<dyn Error>> {
+    let mut todo = VTodo {
+        line: line_number,
+        uid: None,
+        action: Action::default().rename(""),
+        related: Vec::new(),
+    };
+    let mut priority = Priority::Optional;
+    let mut completed = false;
+
+    for property in properties {
+        match property.name.as_str() {
+            "UID" => todo.uid = Some(property.value.clone()),
+            "SUMMARY" => todo.action = todo.action.rename(&unescape_icalendar_text(&property.value)),
+            "PRIORITY" => {
+                priority = property
+                    .value
+                    .trim()
+                    .parse::<u8>()
+                    .ok()
+                    .and_then(get_priority_from_icalendar)
+                    .ok_or_else(|| {
+                        describe_line_error(property.line, format!("{} is an Invalid Priority", property.value))
+                    })?;
+            }
+            "STATUS" => completed = completed || property.value.eq_ignore_ascii_case("COMPLETED"),
+            "COMPLETED" => completed = true,
+            "PERCENT-COMPLETE" => completed = completed || property.value.trim() == "100",
+            "DUE" => {
+                let due_date = property
+                    .value
+                    .get(..8)
+                    .and_then(|date| NaiveDate::parse_from_str(date, "%Y%m%d").ok())
+                    .ok_or_else(|| {
+                        describe_line_error(property.line, format!("{} is an Invalid Due Date", property.value))
+                    })?;
+                todo.action = todo.action.set_due_date(Some(due_date));
+            }
+            ESTIMATE_PROPERTY => {
+                let estimate = property.value.trim().parse::<u32>().map_err(|_| {
+                    describe_line_error(property.line, format!("{} is an Invalid Estimate", property.value))
+                })?;
+                todo.action = todo.action.set_estimate(Some(estimate));
+            }
+            "RELATED-TO" => todo.related.push((
+                property
+                    .parameters
+                    .get("RELTYPE")
+                    .map(|relationship_type| relationship_type.to_ascii_uppercase())
+                    .unwrap_or_else(|| "PARENT".to_string()),
+                property.value.clone(),
+            )),
+            _ => {}
+        }
+    }
+
+    if todo.action.get_name().is_empty() {
+        todo.action = todo.action.rename("Untitled");
+    }
+    todo.action = todo.action.change_priority(&priority.to_string())?;
+    if completed {
+        todo.action = todo.action.toggle_completion_status();
+    }
+    if let Some(id) = todo.uid.as_deref().and_then(|uid| Uuid::parse_str(uid).ok()) {
+        todo.action = todo.action.set_id(id);
+    }
+
+    Ok(todo)
+}
+
+fn read_vtodos<R: Read>(reader: R) -> Result<Vec<VTodo>, Box<dyn Error>> {
+    let mut todos = Vec::new();
+    let mut current_todo: Option<(usize, Vec<ContentLine>, usize)> = None;
+
+    for (line_number, line) in unfold_lines(reader)? {
+        let content_line = parse_content_line(line_number, &line)?;
+        let component = content_line.value.to_ascii_uppercase();
+        match (&mut current_todo, content_line.name.as_str()) {
+            (None, "BEGIN") if component == "VTODO" => current_todo = Some((line_number, Vec::new(), 0)),
+            (Some((_, _, depth)), "BEGIN") => *depth += 1,
+            (Some((_, _, depth)), "END") if *depth > 0 => *depth -= 1,
+            (Some((start, properties, _)), "END") => {
+                todos.push(read_vtodo(*start, properties)?);
+                current_todo = None;
+            }
+            (Some((_, properties, 0)), _) => properties.push(content_line),
+            _ => {}
+        }
+    }
+
+    match current_todo {
+        Some((start, _, _)) => Err(describe_line_error(start, "VTODO is never ended")),
+        None => Ok(todos),
+    }
+}
+
+impl ClearHeadApp {
+    pub fn export_to_icalendar(&self, timestamp: DateTime<Utc>) -> String {
+        let mut calendar = String::new();
+        calendar.push_str("BEGIN:VCALENDAR\r\n");
+        calendar.push_str("VERSION:2.0\r\n");
+        calendar.push_str(&fold_line(&format!("PRODID:{}", ICALENDAR_PRODUCT_ID)));
+
+        for action in self.action_list.iter() {
+            let id = action.get_id();
+            calendar.push_str("BEGIN:VTODO\r\n");
+            calendar.push_str(&format!("UID:{}\r\n", id));
+            calendar.push_str(&format!("DTSTAMP:{}\r\n", format_icalendar_time(timestamp)));
+            calendar.push_str(&fold_line(&format!("SUMMARY:{}", escape_icalendar_text(&action.get_name()))));
+            calendar.push_str(&format!("PRIORITY:{}\r\n", get_icalendar_priority(&action.get_priority())));
+            match action.get_completion_status() {
+                true => calendar.push_str("STATUS:COMPLETED\r\n"),
+                false => calendar.push_str("STATUS:NEEDS-ACTION\r\n"),
+            }
+            if let Some(due_date) = action.get_due_date() {
+                calendar.push_str(&format!("DUE;VALUE=DATE:{}\r\n", due_date.format("%Y%m%d")));
+            }
+            if let Some(estimate) = action.get_estimate() {
+                calendar.push_str(&format!("{}:{}\r\n", ESTIMATE_PROPERTY, estimate));
+            }
+            for relationship in self.relationship_list.iter() {
+                match relationship.get_variant() {
+                    RelationshipVariant::Parental(_) if relationship.get_participant_2() == id => calendar.push_str(
+                        &format!("RELATED-TO;RELTYPE=PARENT:{}\r\n", relationship.get_participant_1()),
+                    ),
+                    RelationshipVariant::Related(_) if relationship.get_participant_1() == id => calendar.push_str(
+                        &format!("RELATED-TO;RELTYPE=SIBLING:{}\r\n", relationship.get_participant_2()),
+                    ),
+                    _ => {}
+                }
+            }
+            calendar.push_str("END:VTODO\r\n");
+        }
+        calendar.push_str("END:VCALENDAR\r\n");
+
+        calendar
+    }
+
+    pub fn import_from_icalendar<R: Read>(&self, reader: R) -> Result<ClearHeadApp, Box<dyn Error>> {
+        let todos = read_vtodos(reader)?;
+
+        self.check_imported_ids(todos.iter().map(|todo| (todo.line, &todo.action)), describe_line_error)?;
+        let uid_ids: HashMap<&str, Uuid> = todos
+            .iter()
+            .filter_map(|todo| Some((todo.uid.as_deref()?, todo.action.get_id())))
+            .collect();
+
+        let mut updated_app = self.clone();
+        updated_app
+            .action_list
+            .append(todos.iter().map(|todo| todo.action.clone()).collect());
+
+        let mut linked_pairs = HashSet::new();
+        for todo in &todos {
+            let id = todo.action.get_id();
+            for (relationship_type, uid) in &todo.related {
+                let other_id = match uid_ids
+                    .get(uid.as_str())
+                    .copied()
+                    .or_else(|| Uuid::parse_str(uid).ok().filter(|other_id| self.select_action_by_id(*other_id).is_ok()))
+                {
+                    Some(other_id) if other_id != id => other_id,
+                    _ => continue,
+                };
+                let relationship = match relationship_type.as_str() {
+                    "PARENT" => Relationship::create_new_parental(other_id, id),
+                    "CHILD" => Relationship::create_new_parental(id, other_id),
+                    "SIBLING" => Relationship::create_new_related(id, other_id),
+                    _ => continue,
+                };
+                let pair = match relationship_type.as_str() {
+                    "SIBLING" if other_id < id => ("SIBLING", other_id, id),
+                    "SIBLING" => ("SIBLING", id, other_id),
+                    _ => ("PARENT", relationship.get_participant_1(), relationship.get_participant_2()),
+                };
+                if linked_pairs.insert(pair) {
+                    updated_app.relationship_list.push_back(relationship);
+                }
+            }
+        }
+
+        self.enforce_constraints(updated_app)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::functionality::tests::create_app_with_two_actions;
+    use crate::RelationshipListManagement;
+
+    use chrono::TimeZone;
+
+    fn create_timestamp() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 10, 19, 8, 30, 0).unwrap()
+    }
+
+    #[test]
+    fn export_vtodo() {
+        let test_app = create_app_with_two_actions()
+            .rename_action(0, "Paint fence, then rest".to_string())
+            .unwrap()
+            .change_action_priority(0, "critical".to_string())
+            .unwrap()
+            .toggle_action_completion_status(1)
+            .unwrap()
+            .create_action_relationship("parental", 0, 1)
+            .unwrap();
+
+        let calendar = test_app.export_to_icalendar(create_timestamp());
+
+        assert!(calendar.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+        assert!(calendar.contains(&format!(
+            "BEGIN:VTODO\r\nUID:{}\r\nDTSTAMP:20261019T083000Z\r\nSUMMARY:Paint fence\\, then rest\r\nPRIORITY:1\r\nSTATUS:NEEDS-ACTION\r\nEND:VTODO\r\n",
+            test_app.get_action_id(0).unwrap()
+        )));
+        assert!(calendar.contains(&format!(
+            "PRIORITY:9\r\nSTATUS:COMPLETED\r\nRELATED-TO;RELTYPE=PARENT:{}\r\nEND:VTODO\r\n",
+            test_app.get_action_id(0).unwrap()
+        )));
+        assert!(calendar.ends_with("END:VCALENDAR\r\n"));
+    }
+
+    #[test]
+    fn fold_long_lines() {
+        let folded = fold_line(&format!("SUMMARY:{}", "a".repeat(100)));
+
+        assert_eq!(folded, format!("SUMMARY:{}\r\n {}\r\n", "a".repeat(67), "a".repeat(33)));
+    }
+
+    #[test]
+    fn import_vtodos_from_other_client() {
+        let calendar = "BEGIN:VCALENDAR\r\nVERSION:2.0\r\nBEGIN:VTODO\r\nUID:project@example.com\r\nSUMMARY:Move\r\n  house\r\nPRIORITY:2\r\nDUE:20261101T170000Z\r\nBEGIN:VALARM\r\nSUMMARY:Reminder\r\nEND:VALARM\r\nEND:VTODO\r\nBEGIN:VEVENT\r\nSUMMARY:Party\r\nEND:VEVENT\r\nBEGIN:VTODO\r\nUID:boxes@example.com\r\nSUMMARY:Pack boxes\\; label them\r\nPERCENT-COMPLETE:100\r\nRELATED-TO:project@example.com\r\nRELATED-TO;RELTYPE=SIBLING:missing@example.com\r\nEND:VTODO\r\nEND:VCALENDAR\r\n";
+
+        let updated_app = ClearHeadApp::default().import_from_icalendar(calendar.as_bytes()).unwrap();
+
+        assert_eq!(updated_app.action_list.len(), 2);
+        assert_eq!(updated_app.get_action_name(0).unwrap(), "Move house");
+        assert_eq!(updated_app.get_action_priority(0).unwrap(), "Critical");
+        assert_eq!(
+            updated_app.action_list[0].get_due_date(),
+            NaiveDate::from_ymd_opt(2026, 11, 1)
+        );
+        assert_eq!(updated_app.get_action_name(1).unwrap(), "Pack boxes; label them");
+        assert!(updated_app.get_action_completion_status(1).unwrap());
+        assert_eq!(updated_app.relationship_list.len(), 1);
+        assert_eq!(
+            updated_app.get_children_for_id(updated_app.get_action_id(0).unwrap()).unwrap(),
+            im::vector![updated_app.get_action_id(1).unwrap()]
+        );
+    }
+
+    #[test]
+    fn round_trip_icalendar() {
+        let test_app = create_app_with_two_actions()
+            .append_default_action()
+            .change_action_priority(0, "medium".to_string())
+            .unwrap()
+            .change_action_estimate(0, Some(4))
+            .unwrap()
+            .toggle_action_completion_status(2)
+            .unwrap()
+            .create_action_relationship("parental", 0, 1)
+            .unwrap()
+            .create_action_relationship("related", 1, 2)
+            .unwrap();
+
+        let round_trip_app = ClearHeadApp::default()
+            .import_from_icalendar(test_app.export_to_icalendar(create_timestamp()).as_bytes())
+            .unwrap();
+
+        assert_eq!(round_trip_app.action_list, test_app.action_list);
+        assert_eq!(round_trip_app.relationship_list.len(), 2);
+        assert_eq!(
+            round_trip_app.export_to_icalendar(create_timestamp()),
+            test_app.export_to_icalendar(create_timestamp())
+        );
+    }
+
+    #[test]
+    fn failed_import_invalid_priority() {
+        let calendar = "BEGIN:VCALENDAR\r\nBEGIN:VTODO\r\nSUMMARY:Call Mom\r\nPRIORITY:high\r\nEND:VTODO\r\nEND:VCALENDAR\r\n";
+
+        let import_error = ClearHeadApp::default().import_from_icalendar(calendar.as_bytes()).unwrap_err();
+
+        assert_eq!(import_error.to_string(), "Line 4: high is an Invalid Priority");
+    }
+
+    #[test]
+    fn failed_import_unterminated_vtodo() {
+        let calendar = "BEGIN:VCALENDAR\r\nBEGIN:VTODO\r\nSUMMARY:Call Mom\r\n";
+
+        let import_error = ClearHeadApp::default().import_from_icalendar(calendar.as_bytes()).unwrap_err();
+
+        assert_eq!(import_error.to_string(), "Line 2: VTODO is never ended");
+    }
+}
@@ -0,0 +1,277 @@
+use crate::action::{Action, Priority};
+use crate::formats::describe_line_error;
+use crate::functionality::ClearHeadApp;
+use crate::relationship::{Relationship, RelationshipVariant};
+use crate::ActionManipulation;
+
+use std::collections::HashSet;
+use std::error::Error;
+use std::io::{BufRead, BufReader, Read};
+use std::str::FromStr;
+
+use uuid::Uuid;
+
+const INDENT_WIDTH: usize = 2;
+const TAB_WIDTH: usize = 4;
+
+pub fn create_checklist_item(action: &Action, depth: usize) -> String {
+    let checkbox = match action.get_completion_status() {
+        true => "[x]",
+        false => "[ ]",
+    };
+    let priority = match Priority::from_str(&action.get_priority()).unwrap_or_default() {
+        Priority::Optional => String::new(),
+        priority => format!("**{}** ", priority),
+    };
+
+    format!(
+        "{}- {} {}{}\n",
+        " ".repeat(depth * INDENT_WIDTH),
+        checkbox,
+        priority,
+        action.get_name().replace('\n', " ")
+    )
+}
+
+struct ChecklistItem {
+    indent: usize,
+    action: Action,
+}
+
+fn parse_checklist_item(line_number: usize, line: &str) -> Result<Option<ChecklistItem>, Box<dyn Error>> {
+    let content = line.trim_start();
+    let indent = line[..line.len() - content.len()]
+        .chars()
+        .map(|character| if character == '\t' { TAB_WIDTH } else { 1 })
+        .sum();
+
+    let item = match content
+        .strip_prefix("- ")
+        .or_else(|| content.strip_prefix("* "))
+        .or_else(|| content.strip_prefix("+ "))
+    {
+        Some(item) => item,
+        None => return Ok(None),
+    };
+    let (completed, text) = match (item.get(..3), item.get(3..)) {
+        (Some("[ ]"), Some(text)) => (false, text),
+        (Some("[x]") | Some("[X]"), Some(text)) => (true, text),
+        _ => return Ok(None),
+    };
+    let text = text.trim();
+
+    let (priority, name) = match text
+        .strip_prefix("**")
+        .and_then(|rest| rest.split_once("**"))
+        .and_then(|(marker, name)| Some((Priority::from_str(marker).ok()?, name.trim())))
+    {
+        Some((priority, name)) if !name.is_empty() => (priority, name),
+        _ => (Priority::Optional, text),
+    };
+    if name.is_empty() {
+        return Err(describe_line_error(line_number, "the checklist item has no text"));
+    }
+
+    let mut action = Action::default().rename(name).change_priority(&priority.to_string())?;
+    if completed {
+        action = action.toggle_completion_status();
+    }
+
+    Ok(Some(ChecklistItem { indent, action }))
+}
+
+impl ClearHeadApp {
+    fn has_parent(&self, id: Uuid) -> bool {
+        self.relationship_list.iter().any(|relationship| {
+            relationship.get_variant() == RelationshipVariant::create_parental() && relationship.get_participant_2() == id
+        })
+    }
+
+    fn write_checklist_subtree(
+        &self,
+        id: Uuid,
+        depth: usize,
+        written_ids: &mut HashSet<Uuid>,
+        checklist: &mut String,
+    ) -> Result<(), Box<dyn Error>> {
+        // an Action with several parents is only written under the first one reached
+        if !written_ids.insert(id) {
+            return Ok(());
+        }
+        checklist.push_str(&create_checklist_item(&self.select_action_by_id(id)?, depth));
+
+        for relationship in self.relationship_list.iter().filter(|relationship| {
+            relationship.get_variant() == RelationshipVariant::create_parental() && relationship.get_participant_1() == id
+        }) {
+            self.write_checklist_subtree(relationship.get_participant_2(), depth + 1, written_ids, checklist)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn export_to_markdown(&self, root: Option<Uuid>) -> Result<String, Box<dyn Error>> {
+        let mut checklist = String::new();
+        let mut written_ids = HashSet::new();
+
+        let root_ids: Vec<Uuid> = match root {
+            Some(root_id) => vec![root_id],
+            None => self
+                .action_list
+                .iter()
+                .map(|action| action.get_id())
+                .filter(|id| !self.has_parent(*id))
+                .collect(),
+        };
+        for root_id in root_ids {
+            self.write_checklist_subtree(root_id, 0, &mut written_ids, &mut checklist)?;
+        }
+
+        if root.is_none() {
+            for action in self.action_list.iter() {
+                self.write_checklist_subtree(action.get_id(), 0, &mut written_ids, &mut checklist)?;
+            }
+        }
+
+        Ok(checklist)
+    }
+
+    pub fn import_from_markdown<R: Read>(&self, reader: R) -> Result<ClearHeadApp, Box<dyn Error>> {
+        let mut updated_app = self.clone();
+        let mut open_items: Vec<(usize, Uuid)> = Vec::new();
+
+        for (index, line) in BufReader::new(reader).lines().enumerate() {
+            let line = line?;
+            let item = match parse_checklist_item(index + 1, &line)? {
+                Some(item) => item,
+                None => {
+                    if !line.trim().is_empty() {
+                        open_items.clear();
+                    }
+                    continue;
+                }
+            };
+
+            while open_items.last().is_some_and(|(indent, _)| *indent >= item.indent) {
+                open_items.pop();
+            }
+            let id = item.action.get_id();
+            if let Some((_, parent_id)) = open_items.last() {
+                updated_app
+                    .relationship_list
+                    .push_back(Relationship::create_new_parental(*parent_id, id));
+            }
+            updated_app.action_list.push_back(item.action);
+            open_items.push((item.indent, id));
+        }
+
+        self.enforce_constraints(updated_app)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::functionality::tests::create_app_with_two_actions;
+    use crate::RelationshipListManagement;
+
+    #[test]
+    fn export_nested_checklist() {
+        let test_app = create_app_with_two_actions()
+            .append_default_action()
+            .rename_action(0, "Move house".to_string())
+            .unwrap()
+            .change_action_priority(1, "high".to_string())
+            .unwrap()
+            .toggle_action_completion_status(2)
+            .unwrap()
+            .create_action_relationship("parental", 0, 1)
+            .unwrap()
+            .create_action_relationship("parental", 1, 2)
+            .unwrap();
+
+        assert_eq!(
+            test_app.export_to_markdown(None).unwrap(),
+            "- [ ] Move house\n  - [ ] **High** Default Action\n    - [x] Default Action\n"
+        );
+        assert_eq!(
+            test_app.export_to_markdown(Some(test_app.get_action_id(1).unwrap())).unwrap(),
+            "- [ ] **High** Default Action\n  - [x] Default Action\n"
+        );
+    }
+
+    #[test]
+    fn export_parental_cycle() {
+        let mut test_app = create_app_with_two_actions();
+        let (first_id, second_id) = (test_app.get_action_id(0).unwrap(), test_app.get_action_id(1).unwrap());
+        test_app.relationship_list = im::vector![
+            Relationship::create_new_parental(first_id, second_id),
+            Relationship::create_new_parental(second_id, first_id)
+        ];
+
+        assert_eq!(
+            test_app.export_to_markdown(None).unwrap(),
+            "- [ ] Default Action\n  - [ ] Default Action\n"
+        );
+    }
+
+    #[test]
+    fn export_action_with_two_parents_once() {
+        let test_app = create_app_with_two_actions()
+            .append_default_action()
+            .rename_action(0, "Garden".to_string())
+            .unwrap()
+            .rename_action(1, "Spring".to_string())
+            .unwrap()
+            .rename_action(2, "Buy seeds".to_string())
+            .unwrap()
+            .create_action_relationship("parental", 0, 2)
+            .unwrap()
+            .create_action_relationship("parental", 1, 2)
+            .unwrap();
+
+        assert_eq!(
+            test_app.export_to_markdown(None).unwrap(),
+            "- [ ] Garden\n  - [ ] Buy seeds\n- [ ] Spring\n"
+        );
+    }
+
+    #[test]
+    fn import_nested_checklist() {
+        let checklist = "# Release\n\n- [ ] **Critical** Ship 2.0\n  - [x] Write changelog\n  - [ ] Tag release\n\t- [ ] **Soon** Push tag\n* [X] Celebrate\n- not a task\n";
+
+        let updated_app = ClearHeadApp::default().import_from_markdown(checklist.as_bytes()).unwrap();
+
+        assert_eq!(updated_app.action_list.len(), 5);
+        assert_eq!(updated_app.get_action_priority(0).unwrap(), "Critical");
+        assert!(updated_app.get_action_completion_status(1).unwrap());
+        assert_eq!(updated_app.get_action_name(3).unwrap(), "**Soon** Push tag");
+        assert!(updated_app.get_action_completion_status(4).unwrap());
+        assert_eq!(
+            updated_app.get_children_for_id(updated_app.get_action_id(0).unwrap()).unwrap(),
+            im::vector![updated_app.get_action_id(1).unwrap(), updated_app.get_action_id(2).unwrap()]
+        );
+        assert_eq!(
+            updated_app.get_children_for_id(updated_app.get_action_id(2).unwrap()).unwrap(),
+            im::vector![updated_app.get_action_id(3).unwrap()]
+        );
+        assert_eq!(updated_app.relationship_list.len(), 3);
+    }
+
+    #[test]
+    fn round_trip_markdown() {
+        let checklist = "- [ ] **Medium** Garden\n  - [x] **Low** Buy seeds\n  - [ ] Plant\n- [ ] Call Mom\n";
+
+        let test_app = ClearHeadApp::default().import_from_markdown(checklist.as_bytes()).unwrap();
+
+        assert_eq!(test_app.export_to_markdown(None).unwrap(), checklist);
+    }
+
+    #[test]
+    fn failed_import_empty_item() {
+        let import_error = ClearHeadApp::default()
+            .import_from_markdown("- [ ] Garden\n  - [ ] \n".as_bytes())
+            .unwrap_err();
+
+        assert_eq!(import_error.to_string(), "Line 2: the checklist item has no text");
+    }
+}
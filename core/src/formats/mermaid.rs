@@ -0,0 +1,374 @@
+use crate::action::Priority;
+use crate::functionality::ClearHeadApp;
+use crate::relationship::item::EdgeDirectionality;
+use crate::relationship::{Relationship, RelationshipVariant};
+use crate::ActionManipulation;
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::str::FromStr;
+
+use chrono::{Duration, NaiveDate};
+use uuid::Uuid;
+
+pub fn escape_mermaid_string(text: &str) -> String {
+    text.replace('"', "#quot;")
+        .replace('|', "#124;")
+        .replace('\n', " ")
+}
+
+fn sanitize_gantt_name(text: &str) -> String {
+    text.replace([':', ';', '#', '\n'], " ").trim().to_string()
+}
+
+fn get_mermaid_arrow(variant: &RelationshipVariant) -> &'static str {
+    match variant {
+        RelationshipVariant::Parental(EdgeDirectionality::Directed) => "-->",
+        RelationshipVariant::Parental(EdgeDirectionality::Undirected) => "---",
+        RelationshipVariant::Sequential(EdgeDirectionality::Directed) => "==>",
+        RelationshipVariant::Sequential(EdgeDirectionality::Undirected) => "===",
+        RelationshipVariant::Related(EdgeDirectionality::Directed)
+        | RelationshipVariant::Custom(_, EdgeDirectionality::Directed) => "-.->",
+        RelationshipVariant::Related(EdgeDirectionality::Undirected)
+        | RelationshipVariant::Custom(_, EdgeDirectionality::Undirected) => "-.-",
+    }
+}
+
+fn create_mermaid_edge(relationship: &Relationship, node_ids: &HashMap<Uuid, String>) -> Option<String> {
+    let source = node_ids.get(&relationship.get_participant_1())?;
+    let target = node_ids.get(&relationship.get_participant_2())?;
+    let variant = relationship.get_variant();
+    let label = match (relationship.get_label(), &variant) {
+        (Some(label), _) => format!("|{}|", escape_mermaid_string(&label)),
+        (None, RelationshipVariant::Custom(name, _)) => format!("|{}|", escape_mermaid_string(name)),
+        (None, _) => String::new(),
+    };
+
+    Some(format!(
+        "    {} {}{} {}\n",
+        source,
+        get_mermaid_arrow(&variant),
+        label,
+        target
+    ))
+}
+
+impl ClearHeadApp {
+    pub fn export_to_mermaid_flowchart(&self, root: Option<Uuid>) -> Result<String, Box<dyn Error>> {
+        let included_ids = self.get_export_ids(root)?;
+        let node_ids: HashMap<Uuid, String> = self
+            .action_list
+            .iter()
+            .enumerate()
+            .filter(|(_, action)| included_ids.contains(&action.get_id()))
+            .map(|(index, action)| (action.get_id(), format!("A{}", index)))
+            .collect();
+
+        let mut flowchart = String::new();
+        flowchart.push_str("flowchart TD\n");
+        flowchart.push_str("    classDef critical fill:tomato\n");
+        flowchart.push_str("    classDef high fill:orange\n");
+        flowchart.push_str("    classDef medium fill:khaki\n");
+        flowchart.push_str("    classDef low fill:lightblue\n");
+        flowchart.push_str("    classDef optional fill:lightgrey\n");
+        flowchart.push_str("    classDef completed stroke-dasharray:5 5,color:gray\n");
+
+        let mut completed_nodes = Vec::new();
+        for (index, action) in self
+            .action_list
+            .iter()
+            .enumerate()
+            .filter(|(_, action)| included_ids.contains(&action.get_id()))
+        {
+            let priority = Priority::from_str(&action.get_priority())?;
+            flowchart.push_str(&format!(
+                "    A{}[\"{}\"]:::{}\n",
+                index,
+                escape_mermaid_string(&action.get_name()),
+                priority.to_string().to_lowercase()
+            ));
+            if action.get_completion_status() {
+                completed_nodes.push(format!("A{}", index));
+            }
+        }
+
+        for relationship in self.relationship_list.iter() {
+            if let Some(edge) = create_mermaid_edge(relationship, &node_ids) {
+                flowchart.push_str(&edge);
+            }
+        }
+
+        if !completed_nodes.is_empty() {
+            flowchart.push_str(&format!("    class {} completed\n", completed_nodes.join(",")));
+        }
+
+        Ok(flowchart)
+    }
+
+    pub fn export_to_mermaid_gantt(&self, root: Option<Uuid>, start: NaiveDate) -> Result<String, Box<dyn Error>> {
+        let included_ids = self.get_export_ids(root)?;
+        let exported_app = ClearHeadApp {
+            action_list: self
+                .action_list
+                .iter()
+                .filter(|action| included_ids.contains(&action.get_id()))
+                .cloned()
+                .collect(),
+            relationship_list: self
+                .relationship_list
+                .iter()
+                .filter(|relationship| {
+                    included_ids.contains(&relationship.get_participant_1())
+                        && included_ids.contains(&relationship.get_participant_2())
+                })
+                .cloned()
+                .collect(),
+            ..Default::default()
+        };
+
+        let critical_path = exported_app.get_critical_path()?;
+        let positions: HashMap<Uuid, usize> = exported_app
+            .action_list
+            .iter()
+            .enumerate()
+            .map(|(index, action)| (action.get_id(), index))
+            .collect();
+
+        let mut predecessors: Vec<Vec<usize>> = vec![Vec::new(); exported_app.action_list.len()];
+        for relationship in exported_app
+            .relationship_list
+            .iter()
+            .filter(|relationship| relationship.get_variant() == RelationshipVariant::create_sequential())
+        {
+            predecessors[positions[&relationship.get_participant_2()]]
+                .push(positions[&relationship.get_participant_1()]);
+        }
+
+        let mut schedule_order: Vec<usize> = (0..critical_path.schedule.len()).collect();
+        schedule_order.sort_by_key(|index| critical_path.schedule[*index].earliest_start);
+
+        let mut gantt = String::new();
+        gantt.push_str("gantt\n");
+        gantt.push_str("    title ClearHead Actions\n");
+        gantt.push_str("    dateFormat YYYY-MM-DD\n");
+        gantt.push_str("    section Actions\n");
+
+        for index in schedule_order {
+            let scheduled_action = &critical_path.schedule[index];
+
+            let mut tags = String::new();
+            if scheduled_action.is_critical() {
+                tags.push_str("crit, ");
+            }
+            if scheduled_action.action.get_completion_status() {
+                tags.push_str("done, ");
+            }
+
+            let task_start = match predecessors[index].is_empty() {
+                true => (start + Duration::days(scheduled_action.earliest_start.into())).to_string(),
+                false => format!(
+                    "after {}",
+                    predecessors[index]
+                        .iter()
+                        .map(|predecessor| format!("a{}", predecessor))
+                        .collect::<Vec<String>>()
+                        .join(" ")
+                ),
+            };
+
+            let task_end = match scheduled_action.action.get_due_date() {
+                Some(due_date) => due_date.to_string(),
+                None => format!("{}d", scheduled_action.duration),
+            };
+
+            gantt.push_str(&format!(
+                "    {} :{}a{}, {}, {}\n",
+                sanitize_gantt_name(&scheduled_action.action.get_name()),
+                tags,
+                index,
+                task_start,
+                task_end
+            ));
+        }
+
+        Ok(gantt)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::functionality::tests::{create_app_with_two_actions, create_minimal_related_app};
+    use crate::RelationshipListManagement;
+    use indoc::indoc;
+
+    fn get_test_start_date() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2022, 10, 3).unwrap()
+    }
+
+    #[test]
+    fn export_empty_flowchart() {
+        let test_app = ClearHeadApp::default();
+
+        let flowchart = test_app.export_to_mermaid_flowchart(None).unwrap();
+
+        assert!(flowchart.starts_with("flowchart TD\n"));
+        assert!(!flowchart.contains("A0"));
+    }
+
+    #[test]
+    fn export_flowchart_with_every_variant() {
+        let test_app = ClearHeadApp::default()
+            .append_default_action()
+            .rename_action(0, "Plan \"Launch\"".to_string())
+            .unwrap()
+            .change_action_priority(0, "high".to_string())
+            .unwrap()
+            .append_default_action()
+            .toggle_action_completion_status(1)
+            .unwrap()
+            .append_default_action()
+            .create_action_relationship("parental", 0, 1)
+            .unwrap()
+            .create_action_relationship("sequential", 1, 2)
+            .unwrap()
+            .create_action_relationship("related", 0, 2)
+            .unwrap()
+            .set_relationship_label(2, Some("shared budget"))
+            .unwrap();
+
+        let flowchart = test_app.export_to_mermaid_flowchart(None).unwrap();
+
+        assert_eq!(
+            flowchart,
+            indoc!(
+                "
+                flowchart TD
+                    classDef critical fill:tomato
+                    classDef high fill:orange
+                    classDef medium fill:khaki
+                    classDef low fill:lightblue
+                    classDef optional fill:lightgrey
+                    classDef completed stroke-dasharray:5 5,color:gray
+                    A0[\"Plan #quot;Launch#quot;\"]:::high
+                    A1[\"Default Action\"]:::optional
+                    A2[\"Default Action\"]:::optional
+                    A0 --> A1
+                    A1 ==> A2
+                    A0 -.-|shared budget| A2
+                    class A1 completed
+                "
+            )
+        );
+    }
+
+    #[test]
+    fn export_flowchart_rooted_at_action() {
+        let test_app = create_minimal_related_app("parental")
+            .append_default_action()
+            .create_action_relationship("sequential", 2, 0)
+            .unwrap();
+
+        let flowchart = test_app
+            .export_to_mermaid_flowchart(Some(test_app.action_list[0].get_id()))
+            .unwrap();
+
+        assert!(flowchart.contains("    A0 --> A1\n"));
+        assert!(!flowchart.contains("A2"));
+    }
+
+    #[test]
+    fn failed_flowchart_with_unknown_root() {
+        let test_app = create_app_with_two_actions();
+
+        let missing_error = test_app.export_to_mermaid_flowchart(Some(Uuid::nil())).unwrap_err();
+
+        assert_eq!(missing_error.to_string(), format!("No Action with Id {}", Uuid::nil()));
+    }
+
+    #[test]
+    fn export_gantt_in_sequential_order() {
+        let test_app = ClearHeadApp::default()
+            .append_default_action()
+            .rename_action(0, "Release: v1".to_string())
+            .unwrap()
+            .append_default_action()
+            .rename_action(1, "Build".to_string())
+            .unwrap()
+            .change_action_estimate(1, Some(3))
+            .unwrap()
+            .toggle_action_completion_status(1)
+            .unwrap()
+            .append_default_action()
+            .rename_action(2, "Write Docs".to_string())
+            .unwrap()
+            .change_action_due_date(2, NaiveDate::from_ymd_opt(2022, 10, 5))
+            .unwrap()
+            .create_action_relationship("sequential", 1, 0)
+            .unwrap();
+
+        let gantt = test_app.export_to_mermaid_gantt(None, get_test_start_date()).unwrap();
+
+        assert_eq!(
+            gantt,
+            indoc!(
+                "
+                gantt
+                    title ClearHead Actions
+                    dateFormat YYYY-MM-DD
+                    section Actions
+                    Build :crit, done, a1, 2022-10-03, 3d
+                    Write Docs :a2, 2022-10-03, 2022-10-05
+                    Release  v1 :crit, a0, after a1, 1d
+                "
+            )
+        );
+    }
+
+    #[test]
+    fn export_gantt_rooted_at_action() {
+        let test_app = create_minimal_related_app("parental")
+            .append_default_action()
+            .rename_action(2, "Unrelated".to_string())
+            .unwrap()
+            .create_action_relationship("sequential", 2, 1)
+            .unwrap()
+            .create_action_relationship("sequential", 0, 1)
+            .unwrap();
+
+        let gantt = test_app
+            .export_to_mermaid_gantt(Some(test_app.action_list[0].get_id()), get_test_start_date())
+            .unwrap();
+
+        assert!(gantt.contains(" :crit, a1, after a0, 1d\n"));
+        assert!(!gantt.contains("Unrelated"));
+        assert!(!gantt.contains("a2"));
+    }
+
+    #[test]
+    fn failed_gantt_with_unknown_root() {
+        let test_app = create_app_with_two_actions();
+
+        let missing_error = test_app
+            .export_to_mermaid_gantt(Some(Uuid::nil()), get_test_start_date())
+            .unwrap_err();
+
+        assert_eq!(missing_error.to_string(), format!("No Action with Id {}", Uuid::nil()));
+    }
+
+    #[test]
+    fn failed_gantt_with_cycle() {
+        let test_app = create_app_with_two_actions()
+            .create_action_relationship("sequential", 0, 1)
+            .unwrap()
+            .create_action_relationship("sequential", 1, 0)
+            .unwrap();
+
+        let cycle_error = test_app.export_to_mermaid_gantt(None, get_test_start_date()).unwrap_err();
+
+        assert_eq!(
+            cycle_error.to_string(),
+            "Sequential relationships form a cycle, so no critical path exists"
+        );
+    }
+}
@@ -0,0 +1,60 @@
+pub mod dot;
+pub use dot::*;
+
+pub mod mermaid;
+pub use mermaid::*;
+
+pub mod todo_txt;
+pub use todo_txt::*;
+
+pub mod icalendar;
+pub use icalendar::*;
+
+pub mod markdown;
+pub use markdown::*;
+
+use crate::action::Action;
+use crate::functionality::ClearHeadApp;
+use crate::ActionManipulation;
+
+use std::collections::HashSet;
+use std::error::Error;
+
+use uuid::Uuid;
+
+pub(crate) fn describe_line_error(line: usize, error: impl ToString) -> Box<dyn Error> {
+    format!("Line {}: {}", line, error.to_string()).into()
+}
+
+impl ClearHeadApp {
+    pub(crate) fn check_imported_ids<'a>(
+        &self,
+        imported_actions: impl IntoIterator<Item = (usize, &'a Action)>,
+        describe_error: impl Fn(usize, String) -> Box<dyn Error>,
+    ) -> Result<(), Box<dyn Error>> {
+        let mut existing_ids: HashSet<Uuid> = self.action_list.iter().map(|action| action.get_id()).collect();
+        for (position, action) in imported_actions {
+            if !existing_ids.insert(action.get_id()) {
+                return Err(describe_error(
+                    position,
+                    format!("an Action with Id {} already exists", action.get_id()),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn get_export_ids(&self, root: Option<Uuid>) -> Result<HashSet<Uuid>, Box<dyn Error>> {
+        match root {
+            Some(root_id) => {
+                self.select_action_by_id(root_id)?;
+                let mut subgraph_ids: HashSet<Uuid> =
+                    self.get_descendant_ids(root_id).into_iter().collect();
+                subgraph_ids.insert(root_id);
+                Ok(subgraph_ids)
+            }
+            None => Ok(self.action_list.iter().map(|action| action.get_id()).collect()),
+        }
+    }
+}
@@ -0,0 +1,422 @@
+use crate::action::{Action, Priority};
+use crate::formats::describe_line_error;
+use crate::functionality::ClearHeadApp;
+use crate::relationship::{Relationship, RelationshipVariant};
+use crate::ActionManipulation;
+
+use std::error::Error;
+use std::io::{BufRead, BufReader, Read};
+use std::str::FromStr;
+
+use chrono::NaiveDate;
+use uuid::Uuid;
+
+fn get_priority_letter(priority: &str) -> Option<char> {
+    match Priority::from_str(priority).ok()? {
+        Priority::Critical => Some('A'),
+        Priority::High => Some('B'),
+        Priority::Medium => Some('C'),
+        Priority::Low => Some('D'),
+        Priority::Optional => None,
+    }
+}
+
+fn get_letter_priority(letter: char) -> Option<Priority> {
+    match letter {
+        'A' => Some(Priority::Critical),
+        'B' => Some(Priority::High),
+        'C' => Some(Priority::Medium),
+        'D' => Some(Priority::Low),
+        'E'..='Z' => Some(Priority::Optional),
+        _ => None,
+    }
+}
+
+fn parse_priority_marker(word: &str) -> Option<Priority> {
+    let mut characters = word.chars();
+    match (characters.next(), characters.next(), characters.next(), characters.next()) {
+        (Some('('), Some(letter), Some(')'), None) => get_letter_priority(letter),
+        _ => None,
+    }
+}
+
+const METADATA_KEYS: [&str; 5] = ["due", "estimate", "id", "parent", "pri"];
+
+// todo.txt has no quoting, so name words that would parse as markers or tags get a leading backslash
+pub fn escape_todo_txt_name(name: &str) -> String {
+    name.split_whitespace()
+        .enumerate()
+        .map(|(position, word)| {
+            let is_marker = position == 0
+                && (word == "x" || parse_priority_marker(word).is_some() || word.parse::<NaiveDate>().is_ok());
+            let is_tag = word.starts_with('+')
+                || word.starts_with('\\')
+                || matches!(word.split_once(':'), Some((key, _)) if METADATA_KEYS.contains(&key));
+            match is_marker || is_tag {
+                true => format!("\\{}", word),
+                false => word.to_string(),
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+pub fn get_project_tag(name: &str) -> String {
+    name.split_whitespace().collect::<Vec<&str>>().join("_")
+}
+
+struct TodoTxtTask {
+    line: usize,
+    action: Action,
+    projects: Vec<String>,
+    parent_ids: Vec<Uuid>,
+}
+
+fn parse_todo_txt_line(line_number: usize, line: &str) -> Result<TodoTxtTask, Box<dyn Error>> {
+    let mut words = line.split_whitespace().peekable();
+    let completed = words.next_if_eq(&"x").is_some();
+    let mut priority = match words.peek().and_then(|word| parse_priority_marker(word)) {
+        Some(priority) => {
+            words.next();
+            priority
+        }
+        None => Priority::Optional,
+    };
+    for _ in 0..if completed { 2 } else { 1 } {
+        words.next_if(|word| word.parse::<NaiveDate>().is_ok());
+    }
+
+    let mut action = Action::default();
+    let mut name_words = Vec::new();
+    let mut projects = Vec::new();
+    let mut parent_ids = Vec::new();
+    for word in words {
+        if let Some(literal) = word.strip_prefix('\\') {
+            name_words.push(literal);
+            continue;
+        }
+        if let Some(project) = word.strip_prefix('+').filter(|project| !project.is_empty()) {
+            projects.push(project.to_string());
+            continue;
+        }
+        match word.split_once(':') {
+            Some(("due", value)) => {
+                let due_date = value
+                    .parse::<NaiveDate>()
+                    .map_err(|_| describe_line_error(line_number, format!("{} is an Invalid Due Date", value)))?;
+                action = action.set_due_date(Some(due_date));
+            }
+            Some(("estimate", value)) => {
+                let estimate = value
+                    .parse::<u32>()
+                    .map_err(|_| describe_line_error(line_number, format!("{} is an Invalid Estimate", value)))?;
+                action = action.set_estimate(Some(estimate));
+            }
+            Some(("id", value)) => {
+                let id = Uuid::parse_str(value)
+                    .map_err(|_| describe_line_error(line_number, format!("{} is an Invalid Id", value)))?;
+                action = action.set_id(id);
+            }
+            Some(("parent", value)) => {
+                let parent_id = Uuid::parse_str(value)
+                    .map_err(|_| describe_line_error(line_number, format!("{} is an Invalid Parent Id", value)))?;
+                parent_ids.push(parent_id);
+            }
+            Some(("pri", value)) if value.len() == 1 => {
+                priority = value
+                    .chars()
+                    .next()
+                    .and_then(get_letter_priority)
+                    .ok_or_else(|| describe_line_error(line_number, format!("{} is an Invalid Priority", value)))?;
+            }
+            _ => name_words.push(word),
+        }
+    }
+
+    if name_words.is_empty() {
+        return Err(describe_line_error(line_number, "the Task has no description"));
+    }
+    action = action
+        .rename(&name_words.join(" "))
+        .change_priority(&priority.to_string())?;
+    if completed {
+        action = action.toggle_completion_status();
+    }
+
+    Ok(TodoTxtTask {
+        line: line_number,
+        action,
+        projects,
+        parent_ids,
+    })
+}
+
+impl ClearHeadApp {
+    fn get_parent_ids(&self, id: Uuid) -> Vec<Uuid> {
+        self.relationship_list
+            .iter()
+            .filter(|relationship| {
+                matches!(relationship.get_variant(), RelationshipVariant::Parental(_))
+                    && relationship.get_participant_2() == id
+            })
+            .map(|relationship| relationship.get_participant_1())
+            .collect()
+    }
+
+    pub fn export_to_todo_txt(&self) -> String {
+        let mut todo_txt = String::new();
+
+        for action in self.action_list.iter() {
+            let mut words = Vec::new();
+            let priority_letter = get_priority_letter(&action.get_priority());
+            if action.get_completion_status() {
+                words.push("x".to_string());
+            } else if let Some(letter) = priority_letter {
+                words.push(format!("({})", letter));
+            }
+            words.push(escape_todo_txt_name(&action.get_name()));
+            let parent_ids = self.get_parent_ids(action.get_id());
+            for parent_id in &parent_ids {
+                if let Ok(parent) = self.select_action_by_id(*parent_id) {
+                    words.push(format!("+{}", get_project_tag(&parent.get_name())));
+                }
+            }
+            if let Some(due_date) = action.get_due_date() {
+                words.push(format!("due:{}", due_date));
+            }
+            if let Some(estimate) = action.get_estimate() {
+                words.push(format!("estimate:{}", estimate));
+            }
+            // completed tasks drop the leading priority, so it is kept as an extension instead
+            if let (true, Some(letter)) = (action.get_completion_status(), priority_letter) {
+                words.push(format!("pri:{}", letter));
+            }
+            words.push(format!("id:{}", action.get_id()));
+            words.extend(parent_ids.iter().map(|parent_id| format!("parent:{}", parent_id)));
+
+            todo_txt.push_str(&words.join(" "));
+            todo_txt.push('\n');
+        }
+
+        todo_txt
+    }
+
+    pub fn import_from_todo_txt<R: Read>(&self, reader: R) -> Result<ClearHeadApp, Box<dyn Error>> {
+        let mut tasks = Vec::new();
+        for (index, line) in BufReader::new(reader).lines().enumerate() {
+            let line = line?;
+            if !line.trim().is_empty() {
+                tasks.push(parse_todo_txt_line(index + 1, &line)?);
+            }
+        }
+
+        self.check_imported_ids(tasks.iter().map(|task| (task.line, &task.action)), describe_line_error)?;
+
+        let mut updated_app = self.clone();
+        updated_app
+            .action_list
+            .append(tasks.iter().map(|task| task.action.clone()).collect());
+
+        for task in &tasks {
+            for parent_id in &task.parent_ids {
+                updated_app.select_action_by_id(*parent_id).map_err(|_| {
+                    describe_line_error(task.line, format!("parent:{} refers to no Action", parent_id))
+                })?;
+            }
+            let mut parent_ids = task.parent_ids.clone();
+            if task.parent_ids.is_empty() {
+                for project in &task.projects {
+                    let parent_id = match tasks
+                        .iter()
+                        .map(|task| &task.action)
+                        .chain(updated_app.action_list.iter())
+                        .find(|action| &get_project_tag(&action.get_name()) == project)
+                        .map(|parent| parent.get_id())
+                    {
+                        Some(parent_id) => parent_id,
+                        None => {
+                            let project_action = Action::default().rename(&project.replace('_', " "));
+                            updated_app.action_list.push_back(project_action.clone());
+                            project_action.get_id()
+                        }
+                    };
+                    parent_ids.push(parent_id);
+                }
+            }
+            for parent_id in parent_ids {
+                if parent_id != task.action.get_id() {
+                    updated_app
+                        .relationship_list
+                        .push_back(Relationship::create_new_parental(parent_id, task.action.get_id()));
+                }
+            }
+        }
+
+        self.enforce_constraints(updated_app)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::functionality::tests::create_app_with_two_actions;
+    use crate::RelationshipListManagement;
+
+    #[test]
+    fn import_priority_completion_and_extensions() {
+        let updated_app = ClearHeadApp::default()
+            .import_from_todo_txt(
+                "(A) 2026-10-01 Call Mom @phone due:2026-10-20 estimate:2\nx 2026-10-02 2026-10-01 Pay rent pri:B\n"
+                    .as_bytes(),
+            )
+            .unwrap();
+
+        assert_eq!(updated_app.get_action_name(0).unwrap(), "Call Mom @phone");
+        assert_eq!(updated_app.get_action_priority(0).unwrap(), "Critical");
+        assert_eq!(
+            updated_app.action_list[0].get_due_date(),
+            NaiveDate::from_ymd_opt(2026, 10, 20)
+        );
+        assert_eq!(updated_app.action_list[0].get_estimate(), Some(2));
+        assert_eq!(updated_app.get_action_name(1).unwrap(), "Pay rent");
+        assert_eq!(updated_app.get_action_priority(1).unwrap(), "High");
+        assert!(updated_app.get_action_completion_status(1).unwrap());
+    }
+
+    #[test]
+    fn import_projects_as_parents() {
+        let updated_app = ClearHeadApp::default()
+            .import_from_todo_txt("Paint fence +Garden_Work\nGarden Work\nBuy seeds +Spring\n".as_bytes())
+            .unwrap();
+
+        assert_eq!(updated_app.action_list.len(), 4);
+        assert_eq!(updated_app.get_action_name(3).unwrap(), "Spring");
+        assert_eq!(
+            updated_app.get_children_for_id(updated_app.get_action_id(1).unwrap()).unwrap(),
+            im::vector![updated_app.get_action_id(0).unwrap()]
+        );
+        assert_eq!(updated_app.relationship_list.len(), 2);
+    }
+
+    #[test]
+    fn export_to_todo_txt() {
+        let test_app = create_app_with_two_actions()
+            .change_action_priority(0, "high".to_string())
+            .unwrap()
+            .rename_action(0, "Garden Work".to_string())
+            .unwrap()
+            .toggle_action_completion_status(1)
+            .unwrap()
+            .change_action_priority(1, "low".to_string())
+            .unwrap()
+            .create_action_relationship("parental", 0, 1)
+            .unwrap();
+
+        assert_eq!(
+            test_app.export_to_todo_txt(),
+            format!(
+                "(B) Garden Work id:{}\nx Default Action +Garden_Work pri:D id:{} parent:{}\n",
+                test_app.get_action_id(0).unwrap(),
+                test_app.get_action_id(1).unwrap(),
+                test_app.get_action_id(0).unwrap()
+            )
+        );
+    }
+
+    #[test]
+    fn round_trip_todo_txt() {
+        let test_app = ClearHeadApp::default()
+            .import_from_todo_txt("(C) Plan trip @home +Travel due:2026-11-01 estimate:3 url:example\nx Pack +Travel pri:A\n".as_bytes())
+            .unwrap();
+
+        let round_trip_app = ClearHeadApp::default()
+            .import_from_todo_txt(test_app.export_to_todo_txt().as_bytes())
+            .unwrap();
+
+        assert_eq!(round_trip_app.action_list, test_app.action_list);
+        assert_eq!(round_trip_app.relationship_list.len(), test_app.relationship_list.len());
+        assert_eq!(round_trip_app.export_to_todo_txt(), test_app.export_to_todo_txt());
+    }
+
+    #[test]
+    fn round_trip_names_with_todo_txt_markers() {
+        let test_app = ClearHeadApp::default()
+            .append_default_action()
+            .rename_action(0, "x marks +spot".to_string())
+            .unwrap()
+            .append_default_action()
+            .rename_action(1, "(B) due:later id:none \\raw".to_string())
+            .unwrap()
+            .append_default_action()
+            .rename_action(2, "2024-01-02 retro".to_string())
+            .unwrap();
+
+        let todo_txt = test_app.export_to_todo_txt();
+        let round_trip_app = ClearHeadApp::default().import_from_todo_txt(todo_txt.as_bytes()).unwrap();
+
+        assert!(todo_txt.starts_with("\\x marks \\+spot id:"));
+        assert_eq!(round_trip_app.action_list, test_app.action_list);
+        assert!(round_trip_app.relationship_list.is_empty());
+    }
+
+    #[test]
+    fn import_parents_by_id() {
+        let test_app = create_app_with_two_actions()
+            .rename_action(1, "Garden".to_string())
+            .unwrap()
+            .append_default_action()
+            .rename_action(2, "Garden".to_string())
+            .unwrap()
+            .append_default_action()
+            .rename_action(3, "Weed beds".to_string())
+            .unwrap()
+            .create_action_relationship("parental", 2, 3)
+            .unwrap();
+
+        let round_trip_app = ClearHeadApp::default()
+            .import_from_todo_txt(test_app.export_to_todo_txt().as_bytes())
+            .unwrap();
+
+        assert_eq!(round_trip_app.action_list, test_app.action_list);
+        assert_eq!(
+            round_trip_app.get_children_for_id(test_app.get_action_id(2).unwrap()).unwrap(),
+            im::vector![test_app.get_action_id(3).unwrap()]
+        );
+        assert_eq!(round_trip_app.relationship_list.len(), 1);
+    }
+
+    #[test]
+    fn failed_import_unknown_parent_id() {
+        let import_error = ClearHeadApp::default()
+            .import_from_todo_txt(format!("Weed beds +Garden parent:{}\n", Uuid::nil()).as_bytes())
+            .unwrap_err();
+
+        assert_eq!(
+            import_error.to_string(),
+            "Line 1: parent:00000000-0000-0000-0000-000000000000 refers to no Action"
+        );
+    }
+
+    #[test]
+    fn failed_import_invalid_extension() {
+        let import_error = ClearHeadApp::default()
+            .import_from_todo_txt("Call Mom\nPay rent due:tomorrow\n".as_bytes())
+            .unwrap_err();
+
+        assert_eq!(import_error.to_string(), "Line 2: tomorrow is an Invalid Due Date");
+    }
+
+    #[test]
+    fn failed_import_duplicate_action_id() {
+        let test_app = create_app_with_two_actions();
+
+        let import_error = test_app
+            .import_from_todo_txt(test_app.export_to_todo_txt().as_bytes())
+            .unwrap_err();
+
+        assert_eq!(
+            import_error.to_string(),
+            format!("Line 1: an Action with Id {} already exists", test_app.get_action_id(0).unwrap())
+        );
+    }
+}
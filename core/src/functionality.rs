@@ -1,8 +1,11 @@
+use crate::constraints::GraphConstraints;
+use crate::rollup::{get_indexed_progress, RollupSettings};
+use crate::relationship::item::CustomVariant;
 use crate::relationship::Relationship;
+use crate::relationship::RelationshipIndexCache;
 use crate::relationship::RelationshipListManagement;
 
-use crate::action::Action;
-use crate::action_implementation::ActionListManipulation;
+use crate::action::{Action, ActionError};
 use crate::ActionManipulation;
 
 use tabled::object::Rows;
@@ -12,22 +15,83 @@ use tabled::Header;
 use tabled::Modify;
 
 use std::cmp::PartialEq;
+use std::collections::HashMap;
 use std::error::Error;
-use std::fmt::Debug;
+use std::fmt::{self, Debug, Formatter};
 use tabled::Table;
+use tabled::Tabled;
 
 use im::Vector;
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
-#[derive(Debug, PartialEq, Default, Clone, Serialize, Deserialize)]
+#[derive(PartialEq, Default, Clone, Serialize, Deserialize)]
 pub struct ClearHeadApp {
     pub action_list: Vector<Action>,
     pub relationship_list: Vector<Relationship>,
+    #[serde(default, skip_serializing_if = "Vector::is_empty")]
+    pub custom_variants: Vector<CustomVariant>,
+    #[serde(default, skip_serializing_if = "GraphConstraints::is_unconstrained")]
+    pub constraints: GraphConstraints,
+    #[serde(default, skip_serializing_if = "RollupSettings::is_default")]
+    pub rollup: RollupSettings,
+    #[serde(default, skip_serializing_if = "is_initial_revision")]
+    pub revision: u64,
+    #[serde(skip)]
+    pub(crate) relationship_index: RelationshipIndexCache,
+}
+
+impl Debug for ClearHeadApp {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("ClearHeadApp")
+            .field("action_list", &self.action_list)
+            .field("relationship_list", &self.relationship_list)
+            .field("custom_variants", &self.custom_variants)
+            .field("constraints", &self.constraints)
+            .field("rollup", &self.rollup)
+            .field("revision", &self.revision)
+            .finish()
+    }
+}
+
+fn is_initial_revision(revision: &u64) -> bool {
+    *revision == 0
+}
+
+#[derive(Tabled)]
+struct ActionListRow {
+    #[tabled(rename = "Name")]
+    name: String,
+    #[tabled(rename = "Priority")]
+    priority: String,
+    #[tabled(rename = "Completed")]
+    completed: bool,
+    #[tabled(rename = "Progress")]
+    progress: String,
 }
 
 impl ClearHeadApp {
     pub fn get_list(&self) -> Table {
-        Table::builder(&self.action_list)
+        let relationship_index = self.get_relationship_index();
+        let weights = self.get_progress_weights();
+        let rows: Vec<ActionListRow> = self
+            .action_list
+            .iter()
+            .map(|action| {
+                let progress = get_indexed_progress(&relationship_index, &weights, action.get_id());
+                ActionListRow {
+                    name: action.get_name(),
+                    priority: action.get_priority(),
+                    completed: action.get_completion_status(),
+                    progress: match progress.total {
+                        0 => String::new(),
+                        _ => progress.to_string(),
+                    },
+                }
+            })
+            .collect();
+
+        Table::builder(&rows)
             .index()
             .build()
             .with(Header("Action List"))
@@ -38,23 +102,26 @@ impl ClearHeadApp {
 
     pub fn get_extended_list(&self) -> Result<String, Box<dyn Error>> {
         let mut extended_list = String::new();
-        let mut index = 0;
+        let relationship_index = self.get_relationship_index();
+        let actions_by_id: HashMap<Uuid, &Action> = self
+            .action_list
+            .iter()
+            .map(|action| (action.get_id(), action))
+            .collect();
 
         extended_list.push_str("Order,Name,Priority,Completed,Id\n");
-        for action in &self.action_list {
+        for (index, action) in self.action_list.iter().enumerate() {
             extended_list.push_str(&format!("{},{}\n", index, action.to_string()));
-            index += 1;
-
-            if self.id_is_present_in_participant_1_list(action.get_id()) {
-                for relationship in &self.get_participant_1_list_for_id(action.get_id())? {
-                    extended_list.push_str(&format!(
-                        "  - {},{}\n",
-                        relationship.get_variant(),
-                        &self
-                            .action_list
-                            .select_action_by_id(relationship.get_participant_2())?
-                    ));
-                }
+
+            for relationship in relationship_index.get_all_outgoing(action.get_id()) {
+                let related_action = actions_by_id
+                    .get(&relationship.get_participant_2())
+                    .ok_or(ActionError::InvalidId(relationship.get_participant_2()))?;
+                extended_list.push_str(&format!(
+                    "  - {},{}\n",
+                    relationship.get_variant(),
+                    related_action
+                ));
             }
         }
         Ok(extended_list)
@@ -96,6 +163,14 @@ pub mod tests {
         app
     }
 
+    pub fn create_temporary_directory(test_name: &str) -> std::path::PathBuf {
+        let directory = std::env::temp_dir().join(format!("clear_head_{}_{}", test_name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&directory);
+        std::fs::create_dir_all(&directory).unwrap();
+
+        directory
+    }
+
     pub fn get_first_action(app: &ClearHeadApp) -> Action {
         app.action_list[0].clone()
     }
@@ -124,6 +199,7 @@ pub mod tests {
 
         assert_eq!(test_app.action_list, Vector::new());
         assert_eq!(test_app.relationship_list, Vector::new());
+        assert_eq!(test_app.custom_variants, Vector::new());
     }
 
     #[test]
@@ -134,15 +210,15 @@ pub mod tests {
 
         let expected_string = indoc!(
             "
-            +---+----------------+----------+-----------+
-            |                Action List                |
-            +---+----------------+----------+-----------+
-            |   | Name           | Priority | Completed |
-            +---+----------------+----------+-----------+
-            | 0 | Default Action | Optional | false     |
-            +---+----------------+----------+-----------+
-            |                 1 Item(s)                 |
-            +---+----------------+----------+-----------+"
+            +---+----------------+----------+-----------+----------+
+            |                     Action List                      |
+            +---+----------------+----------+-----------+----------+
+            |   | Name           | Priority | Completed | Progress |
+            +---+----------------+----------+-----------+----------+
+            | 0 | Default Action | Optional | false     |          |
+            +---+----------------+----------+-----------+----------+
+            |                      1 Item(s)                       |
+            +---+----------------+----------+-----------+----------+"
         );
 
         assert_eq!(action_list_string.to_string(), expected_string);
@@ -156,17 +232,43 @@ pub mod tests {
 
         let expected_string = indoc!(
             "
-            +---+----------------+----------+-----------+
-            |                Action List                |
-            +---+----------------+----------+-----------+
-            |   | Name           | Priority | Completed |
-            +---+----------------+----------+-----------+
-            | 0 | Default Action | Optional | false     |
-            +---+----------------+----------+-----------+
-            | 1 | Default Action | Optional | false     |
-            +---+----------------+----------+-----------+
-            |                 2 Item(s)                 |
-            +---+----------------+----------+-----------+"
+            +---+----------------+----------+-----------+----------+
+            |                     Action List                      |
+            +---+----------------+----------+-----------+----------+
+            |   | Name           | Priority | Completed | Progress |
+            +---+----------------+----------+-----------+----------+
+            | 0 | Default Action | Optional | false     |          |
+            +---+----------------+----------+-----------+----------+
+            | 1 | Default Action | Optional | false     |          |
+            +---+----------------+----------+-----------+----------+
+            |                      2 Item(s)                       |
+            +---+----------------+----------+-----------+----------+"
+        );
+
+        assert_eq!(action_list_string.to_string(), expected_string);
+    }
+
+    #[test]
+    fn list_parent_progress() {
+        let test_app = create_minimal_related_app("parental")
+            .toggle_action_completion_status(1)
+            .unwrap();
+
+        let action_list_string = test_app.get_list();
+
+        let expected_string = indoc!(
+            "
+            +---+----------------+----------+-----------+------------+
+            |                      Action List                       |
+            +---+----------------+----------+-----------+------------+
+            |   | Name           | Priority | Completed | Progress   |
+            +---+----------------+----------+-----------+------------+
+            | 0 | Default Action | Optional | false     | 1/1 (100%) |
+            +---+----------------+----------+-----------+------------+
+            | 1 | Default Action | Optional | true      |            |
+            +---+----------------+----------+-----------+------------+
+            |                       2 Item(s)                        |
+            +---+----------------+----------+-----------+------------+"
         );
 
         assert_eq!(action_list_string.to_string(), expected_string);
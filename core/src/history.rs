@@ -0,0 +1,262 @@
+use crate::functionality::ClearHeadApp;
+use crate::storage::{read_json_file, write_json_file};
+
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use im::Vector;
+use serde::{Deserialize, Serialize};
+
+pub const DEFAULT_HISTORY_DEPTH: usize = 20;
+
+#[derive(Debug, PartialEq)]
+pub enum HistoryError {
+    NothingToUndo,
+    NothingToRedo,
+}
+
+impl Display for HistoryError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            HistoryError::NothingToUndo => write!(f, "Nothing to Undo"),
+            HistoryError::NothingToRedo => write!(f, "Nothing to Redo"),
+        }
+    }
+}
+
+impl Error for HistoryError {}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    pub description: String,
+    pub recorded: DateTime<Utc>,
+    pub app: ClearHeadApp,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UndoHistory {
+    depth: usize,
+    undo_stack: Vector<HistoryEntry>,
+    redo_stack: Vector<HistoryEntry>,
+}
+
+impl Default for UndoHistory {
+    fn default() -> UndoHistory {
+        UndoHistory {
+            depth: DEFAULT_HISTORY_DEPTH,
+            undo_stack: Vector::new(),
+            redo_stack: Vector::new(),
+        }
+    }
+}
+
+impl UndoHistory {
+    pub fn read_from_file(history_path: &Path) -> Result<UndoHistory, Box<dyn Error>> {
+        match read_json_file(history_path) {
+            Ok(history) => Ok(history),
+            Err(error) => match error.downcast_ref::<std::io::Error>() {
+                Some(io_error) if io_error.kind() == ErrorKind::NotFound => Ok(UndoHistory::default()),
+                _ => Err(error),
+            },
+        }
+    }
+
+    pub fn write_to_file(&self, history_path: &Path) -> Result<(), Box<dyn Error>> {
+        write_json_file(self, history_path, false, 0)
+    }
+
+    pub fn get_depth(&self) -> usize {
+        self.depth
+    }
+
+    pub fn set_depth(&self, depth: usize) -> UndoHistory {
+        UndoHistory {
+            depth,
+            undo_stack: trim_to_depth(&self.undo_stack, depth),
+            redo_stack: trim_to_depth(&self.redo_stack, depth),
+        }
+    }
+
+    pub fn get_undo_description(&self) -> Option<&str> {
+        self.undo_stack.back().map(|entry| entry.description.as_str())
+    }
+
+    pub fn get_redo_description(&self) -> Option<&str> {
+        self.redo_stack.back().map(|entry| entry.description.as_str())
+    }
+
+    pub fn record(&self, description: &str, previous_app: &ClearHeadApp) -> UndoHistory {
+        let mut undo_stack = self.undo_stack.clone();
+        undo_stack.push_back(HistoryEntry {
+            description: description.to_string(),
+            recorded: Utc::now(),
+            app: previous_app.clone(),
+        });
+
+        UndoHistory {
+            depth: self.depth,
+            undo_stack: trim_to_depth(&undo_stack, self.depth),
+            redo_stack: Vector::new(),
+        }
+    }
+
+    pub fn undo(&self, current_app: &ClearHeadApp) -> Result<(UndoHistory, ClearHeadApp, String), Box<dyn Error>> {
+        let mut undo_stack = self.undo_stack.clone();
+        let entry = undo_stack.pop_back().ok_or(HistoryError::NothingToUndo)?;
+
+        let mut redo_stack = self.redo_stack.clone();
+        redo_stack.push_back(HistoryEntry {
+            description: entry.description.clone(),
+            recorded: Utc::now(),
+            app: current_app.clone(),
+        });
+
+        let updated_history = UndoHistory {
+            depth: self.depth,
+            undo_stack,
+            redo_stack: trim_to_depth(&redo_stack, self.depth),
+        };
+
+        Ok((updated_history, restore(&entry.app, current_app), entry.description))
+    }
+
+    pub fn redo(&self, current_app: &ClearHeadApp) -> Result<(UndoHistory, ClearHeadApp, String), Box<dyn Error>> {
+        let mut redo_stack = self.redo_stack.clone();
+        let entry = redo_stack.pop_back().ok_or(HistoryError::NothingToRedo)?;
+
+        let mut undo_stack = self.undo_stack.clone();
+        undo_stack.push_back(HistoryEntry {
+            description: entry.description.clone(),
+            recorded: Utc::now(),
+            app: current_app.clone(),
+        });
+
+        let updated_history = UndoHistory {
+            depth: self.depth,
+            undo_stack: trim_to_depth(&undo_stack, self.depth),
+            redo_stack,
+        };
+
+        Ok((updated_history, restore(&entry.app, current_app), entry.description))
+    }
+}
+
+fn restore(stored_app: &ClearHeadApp, current_app: &ClearHeadApp) -> ClearHeadApp {
+    let mut restored_app = stored_app.clone();
+    restored_app.revision = current_app.revision;
+
+    restored_app
+}
+
+fn trim_to_depth(stack: &Vector<HistoryEntry>, depth: usize) -> Vector<HistoryEntry> {
+    stack.skip(stack.len().saturating_sub(depth))
+}
+
+pub fn get_history_path(file_path: &Path) -> PathBuf {
+    let mut history_name = file_path.file_name().unwrap_or_default().to_os_string();
+    history_name.push(".history");
+
+    file_path.with_file_name(history_name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::functionality::tests::{create_app_with_two_actions, create_temporary_directory};
+
+    #[test]
+    fn undo_and_redo_change() {
+        let test_app = create_app_with_two_actions();
+        let renamed_app = test_app.rename_action(0, "Paint Fence".to_string()).unwrap();
+        let history = UndoHistory::default().record("Renamed Action", &test_app);
+
+        let (undone_history, undone_app, description) = history.undo(&renamed_app).unwrap();
+        assert_eq!(description, "Renamed Action");
+        assert_eq!(undone_app, test_app);
+        assert_eq!(undone_history.get_redo_description(), Some("Renamed Action"));
+
+        let (redone_history, redone_app, _) = undone_history.redo(&undone_app).unwrap();
+        assert_eq!(redone_app, renamed_app);
+        assert_eq!(redone_history.get_undo_description(), Some("Renamed Action"));
+        assert_eq!(redone_history.get_redo_description(), None);
+    }
+
+    #[test]
+    fn multiple_levels_of_undo() {
+        let first_app = ClearHeadApp::default();
+        let second_app = first_app.append_default_action();
+        let third_app = second_app.append_default_action();
+        let history = UndoHistory::default()
+            .record("First", &first_app)
+            .record("Second", &second_app);
+
+        let (history, undone_app, _) = history.undo(&third_app).unwrap();
+        assert_eq!(undone_app, second_app);
+        let (_, undone_app, description) = history.undo(&undone_app).unwrap();
+
+        assert_eq!(undone_app, first_app);
+        assert_eq!(description, "First");
+    }
+
+    #[test]
+    fn recording_clears_redo() {
+        let test_app = ClearHeadApp::default();
+        let (history, _, _) = UndoHistory::default()
+            .record("Created Action", &test_app)
+            .undo(&test_app.append_default_action())
+            .unwrap();
+
+        let history = history.record("Renamed Action", &test_app);
+
+        assert_eq!(history.get_redo_description(), None);
+    }
+
+    #[test]
+    fn keep_configured_depth() {
+        let test_app = ClearHeadApp::default();
+        let history = UndoHistory::default()
+            .set_depth(2)
+            .record("First", &test_app)
+            .record("Second", &test_app)
+            .record("Third", &test_app);
+
+        let (history, _, _) = history.undo(&test_app).unwrap();
+        let (history, _, _) = history.undo(&test_app).unwrap();
+
+        assert_eq!(history.undo(&test_app).unwrap_err().to_string(), "Nothing to Undo");
+    }
+
+    #[test]
+    fn failed_redo_without_undo() {
+        let redo_error = UndoHistory::default().redo(&ClearHeadApp::default()).unwrap_err();
+
+        assert_eq!(redo_error.to_string(), "Nothing to Redo");
+    }
+
+    #[test]
+    fn restored_app_keeps_current_revision() {
+        let mut current_app = ClearHeadApp::default().append_default_action();
+        current_app.revision = 7;
+        let history = UndoHistory::default().record("Created Action", &ClearHeadApp::default());
+
+        let (_, undone_app, _) = history.undo(&current_app).unwrap();
+
+        assert_eq!(undone_app.revision, 7);
+    }
+
+    #[test]
+    fn write_and_read_history_file() {
+        let history_path = get_history_path(&create_temporary_directory("history_file").join("app.json"));
+        let history = UndoHistory::default()
+            .set_depth(5)
+            .record("Created Action", &create_app_with_two_actions());
+
+        assert_eq!(UndoHistory::read_from_file(&history_path).unwrap(), UndoHistory::default());
+        history.write_to_file(&history_path).unwrap();
+
+        assert_eq!(UndoHistory::read_from_file(&history_path).unwrap(), history);
+    }
+}
@@ -0,0 +1,877 @@
+use crate::action::{Action, ActionError};
+use crate::backend::StorageBackend;
+use crate::constraints::GraphConstraints;
+use crate::functionality::ClearHeadApp;
+use crate::relationship::item::CustomVariant;
+use crate::relationship::{Relationship, RelationshipVariant};
+use crate::rollup::RollupSettings;
+use crate::storage::{read_json_file, write_atomically, write_json_file, StorageError};
+use crate::ActionManipulation;
+
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::fs::{self, OpenOptions};
+use std::io::{BufWriter, ErrorKind, Write};
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, NaiveDate, Utc};
+use im::Vector;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+pub const DEFAULT_SNAPSHOT_INTERVAL: u64 = 100;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum Event {
+    ActionAdded { position: usize, action: Action },
+    ActionRemoved { id: Uuid },
+    ActionRenamed { id: Uuid, name: String },
+    ActionReprioritized { id: Uuid, priority: String },
+    ActionCompletionToggled { id: Uuid },
+    ActionEstimateChanged { id: Uuid, estimate: Option<u32> },
+    ActionDueDateChanged { id: Uuid, due_date: Option<NaiveDate> },
+    ActionsReordered { ids: Vec<Uuid> },
+    RelationshipAdded { position: usize, relationship: Relationship },
+    RelationshipRemoved { id: Uuid },
+    RelationshipVariantChanged { id: Uuid, variant: RelationshipVariant },
+    RelationshipRetargeted { id: Uuid, participant_1: Uuid, participant_2: Uuid },
+    RelationshipLabelChanged { id: Uuid, label: Option<String> },
+    RelationshipWeightChanged { id: Uuid, weight: Option<f64> },
+    RelationshipCreatedChanged { id: Uuid, created: Option<DateTime<Utc>> },
+    RelationshipsReordered { ids: Vec<Uuid> },
+    CustomVariantsChanged { custom_variants: Vector<CustomVariant> },
+    ConstraintsChanged { constraints: GraphConstraints },
+    RollupChanged { rollup: RollupSettings },
+}
+
+impl Display for Event {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Event::ActionAdded { action, .. } => {
+                write!(f, "Added Action {} ({})", action.get_name(), action.get_id())
+            }
+            Event::ActionRemoved { id } => write!(f, "Removed Action {}", id),
+            Event::ActionRenamed { id, name } => write!(f, "Renamed Action {} to {}", id, name),
+            Event::ActionReprioritized { id, priority } => {
+                write!(f, "Changed Priority of Action {} to {}", id, priority)
+            }
+            Event::ActionCompletionToggled { id } => {
+                write!(f, "Toggled Completion Status of Action {}", id)
+            }
+            Event::ActionEstimateChanged { id, estimate } => match estimate {
+                Some(estimate) => write!(f, "Changed Estimate of Action {} to {}", id, estimate),
+                None => write!(f, "Cleared Estimate of Action {}", id),
+            },
+            Event::ActionDueDateChanged { id, due_date } => match due_date {
+                Some(due_date) => write!(f, "Changed Due Date of Action {} to {}", id, due_date),
+                None => write!(f, "Cleared Due Date of Action {}", id),
+            },
+            Event::ActionsReordered { .. } => write!(f, "Reordered Actions"),
+            Event::RelationshipAdded { relationship, .. } => write!(
+                f,
+                "Added {} Relationship {} ({} -> {})",
+                relationship.get_variant(),
+                relationship.get_id(),
+                relationship.get_participant_1(),
+                relationship.get_participant_2()
+            ),
+            Event::RelationshipRemoved { id } => write!(f, "Removed Relationship {}", id),
+            Event::RelationshipVariantChanged { id, variant } => {
+                write!(f, "Changed Variant of Relationship {} to {}", id, variant)
+            }
+            Event::RelationshipRetargeted {
+                id,
+                participant_1,
+                participant_2,
+            } => write!(
+                f,
+                "Retargeted Relationship {} to {} -> {}",
+                id, participant_1, participant_2
+            ),
+            Event::RelationshipLabelChanged { id, label } => match label {
+                Some(label) => write!(f, "Changed Label of Relationship {} to {}", id, label),
+                None => write!(f, "Cleared Label of Relationship {}", id),
+            },
+            Event::RelationshipWeightChanged { id, weight } => match weight {
+                Some(weight) => write!(f, "Changed Weight of Relationship {} to {}", id, weight),
+                None => write!(f, "Cleared Weight of Relationship {}", id),
+            },
+            Event::RelationshipCreatedChanged { id, created } => match created {
+                Some(created) => write!(f, "Changed Creation Time of Relationship {} to {}", id, created),
+                None => write!(f, "Cleared Creation Time of Relationship {}", id),
+            },
+            Event::RelationshipsReordered { .. } => write!(f, "Reordered Relationships"),
+            Event::CustomVariantsChanged { .. } => write!(f, "Changed Custom Variants"),
+            Event::ConstraintsChanged { .. } => write!(f, "Changed Graph Constraints"),
+            Event::RollupChanged { .. } => write!(f, "Changed Rollup Settings"),
+        }
+    }
+}
+
+impl Event {
+    pub fn apply(&self, app: &ClearHeadApp) -> Result<ClearHeadApp, Box<dyn Error>> {
+        let mut updated_app = app.clone();
+
+        match self {
+            Event::ActionAdded { position, action } => {
+                let position = (*position).min(updated_app.action_list.len());
+                updated_app.action_list.insert(position, action.clone());
+            }
+            Event::ActionRemoved { id } => {
+                let index = find_action(app, *id)?;
+                updated_app.action_list.remove(index);
+            }
+            Event::ActionRenamed { id, name } => {
+                update_action(&mut updated_app, *id, |action| Ok(action.rename(name)))?
+            }
+            Event::ActionReprioritized { id, priority } => {
+                update_action(&mut updated_app, *id, |action| action.change_priority(priority))?
+            }
+            Event::ActionCompletionToggled { id } => {
+                update_action(&mut updated_app, *id, |action| Ok(action.toggle_completion_status()))?
+            }
+            Event::ActionEstimateChanged { id, estimate } => {
+                update_action(&mut updated_app, *id, |action| Ok(action.set_estimate(*estimate)))?
+            }
+            Event::ActionDueDateChanged { id, due_date } => {
+                update_action(&mut updated_app, *id, |action| Ok(action.set_due_date(*due_date)))?
+            }
+            Event::ActionsReordered { ids } => {
+                updated_app.action_list = reorder(&app.action_list, ids, |action| action.get_id())?;
+            }
+            Event::RelationshipAdded {
+                position,
+                relationship,
+            } => {
+                let position = (*position).min(updated_app.relationship_list.len());
+                updated_app.relationship_list.insert(position, relationship.clone());
+            }
+            Event::RelationshipRemoved { id } => {
+                let index = find_relationship(app, *id)?;
+                updated_app.relationship_list.remove(index);
+            }
+            Event::RelationshipVariantChanged { id, variant } => {
+                update_relationship(&mut updated_app, *id, |relationship| {
+                    Ok(relationship.set_variant_to(variant.clone()))
+                })?
+            }
+            Event::RelationshipRetargeted {
+                id,
+                participant_1,
+                participant_2,
+            } => update_relationship(&mut updated_app, *id, |relationship| {
+                Ok(relationship
+                    .set_participant_1(*participant_1)
+                    .set_participant_2(*participant_2))
+            })?,
+            Event::RelationshipLabelChanged { id, label } => {
+                update_relationship(&mut updated_app, *id, |relationship| {
+                    Ok(relationship.set_label(label.as_deref()))
+                })?
+            }
+            Event::RelationshipWeightChanged { id, weight } => {
+                update_relationship(&mut updated_app, *id, |relationship| {
+                    Ok(relationship.set_weight(*weight)?)
+                })?
+            }
+            Event::RelationshipCreatedChanged { id, created } => {
+                update_relationship(&mut updated_app, *id, |relationship| {
+                    Ok(relationship.set_created(*created))
+                })?
+            }
+            Event::RelationshipsReordered { ids } => {
+                updated_app.relationship_list =
+                    reorder(&app.relationship_list, ids, |relationship| relationship.get_id())?;
+            }
+            Event::CustomVariantsChanged { custom_variants } => {
+                updated_app.custom_variants = custom_variants.clone();
+            }
+            Event::ConstraintsChanged { constraints } => {
+                updated_app.constraints = *constraints;
+            }
+            Event::RollupChanged { rollup } => {
+                updated_app.rollup = *rollup;
+            }
+        }
+
+        Ok(updated_app)
+    }
+}
+
+fn find_action(app: &ClearHeadApp, id: Uuid) -> Result<usize, Box<dyn Error>> {
+    app.action_list
+        .iter()
+        .position(|action| action.get_id() == id)
+        .ok_or_else(|| ActionError::InvalidId(id).into())
+}
+
+fn find_relationship(app: &ClearHeadApp, id: Uuid) -> Result<usize, Box<dyn Error>> {
+    app.relationship_list
+        .iter()
+        .position(|relationship| relationship.get_id() == id)
+        .ok_or_else(|| format!("No Relationship with Id {}", id).into())
+}
+
+fn update_action(
+    app: &mut ClearHeadApp,
+    id: Uuid,
+    change: impl FnOnce(&Action) -> Result<Action, Box<dyn Error>>,
+) -> Result<(), Box<dyn Error>> {
+    let index = find_action(app, id)?;
+    let updated_action = change(&app.action_list[index])?;
+    app.action_list.set(index, updated_action);
+
+    Ok(())
+}
+
+fn update_relationship(
+    app: &mut ClearHeadApp,
+    id: Uuid,
+    change: impl FnOnce(&Relationship) -> Result<Relationship, Box<dyn Error>>,
+) -> Result<(), Box<dyn Error>> {
+    let index = find_relationship(app, id)?;
+    let updated_relationship = change(&app.relationship_list[index])?;
+    app.relationship_list.set(index, updated_relationship);
+
+    Ok(())
+}
+
+fn reorder<T: Clone>(
+    list: &Vector<T>,
+    ids: &[Uuid],
+    get_id: impl Fn(&T) -> Uuid,
+) -> Result<Vector<T>, Box<dyn Error>> {
+    if ids.len() != list.len() {
+        return Err(format!("Reordering has {} Ids for {} items", ids.len(), list.len()).into());
+    }
+
+    let items: HashMap<Uuid, &T> = list.iter().map(|item| (get_id(item), item)).collect();
+
+    ids.iter()
+        .map(|id| {
+            items
+                .get(id)
+                .map(|item| (*item).clone())
+                .ok_or_else(|| format!("Reordering refers to unknown Id {}", id).into())
+        })
+        .collect()
+}
+
+struct ListChanges<T> {
+    removed: Vec<Uuid>,
+    added: Vec<(usize, T)>,
+    reordered: Option<Vec<Uuid>>,
+}
+
+fn diff_list<T: Clone>(previous: &Vector<T>, updated: &Vector<T>, get_id: impl Fn(&T) -> Uuid) -> ListChanges<T> {
+    let previous_ids: HashSet<Uuid> = previous.iter().map(&get_id).collect();
+    let updated_ids: Vec<Uuid> = updated.iter().map(&get_id).collect();
+    let kept_ids: HashSet<Uuid> = updated_ids.iter().copied().collect();
+
+    let removed: Vec<Uuid> = previous
+        .iter()
+        .map(&get_id)
+        .filter(|id| !kept_ids.contains(id))
+        .collect();
+    let added: Vec<(usize, T)> = updated
+        .iter()
+        .enumerate()
+        .filter(|(_, item)| !previous_ids.contains(&get_id(item)))
+        .map(|(position, item)| (position, item.clone()))
+        .collect();
+
+    let mut replayed_ids: Vec<Uuid> = previous
+        .iter()
+        .map(&get_id)
+        .filter(|id| kept_ids.contains(id))
+        .collect();
+    for (position, item) in &added {
+        replayed_ids.insert((*position).min(replayed_ids.len()), get_id(item));
+    }
+
+    ListChanges {
+        removed,
+        added,
+        reordered: (replayed_ids != updated_ids).then_some(updated_ids),
+    }
+}
+
+pub fn record_events(previous_app: &ClearHeadApp, updated_app: &ClearHeadApp) -> Vec<Event> {
+    let mut events = Vec::new();
+
+    let action_changes = diff_list(&previous_app.action_list, &updated_app.action_list, |action| {
+        action.get_id()
+    });
+    events.extend(action_changes.removed.into_iter().map(|id| Event::ActionRemoved { id }));
+    events.extend(
+        action_changes
+            .added
+            .into_iter()
+            .map(|(position, action)| Event::ActionAdded { position, action }),
+    );
+    events.extend(action_changes.reordered.map(|ids| Event::ActionsReordered { ids }));
+
+    let previous_actions: HashMap<Uuid, &Action> = previous_app
+        .action_list
+        .iter()
+        .map(|action| (action.get_id(), action))
+        .collect();
+    for action in updated_app.action_list.iter() {
+        let id = action.get_id();
+        let previous_action = match previous_actions.get(&id) {
+            Some(previous_action) => previous_action,
+            None => continue,
+        };
+        if previous_action.get_name() != action.get_name() {
+            events.push(Event::ActionRenamed { id, name: action.get_name() });
+        }
+        if previous_action.get_priority() != action.get_priority() {
+            events.push(Event::ActionReprioritized { id, priority: action.get_priority() });
+        }
+        if previous_action.get_completion_status() != action.get_completion_status() {
+            events.push(Event::ActionCompletionToggled { id });
+        }
+        if previous_action.get_estimate() != action.get_estimate() {
+            events.push(Event::ActionEstimateChanged { id, estimate: action.get_estimate() });
+        }
+        if previous_action.get_due_date() != action.get_due_date() {
+            events.push(Event::ActionDueDateChanged { id, due_date: action.get_due_date() });
+        }
+    }
+
+    let relationship_changes = diff_list(
+        &previous_app.relationship_list,
+        &updated_app.relationship_list,
+        |relationship| relationship.get_id(),
+    );
+    events.extend(
+        relationship_changes
+            .removed
+            .into_iter()
+            .map(|id| Event::RelationshipRemoved { id }),
+    );
+    events.extend(
+        relationship_changes
+            .added
+            .into_iter()
+            .map(|(position, relationship)| Event::RelationshipAdded { position, relationship }),
+    );
+    events.extend(
+        relationship_changes
+            .reordered
+            .map(|ids| Event::RelationshipsReordered { ids }),
+    );
+
+    let previous_relationships: HashMap<Uuid, &Relationship> = previous_app
+        .relationship_list
+        .iter()
+        .map(|relationship| (relationship.get_id(), relationship))
+        .collect();
+    for relationship in updated_app.relationship_list.iter() {
+        let id = relationship.get_id();
+        let previous_relationship = match previous_relationships.get(&id) {
+            Some(previous_relationship) => previous_relationship,
+            None => continue,
+        };
+        if previous_relationship.get_variant() != relationship.get_variant() {
+            events.push(Event::RelationshipVariantChanged { id, variant: relationship.get_variant() });
+        }
+        if previous_relationship.get_participant_1() != relationship.get_participant_1()
+            || previous_relationship.get_participant_2() != relationship.get_participant_2()
+        {
+            events.push(Event::RelationshipRetargeted {
+                id,
+                participant_1: relationship.get_participant_1(),
+                participant_2: relationship.get_participant_2(),
+            });
+        }
+        if previous_relationship.get_label() != relationship.get_label() {
+            events.push(Event::RelationshipLabelChanged { id, label: relationship.get_label() });
+        }
+        if previous_relationship.get_weight() != relationship.get_weight() {
+            events.push(Event::RelationshipWeightChanged { id, weight: relationship.get_weight() });
+        }
+        if previous_relationship.get_created() != relationship.get_created() {
+            events.push(Event::RelationshipCreatedChanged { id, created: relationship.get_created() });
+        }
+    }
+
+    if previous_app.custom_variants != updated_app.custom_variants {
+        events.push(Event::CustomVariantsChanged {
+            custom_variants: updated_app.custom_variants.clone(),
+        });
+    }
+    if previous_app.constraints != updated_app.constraints {
+        events.push(Event::ConstraintsChanged {
+            constraints: updated_app.constraints,
+        });
+    }
+    if previous_app.rollup != updated_app.rollup {
+        events.push(Event::RollupChanged {
+            rollup: updated_app.rollup,
+        });
+    }
+
+    events
+}
+
+pub fn replay_events<'a>(
+    app: &ClearHeadApp,
+    events: impl IntoIterator<Item = &'a Event>,
+) -> Result<ClearHeadApp, Box<dyn Error>> {
+    events
+        .into_iter()
+        .try_fold(app.clone(), |replayed_app, event| event.apply(&replayed_app))
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JournalEntry {
+    pub sequence: u64,
+    pub recorded: DateTime<Utc>,
+    pub event: Event,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JournalSnapshot {
+    pub sequence: u64,
+    pub recorded: DateTime<Utc>,
+    pub app: ClearHeadApp,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct JournalBackend {
+    journal_path: PathBuf,
+    snapshot_interval: u64,
+}
+
+impl JournalBackend {
+    pub fn new(journal_path: &Path) -> JournalBackend {
+        JournalBackend {
+            journal_path: journal_path.to_path_buf(),
+            snapshot_interval: DEFAULT_SNAPSHOT_INTERVAL,
+        }
+    }
+
+    pub fn set_snapshot_interval(&self, snapshot_interval: u64) -> JournalBackend {
+        JournalBackend {
+            snapshot_interval,
+            ..self.clone()
+        }
+    }
+
+    pub fn get_journal_path(&self) -> &Path {
+        &self.journal_path
+    }
+
+    pub fn get_snapshot_path(&self) -> PathBuf {
+        let mut snapshot_name = self.journal_path.file_name().unwrap_or_default().to_os_string();
+        snapshot_name.push(".snapshot");
+
+        self.journal_path.with_file_name(snapshot_name)
+    }
+
+    pub fn read_snapshot(&self) -> Result<Option<JournalSnapshot>, Box<dyn Error>> {
+        match read_json_file(&self.get_snapshot_path()) {
+            Ok(snapshot) => Ok(Some(snapshot)),
+            Err(error) => match error.downcast_ref::<std::io::Error>() {
+                Some(io_error) if io_error.kind() == ErrorKind::NotFound => Ok(None),
+                _ => Err(error),
+            },
+        }
+    }
+
+    pub fn read_entries(&self) -> Result<Vec<JournalEntry>, Box<dyn Error>> {
+        let contents = match fs::read_to_string(&self.journal_path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(error) => return Err(error.into()),
+        };
+
+        // a final line without a newline is a torn append and was never committed
+        let mut lines: Vec<&str> = contents.split('\n').collect();
+        lines.pop();
+
+        let mut entries = Vec::new();
+        for (line_number, line) in lines.iter().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let entry: JournalEntry = serde_json::from_str(line)
+                .map_err(|error| format!("Journal line {}: {}", line_number + 1, error))?;
+            entries.push(entry);
+        }
+
+        Ok(entries)
+    }
+
+    pub fn compact(&self) -> Result<usize, Box<dyn Error>> {
+        let entries = self.read_entries()?;
+        let app = self.load()?;
+
+        self.write_snapshot(&app)?;
+        write_atomically(&self.journal_path, 0, |_| Ok(()))?;
+
+        Ok(entries.len())
+    }
+
+    pub fn load_as_of(&self, time: DateTime<Utc>) -> Result<ClearHeadApp, Box<dyn Error>> {
+        let entries = self.read_entries()?;
+        let snapshot = self.read_snapshot()?;
+
+        let complete_journal = match entries.first() {
+            Some(entry) => entry.sequence == 1,
+            None => snapshot.is_none(),
+        };
+        let (base_app, base_sequence) = match snapshot {
+            Some(_) if complete_journal => (ClearHeadApp::default(), 0),
+            Some(snapshot) if snapshot.recorded <= time => (snapshot.app, snapshot.sequence),
+            Some(snapshot) => {
+                return Err(format!("History before {} was compacted", snapshot.recorded.to_rfc3339()).into())
+            }
+            None => (ClearHeadApp::default(), 0),
+        };
+
+        let entries: Vec<&JournalEntry> = entries
+            .iter()
+            .filter(|entry| entry.sequence > base_sequence && entry.recorded <= time)
+            .collect();
+        let mut app = replay_events(&base_app, entries.iter().map(|entry| &entry.event))?;
+        app.revision = entries.last().map(|entry| entry.sequence).unwrap_or(base_sequence);
+
+        Ok(app)
+    }
+
+    fn write_snapshot(&self, app: &ClearHeadApp) -> Result<(), Box<dyn Error>> {
+        let snapshot = JournalSnapshot {
+            sequence: app.revision,
+            recorded: Utc::now(),
+            app: app.clone(),
+        };
+
+        write_json_file(&snapshot, &self.get_snapshot_path(), false, 0)
+    }
+
+    fn discard_torn_append(&self) -> Result<(), Box<dyn Error>> {
+        let contents = match fs::read(&self.journal_path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == ErrorKind::NotFound => return Ok(()),
+            Err(error) => return Err(error.into()),
+        };
+
+        if contents.last().is_some_and(|byte| *byte != b'\n') {
+            let committed_length = contents.iter().rposition(|byte| *byte == b'\n').map_or(0, |end| end + 1);
+            OpenOptions::new()
+                .write(true)
+                .open(&self.journal_path)?
+                .set_len(committed_length as u64)?;
+        }
+
+        Ok(())
+    }
+
+    fn append_events(&self, stored_app: &ClearHeadApp, app: &ClearHeadApp) -> Result<ClearHeadApp, Box<dyn Error>> {
+        let events = record_events(stored_app, app);
+        let mut updated_app = app.clone();
+        updated_app.revision = stored_app.revision;
+        if events.is_empty() {
+            return Ok(updated_app);
+        }
+
+        self.discard_torn_append()?;
+        let journal_file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.journal_path)?;
+        let mut journal_writer = BufWriter::new(journal_file);
+        let recorded = Utc::now();
+        for event in events {
+            updated_app.revision += 1;
+            let entry = JournalEntry {
+                sequence: updated_app.revision,
+                recorded,
+                event,
+            };
+            serde_json::to_writer(&mut journal_writer, &entry)?;
+            journal_writer.write_all(b"\n")?;
+        }
+        journal_writer.flush()?;
+        journal_writer.get_ref().sync_all()?;
+
+        let snapshot_sequence = self.read_snapshot()?.map(|snapshot| snapshot.sequence).unwrap_or(0);
+        if self.snapshot_interval > 0 && updated_app.revision - snapshot_sequence >= self.snapshot_interval {
+            self.write_snapshot(&updated_app)?;
+        }
+
+        Ok(updated_app)
+    }
+}
+
+impl StorageBackend for JournalBackend {
+    fn load(&self) -> Result<ClearHeadApp, Box<dyn Error>> {
+        let (snapshot_app, snapshot_sequence) = match self.read_snapshot()? {
+            Some(snapshot) => (snapshot.app, snapshot.sequence),
+            None => (ClearHeadApp::default(), 0),
+        };
+
+        let entries: Vec<JournalEntry> = self
+            .read_entries()?
+            .into_iter()
+            .filter(|entry| entry.sequence > snapshot_sequence)
+            .collect();
+        let mut app = replay_events(&snapshot_app, entries.iter().map(|entry| &entry.event))?;
+        app.revision = entries.last().map(|entry| entry.sequence).unwrap_or(snapshot_sequence);
+
+        Ok(app)
+    }
+
+    fn save(&mut self, app: &ClearHeadApp) -> Result<(), Box<dyn Error>> {
+        let stored_app = self.load()?;
+        self.append_events(&stored_app, app)?;
+
+        Ok(())
+    }
+
+    fn save_if_unchanged(&mut self, app: &ClearHeadApp) -> Result<ClearHeadApp, Box<dyn Error>> {
+        let stored_app = self.load()?;
+        if stored_app.revision != app.revision {
+            return Err(StorageError::RevisionConflict {
+                expected: app.revision,
+                found: stored_app.revision,
+            }
+            .into());
+        }
+
+        self.append_events(&stored_app, app)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::functionality::tests::{create_app_with_two_actions, create_temporary_directory};
+    use crate::RelationshipListManagement;
+    use chrono::TimeZone;
+
+    fn without_revision(app: &ClearHeadApp) -> ClearHeadApp {
+        let mut app = app.clone();
+        app.revision = 0;
+        app
+    }
+
+    #[test]
+    fn record_typed_action_events() {
+        let test_app = create_app_with_two_actions();
+        let updated_app = test_app
+            .rename_action(0, "Paint Fence".to_string())
+            .unwrap()
+            .toggle_action_completion_status(1)
+            .unwrap();
+
+        let events = record_events(&test_app, &updated_app);
+
+        assert_eq!(
+            events,
+            vec![
+                Event::ActionRenamed {
+                    id: test_app.get_action_id(0).unwrap(),
+                    name: "Paint Fence".to_string()
+                },
+                Event::ActionCompletionToggled {
+                    id: test_app.get_action_id(1).unwrap()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn replay_recorded_events() {
+        let test_app = create_app_with_two_actions();
+        let updated_app = test_app
+            .append_default_action()
+            .create_action_relationship("parental", 2, 0)
+            .unwrap()
+            .remove_action(1)
+            .unwrap()
+            .set_graph_constraint("single_parent", true)
+            .unwrap();
+
+        let events = record_events(&test_app, &updated_app);
+
+        assert_eq!(replay_events(&test_app, &events).unwrap(), updated_app);
+    }
+
+    #[test]
+    fn replay_relationship_details() {
+        let test_app = create_app_with_two_actions()
+            .create_action_relationship("sequential", 0, 1)
+            .unwrap();
+        let created = Utc.with_ymd_and_hms(2024, 1, 2, 3, 4, 5).unwrap();
+        let updated_app = test_app
+            .set_relationship_label(0, Some("blocks"))
+            .unwrap()
+            .set_relationship_weight(0, Some(2.5))
+            .unwrap()
+            .set_relationship_created(0, Some(created))
+            .unwrap();
+
+        let events = record_events(&test_app, &updated_app);
+
+        assert_eq!(
+            events.last(),
+            Some(&Event::RelationshipCreatedChanged {
+                id: test_app.relationship_list[0].get_id(),
+                created: Some(created)
+            })
+        );
+        assert_eq!(replay_events(&test_app, &events).unwrap(), updated_app);
+
+        let journal_path = create_temporary_directory("journal_relationship_details").join("app.journal");
+        let mut backend = JournalBackend::new(&journal_path);
+        backend.update(&|_| Ok(test_app.clone())).unwrap();
+        let stored_app = backend.update(&|app| app.set_relationship_created(0, Some(created))).unwrap();
+
+        assert_eq!(JournalBackend::new(&journal_path).load().unwrap(), stored_app);
+        assert_eq!(stored_app.relationship_list[0].get_created(), Some(created));
+    }
+
+    #[test]
+    fn record_reordered_actions() {
+        let test_app = create_app_with_two_actions();
+        let mut updated_app = test_app.clone();
+        updated_app.action_list = test_app.action_list.iter().rev().cloned().collect();
+
+        let events = record_events(&test_app, &updated_app);
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(replay_events(&test_app, &events).unwrap(), updated_app);
+    }
+
+    #[test]
+    fn failed_replay_missing_action() {
+        let missing_id = Uuid::nil();
+
+        let replay_error = Event::ActionRemoved { id: missing_id }
+            .apply(&ClearHeadApp::default())
+            .unwrap_err();
+
+        assert_eq!(replay_error.to_string(), format!("No Action with Id {}", missing_id));
+    }
+
+    #[test]
+    fn rebuild_app_from_journal() {
+        let journal_path = create_temporary_directory("journal_rebuild").join("app.journal");
+        let mut backend = JournalBackend::new(&journal_path);
+
+        let stored_app = backend
+            .update(&|app| Ok(app.append_default_action().append_default_action()))
+            .unwrap();
+        let renamed_app = backend
+            .update(&|app| app.rename_action(1, "Sand Deck".to_string()))
+            .unwrap();
+
+        assert_eq!(stored_app.revision, 2);
+        assert_eq!(renamed_app.revision, 3);
+        assert_eq!(backend.read_entries().unwrap().len(), 3);
+        assert_eq!(JournalBackend::new(&journal_path).load().unwrap(), renamed_app);
+    }
+
+    #[test]
+    fn snapshot_after_interval() {
+        let journal_path = create_temporary_directory("journal_snapshot").join("app.journal");
+        let mut backend = JournalBackend::new(&journal_path).set_snapshot_interval(2);
+
+        backend.update(&|app| Ok(app.append_default_action())).unwrap();
+        assert_eq!(backend.read_snapshot().unwrap(), None);
+        let updated_app = backend.update(&|app| Ok(app.append_default_action())).unwrap();
+
+        let snapshot = backend.read_snapshot().unwrap().unwrap();
+        assert_eq!(snapshot.sequence, 2);
+        assert_eq!(without_revision(&snapshot.app), without_revision(&updated_app));
+        assert_eq!(backend.load().unwrap(), updated_app);
+    }
+
+    #[test]
+    fn compact_journal_into_snapshot() {
+        let journal_path = create_temporary_directory("journal_compaction").join("app.journal");
+        let mut backend = JournalBackend::new(&journal_path);
+        backend
+            .update(&|app| Ok(app.append_default_action().append_default_action()))
+            .unwrap();
+        let stored_app = backend.update(&|app| app.remove_action(0)).unwrap();
+
+        let compacted_entries = backend.compact().unwrap();
+
+        assert_eq!(compacted_entries, 3);
+        assert!(backend.read_entries().unwrap().is_empty());
+        assert_eq!(backend.load().unwrap(), stored_app);
+        let next_app = backend.update(&|app| Ok(app.append_default_action())).unwrap();
+        assert_eq!(next_app.revision, 4);
+    }
+
+    #[test]
+    fn load_app_as_of_earlier_time() {
+        let journal_path = create_temporary_directory("journal_point_in_time").join("app.journal");
+        let mut backend = JournalBackend::new(&journal_path);
+        let first_app = backend.update(&|app| Ok(app.append_default_action())).unwrap();
+        let between_updates = Utc::now();
+        std::thread::sleep(std::time::Duration::from_millis(5));
+        let second_app = backend.update(&|app| app.rename_action(0, "Sand Deck".to_string())).unwrap();
+
+        assert_eq!(backend.load_as_of(between_updates).unwrap(), first_app);
+        assert_eq!(backend.load_as_of(Utc::now()).unwrap(), second_app);
+        assert_eq!(
+            backend.load_as_of(between_updates - chrono::Duration::days(1)).unwrap(),
+            ClearHeadApp::default()
+        );
+    }
+
+    #[test]
+    fn failed_load_as_of_compacted_time() {
+        let journal_path = create_temporary_directory("journal_compacted_point_in_time").join("app.journal");
+        let mut backend = JournalBackend::new(&journal_path);
+        let before_updates = Utc::now() - chrono::Duration::seconds(1);
+        backend.update(&|app| Ok(app.append_default_action())).unwrap();
+        backend.compact().unwrap();
+        let snapshot = backend.read_snapshot().unwrap().unwrap();
+
+        let history_error = backend.load_as_of(before_updates).unwrap_err();
+
+        assert_eq!(
+            history_error.to_string(),
+            format!("History before {} was compacted", snapshot.recorded.to_rfc3339())
+        );
+        assert_eq!(backend.load_as_of(Utc::now()).unwrap(), backend.load().unwrap());
+    }
+
+    #[test]
+    fn ignore_torn_final_line() {
+        let journal_path = create_temporary_directory("journal_torn_line").join("app.journal");
+        let mut backend = JournalBackend::new(&journal_path);
+        let stored_app = backend.update(&|app| Ok(app.append_default_action())).unwrap();
+
+        let mut journal_file = OpenOptions::new().append(true).open(&journal_path).unwrap();
+        journal_file.write_all(b"{\"sequence\":2,\"recor").unwrap();
+
+        assert_eq!(backend.load().unwrap(), stored_app);
+        let next_app = backend.update(&|app| Ok(app.append_default_action())).unwrap();
+        assert_eq!(backend.read_entries().unwrap().len(), 2);
+        assert_eq!(backend.load().unwrap(), next_app);
+    }
+
+    #[test]
+    fn failed_save_after_concurrent_append() {
+        let journal_path = create_temporary_directory("journal_conflict").join("app.journal");
+        let mut backend = JournalBackend::new(&journal_path);
+        let stale_app = backend.load().unwrap();
+        backend.update(&|app| Ok(app.append_default_action())).unwrap();
+
+        let conflict_error = stale_app.append_default_action().save_to(&mut backend).unwrap_err();
+
+        assert_eq!(
+            conflict_error.to_string(),
+            "Data file changed while this command ran (expected revision 0, found 1), please retry"
+        );
+    }
+}
@@ -16,3 +16,41 @@ pub mod relationship_implementation;
 
 pub mod extended_action;
 
+
+pub mod critical_path;
+pub use critical_path::*;
+
+pub mod formats;
+pub use formats::*;
+
+pub mod subtree;
+
+pub mod constraints;
+pub use constraints::*;
+
+pub mod rollup;
+pub use rollup::*;
+
+pub mod csv_storage;
+pub use csv_storage::*;
+
+pub mod lock;
+pub use lock::*;
+
+pub mod migration;
+pub use migration::*;
+
+pub mod sqlite_storage;
+pub use sqlite_storage::*;
+
+pub mod backend;
+pub use backend::*;
+
+pub mod journal;
+pub use journal::*;
+
+pub mod history;
+pub use history::*;
+
+pub mod diff;
+pub use diff::*;
@@ -0,0 +1,153 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::fs::{self, File, OpenOptions, TryLockError};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, Instant};
+
+pub const DEFAULT_LOCK_TIMEOUT: Duration = Duration::from_secs(5);
+const LOCK_RETRY_INTERVAL: Duration = Duration::from_millis(50);
+
+#[derive(Debug, PartialEq)]
+pub enum LockError {
+    Timeout { lock_path: PathBuf, holder: String },
+}
+
+impl Display for LockError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            LockError::Timeout { lock_path, holder } => write!(
+                f,
+                "{} is held by {}; wait for it to finish and retry",
+                lock_path.display(),
+                holder
+            ),
+        }
+    }
+}
+
+impl Error for LockError {}
+
+#[derive(Debug)]
+pub struct FileLock {
+    lock_path: PathBuf,
+    lock_file: File,
+}
+
+impl FileLock {
+    pub fn acquire(file_path: &Path, timeout: Duration) -> Result<FileLock, Box<dyn Error>> {
+        let lock_path = get_lock_path(file_path);
+        let mut lock_file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(&lock_path)?;
+        let started = Instant::now();
+
+        loop {
+            match lock_file.try_lock() {
+                Ok(()) => {
+                    lock_file.set_len(0)?;
+                    writeln!(lock_file, "{}", std::process::id())?;
+                    return Ok(FileLock { lock_path, lock_file });
+                }
+                Err(TryLockError::WouldBlock) => {
+                    if started.elapsed() >= timeout {
+                        let holder = match fs::read_to_string(&lock_path) {
+                            Ok(contents) if !contents.trim().is_empty() => {
+                                format!("process {}", contents.trim())
+                            }
+                            _ => "another process".to_string(),
+                        };
+                        return Err(LockError::Timeout { lock_path, holder }.into());
+                    }
+                    thread::sleep(LOCK_RETRY_INTERVAL.min(timeout.saturating_sub(started.elapsed())));
+                }
+                Err(TryLockError::Error(error)) => return Err(error.into()),
+            }
+        }
+    }
+
+    pub fn get_lock_path(&self) -> &Path {
+        &self.lock_path
+    }
+}
+
+// removing the lock file could let a waiting process lock the unlinked one
+impl Drop for FileLock {
+    fn drop(&mut self) {
+        let _ = self.lock_file.set_len(0);
+        let _ = self.lock_file.unlock();
+    }
+}
+
+pub fn get_lock_path(file_path: &Path) -> PathBuf {
+    let mut lock_name = file_path.file_name().unwrap_or_default().to_os_string();
+    lock_name.push(".lock");
+
+    file_path.with_file_name(lock_name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::functionality::tests::create_temporary_directory;
+
+    #[test]
+    fn release_lock_on_drop() {
+        let file_path = create_temporary_directory("release_lock").join("app.json");
+
+        let file_lock = FileLock::acquire(&file_path, Duration::ZERO).unwrap();
+        assert!(file_lock.get_lock_path().exists());
+        drop(file_lock);
+
+        assert_eq!(fs::read_to_string(get_lock_path(&file_path)).unwrap(), "");
+        assert!(FileLock::acquire(&file_path, Duration::ZERO).is_ok());
+    }
+
+    #[test]
+    fn acquire_lock_left_by_exited_process() {
+        let file_path = create_temporary_directory("stale_lock").join("app.json");
+        fs::write(get_lock_path(&file_path), "4194304\n").unwrap();
+
+        let file_lock = FileLock::acquire(&file_path, Duration::ZERO).unwrap();
+
+        assert_eq!(
+            fs::read_to_string(file_lock.get_lock_path()).unwrap(),
+            format!("{}\n", std::process::id())
+        );
+    }
+
+    #[test]
+    fn failed_acquire_held_lock() {
+        let file_path = create_temporary_directory("held_lock").join("app.json");
+        let _file_lock = FileLock::acquire(&file_path, Duration::ZERO).unwrap();
+
+        let lock_error = FileLock::acquire(&file_path, Duration::from_millis(100)).unwrap_err();
+
+        assert_eq!(
+            lock_error.to_string(),
+            format!(
+                "{} is held by process {}; wait for it to finish and retry",
+                get_lock_path(&file_path).display(),
+                std::process::id()
+            )
+        );
+    }
+
+    #[test]
+    fn wait_for_released_lock() {
+        let file_path = create_temporary_directory("wait_for_lock").join("app.json");
+        let file_lock = FileLock::acquire(&file_path, Duration::ZERO).unwrap();
+
+        let releasing_thread = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(100));
+            drop(file_lock);
+        });
+
+        assert!(FileLock::acquire(&file_path, Duration::from_secs(5)).is_ok());
+        releasing_thread.join().unwrap();
+    }
+}
@@ -0,0 +1,268 @@
+use crate::functionality::ClearHeadApp;
+
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+pub const CURRENT_FORMAT_VERSION: u64 = 2;
+const FORMAT_VERSION_KEY: &str = "format_version";
+
+type Migration = fn(Map<String, Value>) -> Result<Map<String, Value>, Box<dyn Error>>;
+
+// MIGRATIONS[n] upgrades a version n + 1 document to version n + 2
+const MIGRATIONS: [Migration; (CURRENT_FORMAT_VERSION - 1) as usize] = [migrate_version_1_to_2];
+
+#[derive(Debug, PartialEq)]
+pub enum MigrationError {
+    NotAnObject,
+    InvalidVersion(Value),
+    UnsupportedVersion(u64),
+}
+
+impl Display for MigrationError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            MigrationError::NotAnObject => write!(f, "Data file does not contain a ClearHead document"),
+            MigrationError::InvalidVersion(version) => {
+                write!(f, "{} is an Invalid Format Version", version)
+            }
+            MigrationError::UnsupportedVersion(version) => write!(
+                f,
+                "Data file uses format version {}, but this build only supports up to version {}",
+                version, CURRENT_FORMAT_VERSION
+            ),
+        }
+    }
+}
+
+impl Error for MigrationError {}
+
+#[derive(Serialize)]
+pub(crate) struct VersionedDocument<'a> {
+    format_version: u64,
+    #[serde(flatten)]
+    app: &'a ClearHeadApp,
+}
+
+impl<'a> VersionedDocument<'a> {
+    pub(crate) fn new(app: &'a ClearHeadApp) -> Self {
+        VersionedDocument {
+            format_version: CURRENT_FORMAT_VERSION,
+            app,
+        }
+    }
+}
+
+pub fn get_format_version(document: &Value) -> Result<u64, MigrationError> {
+    match document
+        .as_object()
+        .ok_or(MigrationError::NotAnObject)?
+        .get(FORMAT_VERSION_KEY)
+    {
+        None => Ok(1),
+        Some(version) => match version.as_u64() {
+            Some(version) if version > 0 => Ok(version),
+            _ => Err(MigrationError::InvalidVersion(version.clone())),
+        },
+    }
+}
+
+pub fn migrate_document(document: Value) -> Result<Value, Box<dyn Error>> {
+    let version = get_format_version(&document)?;
+    if version > CURRENT_FORMAT_VERSION {
+        return Err(MigrationError::UnsupportedVersion(version).into());
+    }
+
+    let mut migrated_document = match document {
+        Value::Object(map) => map,
+        _ => return Err(MigrationError::NotAnObject.into()),
+    };
+    for migration in &MIGRATIONS[(version - 1) as usize..] {
+        migrated_document = migration(migrated_document)?;
+    }
+
+    Ok(Value::Object(migrated_document))
+}
+
+pub fn load_document(document: Value) -> Result<ClearHeadApp, Box<dyn Error>> {
+    let mut migrated_document = migrate_document(document)?;
+    if let Some(map) = migrated_document.as_object_mut() {
+        map.remove(FORMAT_VERSION_KEY);
+    }
+
+    Ok(serde_json::from_value(migrated_document)?)
+}
+
+fn get_canonical_direction(variant_name: &str) -> Option<&'static str> {
+    match variant_name {
+        "Parental" | "Sequential" => Some("Directed"),
+        "Related" => Some("Undirected"),
+        _ => None,
+    }
+}
+
+fn migrate_version_1_to_2(mut document: Map<String, Value>) -> Result<Map<String, Value>, Box<dyn Error>> {
+    for list_key in ["action_list", "relationship_list"] {
+        match document.get(list_key) {
+            None => {
+                document.insert(list_key.to_string(), Value::Array(Vec::new()));
+            }
+            Some(Value::Array(_)) => (),
+            Some(_) => return Err(format!("{} must be a list", list_key).into()),
+        }
+    }
+    if let Some(Value::Array(relationships)) = document.get_mut("relationship_list") {
+        for (position, relationship) in relationships.iter_mut().enumerate() {
+            let variant = relationship
+                .get_mut("variant")
+                .and_then(Value::as_object_mut)
+                .ok_or_else(|| format!("Relationship {} has no variant", position + 1))?;
+            for (variant_name, direction) in variant.iter_mut() {
+                if let Some(canonical_direction) = get_canonical_direction(variant_name) {
+                    *direction = Value::from(canonical_direction);
+                }
+            }
+        }
+    }
+    document.insert(FORMAT_VERSION_KEY.to_string(), Value::from(2));
+
+    Ok(document)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{JSONStorage, RelationshipListManagement, RelationshipVariant};
+
+    use std::path::Path;
+
+    use uuid::Uuid;
+
+    #[test]
+    fn unversioned_documents_are_version_1() {
+        let document = serde_json::json!({"action_list": [], "relationship_list": []});
+
+        assert_eq!(get_format_version(&document).unwrap(), 1);
+    }
+
+    #[test]
+    fn load_version_1_fixture() {
+        let app = ClearHeadApp::read_from_json(Path::new("data/migrations/version_1.json")).unwrap();
+
+        assert_eq!(app.action_list.len(), 2);
+        assert_eq!(app.get_action_name(0).unwrap(), "Paint Fence");
+        assert_eq!(app.get_action_priority(1).unwrap(), "High");
+        assert_eq!(app.get_action_estimate(1).unwrap(), None);
+        assert_eq!(app.get_relationship_variant(0).unwrap(), RelationshipVariant::create_parental());
+        assert_eq!(app.get_relationship_variant(1).unwrap(), RelationshipVariant::create_related());
+        assert_eq!(app.get_relationship_variant(2).unwrap(), RelationshipVariant::create_sequential());
+        for index in 0..3 {
+            assert_eq!(app.get_relationship_label(index).unwrap(), None);
+            assert_eq!(app.get_relationship_weight(index).unwrap(), None);
+            assert_eq!(app.get_relationship_created(index).unwrap(), None);
+        }
+        assert_eq!(
+            app.get_children_for_id(app.get_action_id(0).unwrap()).unwrap(),
+            im::vector![app.get_action_id(1).unwrap()]
+        );
+    }
+
+    #[test]
+    fn load_version_2_fixture() {
+        let app = ClearHeadApp::read_from_json(Path::new("data/migrations/version_2.json")).unwrap();
+
+        assert_eq!(app.action_list.len(), 2);
+        assert_eq!(app.get_action_estimate(1).unwrap(), Some(3));
+        assert_eq!(app.get_relationship_label(0).unwrap(), Some("first coat".to_string()));
+        assert_eq!(app.revision, 4);
+    }
+
+    #[test]
+    fn migrate_version_1_step_by_step() {
+        let document = serde_json::json!({"action_list": []});
+
+        let migrated_document = migrate_document(document).unwrap();
+
+        assert_eq!(
+            migrated_document,
+            serde_json::json!({"action_list": [], "relationship_list": [], "format_version": 2})
+        );
+    }
+
+    #[test]
+    fn migrate_version_1_directions() {
+        let relationship = |variant| {
+            serde_json::json!({
+                "variant": variant,
+                "participant_1": Uuid::nil(),
+                "participant_2": Uuid::nil(),
+                "id": Uuid::nil()
+            })
+        };
+        let document = serde_json::json!({
+            "relationship_list": [
+                relationship(serde_json::json!({"Related": "Directed"})),
+                relationship(serde_json::json!({"Parental": "Undirected"}))
+            ]
+        });
+
+        let migrated_document = migrate_document(document).unwrap();
+
+        assert_eq!(
+            migrated_document["relationship_list"],
+            serde_json::json!([
+                relationship(serde_json::json!({"Related": "Undirected"})),
+                relationship(serde_json::json!({"Parental": "Directed"}))
+            ])
+        );
+    }
+
+    #[test]
+    fn failed_migrate_relationship_without_variant() {
+        let document = serde_json::json!({"relationship_list": [{"participant_1": Uuid::nil()}]});
+
+        let migration_error = migrate_document(document).unwrap_err();
+
+        assert_eq!(migration_error.to_string(), "Relationship 1 has no variant");
+    }
+
+    #[test]
+    fn failed_load_newer_version() {
+        let document = serde_json::json!({"format_version": 99, "action_list": []});
+
+        let version_error = load_document(document).unwrap_err();
+
+        assert_eq!(
+            version_error.to_string(),
+            "Data file uses format version 99, but this build only supports up to version 2"
+        );
+    }
+
+    #[test]
+    fn failed_load_invalid_version() {
+        let document = serde_json::json!({"format_version": "two"});
+
+        let version_error = load_document(document).unwrap_err();
+
+        assert_eq!(version_error.to_string(), "\"two\" is an Invalid Format Version");
+    }
+
+    #[test]
+    fn failed_load_non_object_document() {
+        let version_error = load_document(serde_json::json!([])).unwrap_err();
+
+        assert_eq!(version_error.to_string(), "Data file does not contain a ClearHead document");
+    }
+
+    #[test]
+    fn write_current_version() {
+        let app = ClearHeadApp::default().append_default_action();
+
+        let document = serde_json::to_value(VersionedDocument::new(&app)).unwrap();
+
+        assert_eq!(get_format_version(&document).unwrap(), CURRENT_FORMAT_VERSION);
+        assert_eq!(load_document(document).unwrap(), app);
+    }
+}
@@ -0,0 +1,290 @@
+use crate::relationship::{Relationship, RelationshipVariant};
+
+use std::collections::HashMap;
+use std::fmt::{self, Formatter};
+use std::sync::{Arc, Mutex, PoisonError};
+
+use im::Vector;
+use uuid::Uuid;
+
+type AdjacencyMap = HashMap<Uuid, HashMap<RelationshipVariant, Vec<usize>>>;
+
+#[derive(Debug, Default)]
+struct Adjacency {
+    outgoing: AdjacencyMap,
+    incoming: AdjacencyMap,
+}
+
+impl Adjacency {
+    fn new(relationships: &Vector<Relationship>) -> Self {
+        let mut adjacency = Adjacency::default();
+
+        for (position, relationship) in relationships.iter().enumerate() {
+            adjacency
+                .outgoing
+                .entry(relationship.get_participant_1())
+                .or_default()
+                .entry(relationship.get_variant())
+                .or_default()
+                .push(position);
+            adjacency
+                .incoming
+                .entry(relationship.get_participant_2())
+                .or_default()
+                .entry(relationship.get_variant())
+                .or_default()
+                .push(position);
+        }
+
+        adjacency
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RelationshipIndex<'a> {
+    relationships: &'a Vector<Relationship>,
+    adjacency: Arc<Adjacency>,
+}
+
+impl<'a> RelationshipIndex<'a> {
+    pub fn new(relationships: &'a Vector<Relationship>) -> Self {
+        RelationshipIndex {
+            relationships,
+            adjacency: Arc::new(Adjacency::new(relationships)),
+        }
+    }
+
+    pub fn get_outgoing(&self, id: Uuid, variant: &RelationshipVariant) -> Vec<&'a Relationship> {
+        self.select_by_variant(&self.adjacency.outgoing, id, variant)
+    }
+
+    pub fn get_incoming(&self, id: Uuid, variant: &RelationshipVariant) -> Vec<&'a Relationship> {
+        self.select_by_variant(&self.adjacency.incoming, id, variant)
+    }
+
+    pub fn get_all_outgoing(&self, id: Uuid) -> Vec<&'a Relationship> {
+        self.select_all(&[&self.adjacency.outgoing], id)
+    }
+
+    pub fn get_all_incoming(&self, id: Uuid) -> Vec<&'a Relationship> {
+        self.select_all(&[&self.adjacency.incoming], id)
+    }
+
+    pub fn get_all_connected(&self, id: Uuid) -> Vec<&'a Relationship> {
+        self.select_all(&[&self.adjacency.outgoing, &self.adjacency.incoming], id)
+    }
+
+    pub fn get_outgoing_ids(&self, id: Uuid, variant: &RelationshipVariant) -> Vec<Uuid> {
+        self.get_outgoing(id, variant)
+            .iter()
+            .map(|relationship| relationship.get_participant_2())
+            .collect()
+    }
+
+    pub fn get_incoming_ids(&self, id: Uuid, variant: &RelationshipVariant) -> Vec<Uuid> {
+        self.get_incoming(id, variant)
+            .iter()
+            .map(|relationship| relationship.get_participant_1())
+            .collect()
+    }
+
+    pub fn get_degree(&self, id: Uuid) -> usize {
+        let count_edges = |map: &AdjacencyMap| {
+            map.get(&id)
+                .map(|variants| variants.values().map(|positions| positions.len()).sum())
+                .unwrap_or(0)
+        };
+
+        count_edges(&self.adjacency.outgoing) + count_edges(&self.adjacency.incoming)
+    }
+
+    fn select_by_variant(
+        &self,
+        map: &AdjacencyMap,
+        id: Uuid,
+        variant: &RelationshipVariant,
+    ) -> Vec<&'a Relationship> {
+        map.get(&id)
+            .and_then(|variants| variants.get(variant))
+            .map(|positions| positions.iter().map(|position| &self.relationships[*position]).collect())
+            .unwrap_or_default()
+    }
+
+    fn select_all(&self, maps: &[&AdjacencyMap], id: Uuid) -> Vec<&'a Relationship> {
+        let mut positions: Vec<usize> = maps
+            .iter()
+            .filter_map(|map| map.get(&id))
+            .flat_map(|variants| variants.values().flatten().copied())
+            .collect();
+        positions.sort_unstable();
+        // a relationship from an id to itself is both outgoing and incoming
+        positions.dedup();
+
+        positions
+            .iter()
+            .map(|position| &self.relationships[*position])
+            .collect()
+    }
+}
+
+// a list that still ptr_eq's the indexed one has not changed since it was indexed
+#[derive(Default)]
+pub struct RelationshipIndexCache {
+    cached: Mutex<Option<(Vector<Relationship>, Arc<Adjacency>)>>,
+}
+
+impl RelationshipIndexCache {
+    pub fn get_index<'a>(&self, relationships: &'a Vector<Relationship>) -> RelationshipIndex<'a> {
+        let mut cached = self.cached.lock().unwrap_or_else(PoisonError::into_inner);
+        let adjacency = match cached.as_ref() {
+            Some((indexed_relationships, adjacency)) if indexed_relationships.ptr_eq(relationships) => {
+                adjacency.clone()
+            }
+            _ => {
+                let adjacency = Arc::new(Adjacency::new(relationships));
+                *cached = Some((relationships.clone(), adjacency.clone()));
+                adjacency
+            }
+        };
+
+        RelationshipIndex {
+            relationships,
+            adjacency,
+        }
+    }
+
+    #[cfg(test)]
+    fn is_cached(&self, relationships: &Vector<Relationship>) -> bool {
+        self.cached
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .as_ref()
+            .is_some_and(|(indexed_relationships, _)| indexed_relationships.ptr_eq(relationships))
+    }
+}
+
+impl Clone for RelationshipIndexCache {
+    fn clone(&self) -> Self {
+        RelationshipIndexCache {
+            cached: Mutex::new(self.cached.lock().unwrap_or_else(PoisonError::into_inner).clone()),
+        }
+    }
+}
+
+impl fmt::Debug for RelationshipIndexCache {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("RelationshipIndexCache")
+    }
+}
+
+// the cache never changes what an app holds
+impl PartialEq for RelationshipIndexCache {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RelationshipListManagement;
+
+    fn create_indexed_relationships() -> (Vector<Uuid>, Vector<Relationship>) {
+        let ids: Vector<Uuid> = (0..3).map(|_| Uuid::new_v4()).collect();
+        let relationships = Vector::new()
+            .append_parental_relationship(ids[0], ids[1])
+            .unwrap()
+            .append_sequential_relationship(ids[1], ids[2])
+            .unwrap()
+            .append_parental_relationship(ids[0], ids[2])
+            .unwrap()
+            .append_related_relationship(ids[2], ids[0])
+            .unwrap();
+
+        (ids, relationships)
+    }
+
+    #[test]
+    fn lookup_outgoing_by_variant() {
+        let (ids, relationships) = create_indexed_relationships();
+        let index = RelationshipIndex::new(&relationships);
+
+        let children = index.get_outgoing_ids(ids[0], &RelationshipVariant::create_parental());
+
+        assert_eq!(children, vec![ids[1], ids[2]]);
+    }
+
+    #[test]
+    fn lookup_incoming_by_variant() {
+        let (ids, relationships) = create_indexed_relationships();
+        let index = RelationshipIndex::new(&relationships);
+
+        let predecessors = index.get_incoming_ids(ids[2], &RelationshipVariant::create_sequential());
+
+        assert_eq!(predecessors, vec![ids[1]]);
+    }
+
+    #[test]
+    fn lookup_all_variants_in_list_order() {
+        let (ids, relationships) = create_indexed_relationships();
+        let index = RelationshipIndex::new(&relationships);
+
+        let incoming = index.get_all_incoming(ids[2]);
+
+        assert_eq!(incoming, vec![&relationships[1], &relationships[2]]);
+        assert_eq!(index.get_all_outgoing(ids[2]), vec![&relationships[3]]);
+    }
+
+    #[test]
+    fn unknown_id_has_no_relationships() {
+        let (_, relationships) = create_indexed_relationships();
+        let index = RelationshipIndex::new(&relationships);
+
+        assert!(index.get_all_outgoing(Uuid::nil()).is_empty());
+        assert!(index
+            .get_incoming(Uuid::nil(), &RelationshipVariant::create_related())
+            .is_empty());
+        assert_eq!(index.get_degree(Uuid::nil()), 0);
+    }
+
+    #[test]
+    fn lookup_connected_once_per_relationship() {
+        let (ids, mut relationships) = create_indexed_relationships();
+        relationships.push_back(Relationship::create_new_related(ids[1], ids[1]));
+        let index = RelationshipIndex::new(&relationships);
+
+        assert_eq!(
+            index.get_all_connected(ids[1]),
+            vec![&relationships[0], &relationships[1], &relationships[4]]
+        );
+    }
+
+    #[test]
+    fn reuse_cached_index_until_list_changes() {
+        let (ids, relationships) = create_indexed_relationships();
+        let cache = RelationshipIndexCache::default();
+        let cloned_relationships = relationships.clone();
+
+        cache.get_index(&relationships);
+        assert!(cache.is_cached(&cloned_relationships));
+
+        let updated_relationships = relationships.append_parental_relationship(ids[1], ids[2]).unwrap();
+        assert!(!cache.is_cached(&updated_relationships));
+        assert_eq!(
+            cache
+                .get_index(&updated_relationships)
+                .get_outgoing_ids(ids[1], &RelationshipVariant::create_parental()),
+            vec![ids[2]]
+        );
+        assert!(cache.is_cached(&updated_relationships));
+    }
+
+    #[test]
+    fn count_degree() {
+        let (ids, relationships) = create_indexed_relationships();
+        let index = RelationshipIndex::new(&relationships);
+
+        assert_eq!(index.get_degree(ids[0]), 3);
+        assert_eq!(index.get_degree(ids[2]), 3);
+    }
+}
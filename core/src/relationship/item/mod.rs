@@ -2,9 +2,11 @@ pub mod variants;
 pub use variants::*;
 
 use uuid::Uuid;
+use chrono::{DateTime, Utc};
 use serde::Deserialize;
 use serde::Serialize;
 use std::error::Error;
+use std::fmt::Display;
 use std::str::FromStr;
 use tabled::Tabled;
 
@@ -18,6 +20,30 @@ pub struct Relationship {
     participant_2: Uuid,
     #[tabled(skip)]
     id: Uuid,
+
+    #[tabled(rename = "Label", display_with = "display_optional")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    label: Option<String>,
+    #[tabled(rename = "Weight", display_with = "display_optional")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    weight: Option<f64>,
+    #[tabled(rename = "Created", display_with = "display_timestamp")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    created: Option<DateTime<Utc>>,
+}
+
+fn display_optional<T: Display>(value: &Option<T>) -> String {
+    match value {
+        Some(value) => value.to_string(),
+        None => String::new(),
+    }
+}
+
+fn display_timestamp(timestamp: &Option<DateTime<Utc>>) -> String {
+    match timestamp {
+        Some(timestamp) => timestamp.format("%Y-%m-%d %H:%M:%S").to_string(),
+        None => String::new(),
+    }
 }
 
 impl Relationship {
@@ -34,8 +60,29 @@ impl Relationship {
             variant,
             participant_1,
             participant_2,
+            label: None,
+            weight: None,
+            created: Some(Utc::now()),
         })
     }
+    pub fn create_new_with_variant(
+        variant: RelationshipVariant,
+        participant_1: Uuid,
+        participant_2: Uuid,
+    ) -> Self {
+        let id = Uuid::new_v4();
+
+        Relationship {
+            id,
+            variant,
+            participant_1,
+            participant_2,
+            label: None,
+            weight: None,
+            created: Some(Utc::now()),
+        }
+    }
+
     pub fn create_new_related(participant_1: Uuid, participant_2: Uuid) -> Self {
         let id = Uuid::new_v4();
         let variant = RelationshipVariant::create_related();
@@ -45,6 +92,9 @@ impl Relationship {
             variant,
             participant_1,
             participant_2,
+            label: None,
+            weight: None,
+            created: Some(Utc::now()),
         }
     }
 
@@ -57,6 +107,9 @@ impl Relationship {
             variant,
             participant_1,
             participant_2,
+            label: None,
+            weight: None,
+            created: Some(Utc::now()),
         }
     }
 
@@ -69,6 +122,19 @@ impl Relationship {
             variant,
             participant_1,
             participant_2,
+            label: None,
+            weight: None,
+            created: Some(Utc::now()),
+        }
+    }
+
+    pub fn create_copy(&self, participant_1: Uuid, participant_2: Uuid) -> Self {
+        Relationship {
+            id: Uuid::new_v4(),
+            participant_1,
+            participant_2,
+            created: Some(Utc::now()),
+            ..self.clone()
         }
     }
 
@@ -77,7 +143,7 @@ impl Relationship {
     }
 
     pub fn get_variant(&self) -> RelationshipVariant {
-        return self.variant;
+        return self.variant.clone();
     }
 
     pub fn get_edge_direction(&self) -> String {
@@ -92,6 +158,18 @@ impl Relationship {
         return self.participant_2;
     }
 
+    pub fn get_label(&self) -> Option<String> {
+        self.label.clone()
+    }
+
+    pub fn get_weight(&self) -> Option<f64> {
+        self.weight
+    }
+
+    pub fn get_created(&self) -> Option<DateTime<Utc>> {
+        self.created
+    }
+
     pub fn set_variant(&self, target_variant: &str) -> Result<Relationship, String> {
         let variant = RelationshipVariant::from_str(target_variant)?;
         let mut cloned_relationship = self.clone();
@@ -101,6 +179,14 @@ impl Relationship {
         Ok(cloned_relationship)
     }
 
+    pub fn set_variant_to(&self, variant: RelationshipVariant) -> Relationship {
+        let mut cloned_relationship = self.clone();
+
+        cloned_relationship.variant = variant;
+
+        cloned_relationship
+    }
+
     pub fn set_participant_1(&self, new_id: Uuid) -> Relationship {
         let mut cloned_relationship = self.clone();
 
@@ -116,6 +202,43 @@ impl Relationship {
 
         return cloned_relationship;
     }
+
+    pub fn set_label(&self, label: Option<&str>) -> Relationship {
+        let mut cloned_relationship = self.clone();
+
+        cloned_relationship.label = label.map(|label| label.to_owned());
+
+        cloned_relationship
+    }
+
+    pub fn set_weight(&self, weight: Option<f64>) -> Result<Relationship, String> {
+        if let Some(weight) = weight {
+            if !weight.is_finite() {
+                return Err(format!("{} is an Invalid Relationship Weight", weight));
+            }
+        }
+        let mut cloned_relationship = self.clone();
+
+        cloned_relationship.weight = weight;
+
+        Ok(cloned_relationship)
+    }
+
+    pub fn set_created(&self, created: Option<DateTime<Utc>>) -> Relationship {
+        let mut cloned_relationship = self.clone();
+
+        cloned_relationship.created = created;
+
+        cloned_relationship
+    }
+
+    pub(crate) fn set_id(&self, id: Uuid) -> Relationship {
+        let mut cloned_relationship = self.clone();
+
+        cloned_relationship.id = id;
+
+        cloned_relationship
+    }
 }
 
 #[cfg(test)]
@@ -130,6 +253,9 @@ pub mod tests {
             variant,
             participant_1: Uuid::nil(),
             participant_2: Uuid::nil(),
+            label: None,
+            weight: None,
+            created: None,
         }
     }
 
@@ -142,6 +268,9 @@ pub mod tests {
             variant: RelationshipVariant::create_related(),
             participant_1: Uuid::nil(),
             participant_2: Uuid::nil(),
+            label: None,
+            weight: None,
+            created: None,
         };
     }
 
@@ -286,6 +415,39 @@ pub mod tests {
         assert!(updated_relationship.get_variant() == RelationshipVariant::create_parental())
     }
 
+    #[test]
+    fn create_custom_with_variant() {
+        let custom_variant = RelationshipVariant::create_custom("blocks", EdgeDirectionality::Directed);
+
+        let custom_relationship =
+            Relationship::create_new_with_variant(custom_variant.clone(), Uuid::nil(), Uuid::nil());
+
+        assert_eq!(custom_relationship.get_variant(), custom_variant);
+    }
+
+    #[test]
+    fn change_to_custom_variant() {
+        let test_relationship = create_related_with_double_nil();
+        let custom_variant = RelationshipVariant::create_custom("blocks", EdgeDirectionality::Directed);
+
+        let updated_relationship = test_relationship.set_variant_to(custom_variant.clone());
+
+        assert_eq!(updated_relationship.get_variant(), custom_variant);
+        assert_eq!(updated_relationship.get_id(), test_relationship.get_id());
+    }
+
+    #[test]
+    fn copy_between_new_participants() {
+        let test_relationship = create_related_with_double_nil().set_label(Some("shared budget"));
+        let new_uuid = Uuid::new_v4();
+
+        let copied_relationship = test_relationship.create_copy(new_uuid, Uuid::nil());
+
+        assert_ne!(copied_relationship.get_id(), test_relationship.get_id());
+        assert_eq!(copied_relationship.get_participant_1(), new_uuid);
+        assert_eq!(copied_relationship.get_label(), Some("shared budget".to_string()));
+    }
+
     #[test]
     fn set_participant_1() {
         let test_relationship = create_related_with_double_nil();
@@ -306,6 +468,98 @@ pub mod tests {
         assert_eq!(updated_relationship.participant_2 , new_uuid)
     }
 
+    #[test]
+    fn new_relationship_records_creation_time() {
+        let before_creation = Utc::now();
+
+        let test_relationship = create_related_with_double_nil();
+
+        assert!(test_relationship.get_created().unwrap() >= before_creation);
+    }
+
+    #[test]
+    fn set_label() {
+        let test_relationship = create_related_with_double_nil();
+
+        let updated_relationship = test_relationship.set_label(Some("needs review"));
+
+        assert_eq!(updated_relationship.get_label(), Some("needs review".to_string()));
+    }
+
+    #[test]
+    fn set_weight() {
+        let test_relationship = create_related_with_double_nil();
+
+        let updated_relationship = test_relationship.set_weight(Some(0.5)).unwrap();
+
+        assert_eq!(updated_relationship.get_weight(), Some(0.5));
+    }
+
+    #[test]
+    fn failed_set_non_finite_weight() {
+        let test_relationship = create_related_with_double_nil();
+
+        let weight_error = test_relationship.set_weight(Some(f64::NAN)).unwrap_err();
+
+        assert_eq!(weight_error, "NaN is an Invalid Relationship Weight");
+    }
+
+    #[test]
+    fn set_created() {
+        let test_relationship = create_nil_relationship();
+        let timestamp = Utc::now();
+
+        let updated_relationship = test_relationship.set_created(Some(timestamp));
+
+        assert_eq!(updated_relationship.get_created(), Some(timestamp));
+    }
+
+    #[test]
+    fn serialize_relationship_with_metadata() {
+        let example_relationship = create_nil_relationship()
+            .set_label(Some("needs review"))
+            .set_weight(Some(2.5))
+            .unwrap()
+            .set_created(Some(
+                DateTime::parse_from_rfc3339("2022-08-01T12:00:00Z").unwrap().with_timezone(&Utc),
+            ));
+
+        assert_tokens(
+            &example_relationship.readable(),
+            &[
+                Token::Struct {
+                    name: "Relationship",
+                    len: 7,
+                },
+                Token::Str("variant"),
+                Token::NewtypeVariant {
+                    name: "RelationshipVariant",
+                    variant: "Related",
+                },
+                Token::UnitVariant {
+                    name: "EdgeDirectionality",
+                    variant: "Undirected",
+                },
+                Token::Str("participant_1"),
+                Token::Str("00000000-0000-0000-0000-000000000000"),
+                Token::Str("participant_2"),
+                Token::Str("00000000-0000-0000-0000-000000000000"),
+                Token::Str("id"),
+                Token::Str("00000000-0000-0000-0000-000000000000"),
+                Token::Str("label"),
+                Token::Some,
+                Token::Str("needs review"),
+                Token::Str("weight"),
+                Token::Some,
+                Token::F64(2.5),
+                Token::Str("created"),
+                Token::Some,
+                Token::Str("2022-08-01T12:00:00Z"),
+                Token::StructEnd,
+            ],
+        )
+    }
+
     #[test]
     fn serialization_and_deserialization() {
         let example_relationship = create_nil_relationship();
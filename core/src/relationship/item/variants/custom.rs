@@ -0,0 +1,155 @@
+use serde::Deserialize;
+use serde::Serialize;
+use std::fmt::{self, Display, Formatter};
+
+use im::Vector;
+
+use crate::relationship::item::variants::{EdgeDirectionality, RelationshipVariant};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct CustomVariant {
+    name: String,
+    directionality: EdgeDirectionality,
+    inverse_label: String,
+    #[serde(default)]
+    aliases: Vector<String>,
+}
+
+impl CustomVariant {
+    pub fn create_new(
+        name: &str,
+        directionality: EdgeDirectionality,
+        inverse_label: &str,
+        aliases: Vector<String>,
+    ) -> Self {
+        CustomVariant {
+            name: name.trim().to_owned(),
+            directionality,
+            inverse_label: inverse_label.trim().to_owned(),
+            aliases: aliases.iter().map(|alias| alias.trim().to_owned()).collect(),
+        }
+    }
+
+    pub fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn get_directionality(&self) -> EdgeDirectionality {
+        self.directionality
+    }
+
+    pub fn get_inverse_label(&self) -> String {
+        self.inverse_label.clone()
+    }
+
+    pub fn get_aliases(&self) -> Vector<String> {
+        self.aliases.clone()
+    }
+
+    pub fn get_variant(&self) -> RelationshipVariant {
+        RelationshipVariant::create_custom(&self.name, self.directionality)
+    }
+
+    pub fn matches(&self, s: &str) -> bool {
+        let target = s.trim();
+
+        self.name.eq_ignore_ascii_case(target)
+            || self.aliases.iter().any(|alias| alias.eq_ignore_ascii_case(target))
+    }
+}
+
+impl Display for CustomVariant {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} ({}), inverse: {}, aliases: [{}]",
+            self.name,
+            self.directionality,
+            self.inverse_label,
+            self.aliases.iter().cloned().collect::<Vec<String>>().join(", ")
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_test::{assert_tokens, Token};
+
+    fn create_blocks_variant() -> CustomVariant {
+        CustomVariant::create_new(
+            "blocks",
+            EdgeDirectionality::Directed,
+            "blocked by",
+            im::vector!["blocker".to_string()],
+        )
+    }
+
+    #[test]
+    fn create_custom_variant() {
+        let custom_variant = create_blocks_variant();
+
+        assert_eq!(custom_variant.get_name(), "blocks");
+        assert_eq!(custom_variant.get_directionality(), EdgeDirectionality::Directed);
+        assert_eq!(custom_variant.get_inverse_label(), "blocked by");
+        assert_eq!(custom_variant.get_aliases(), im::vector!["blocker".to_string()]);
+    }
+
+    #[test]
+    fn match_name_and_aliases_ignoring_case() {
+        let custom_variant = create_blocks_variant();
+
+        assert!(custom_variant.matches("Blocks"));
+        assert!(custom_variant.matches("BLOCKER"));
+        assert!(!custom_variant.matches("blocked by"));
+    }
+
+    #[test]
+    fn convert_to_relationship_variant() {
+        let custom_variant = create_blocks_variant();
+
+        assert_eq!(
+            custom_variant.get_variant(),
+            RelationshipVariant::create_custom("blocks", EdgeDirectionality::Directed)
+        );
+    }
+
+    #[test]
+    fn print_custom_variant() {
+        let custom_variant = create_blocks_variant();
+
+        assert_eq!(
+            custom_variant.to_string(),
+            "blocks (Directed), inverse: blocked by, aliases: [blocker]"
+        );
+    }
+
+    #[test]
+    fn serialization_and_deserialization() {
+        let custom_variant = create_blocks_variant();
+
+        assert_tokens(
+            &custom_variant,
+            &[
+                Token::Struct {
+                    name: "CustomVariant",
+                    len: 4,
+                },
+                Token::Str("name"),
+                Token::Str("blocks"),
+                Token::Str("directionality"),
+                Token::UnitVariant {
+                    name: "EdgeDirectionality",
+                    variant: "Directed",
+                },
+                Token::Str("inverse_label"),
+                Token::Str("blocked by"),
+                Token::Str("aliases"),
+                Token::Seq { len: Some(1) },
+                Token::Str("blocker"),
+                Token::SeqEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
+}
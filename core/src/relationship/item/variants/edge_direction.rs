@@ -9,7 +9,7 @@ use serde::Serialize;
 use tabled::Tabled;
 
 #[allow(dead_code)]
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, Tabled)]
+#[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Debug, Clone, Copy, Tabled)]
 pub enum EdgeDirectionality {
     Directed,
     Undirected,
@@ -51,7 +51,7 @@ impl Display for EdgeDirectionalityError {
     fn fmt(&self, f: &mut std::fmt::Formatter<>) -> std::fmt::Result {
         match self {
             EdgeDirectionalityError::InvalidInput(bad_string) => write!(
-                f, "{} is an Invalid Priority Option", bad_string),
+                f, "{} is an Invalid Edge Directionality Option", bad_string),
         }
     }
 }
@@ -1,20 +1,23 @@
 use serde::Deserialize;
 use serde::Serialize;
-use tabled::Tabled;
 use std::fmt;
 use std::str::FromStr;
 
 pub mod edge_direction;
 pub use edge_direction::*;
 
+pub mod custom;
+pub use custom::*;
 
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, Tabled)]
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Debug, Clone)]
 #[non_exhaustive]
 pub enum RelationshipVariant {
     Parental(EdgeDirectionality),
     Sequential(EdgeDirectionality),
     Related(EdgeDirectionality),
+    Custom(String, EdgeDirectionality),
 }
 
 impl RelationshipVariant {
@@ -24,9 +27,14 @@ impl RelationshipVariant {
             RelationshipVariant::Related(direction) => direction.to_string(),
             RelationshipVariant::Parental(direction) => direction.to_string(),
             RelationshipVariant::Sequential(direction) => direction.to_string(),
+            RelationshipVariant::Custom(_, direction) => direction.to_string(),
         };
     }
 
+    pub fn is_custom(&self) -> bool {
+        matches!(self, RelationshipVariant::Custom(_, _))
+    }
+
     pub fn create_related() -> RelationshipVariant {
         return RelationshipVariant::Related(EdgeDirectionality::Undirected);
     }
@@ -38,6 +46,24 @@ impl RelationshipVariant {
     pub fn create_parental() -> RelationshipVariant {
         return RelationshipVariant::Parental(EdgeDirectionality::Directed);
     }
+
+    pub fn create_custom(name: &str, direction: EdgeDirectionality) -> RelationshipVariant {
+        RelationshipVariant::Custom(name.to_owned(), direction)
+    }
+
+    pub fn from_str_with_custom_variants(
+        s: &str,
+        custom_variants: &im::Vector<CustomVariant>,
+    ) -> Result<Self, String> {
+        match RelationshipVariant::from_str(s) {
+            Ok(variant) => Ok(variant),
+            Err(error) => custom_variants
+                .iter()
+                .find(|custom_variant| custom_variant.matches(s))
+                .map(|custom_variant| custom_variant.get_variant())
+                .ok_or(error),
+        }
+    }
 }
 
 impl fmt::Display for RelationshipVariant {
@@ -48,6 +74,7 @@ impl fmt::Display for RelationshipVariant {
             RelationshipVariant::Sequential(edge) => {
                 write!(f, "Sequential: {:?}", edge)
             }
+            RelationshipVariant::Custom(name, edge) => write!(f, "{}: {:?}", name, edge),
         }
     }
 }
@@ -176,6 +203,73 @@ mod tests {
         assert!(edge_string == "Directed")
     }
 
+    #[test]
+    fn create_custom_variant() {
+        let custom_variant = RelationshipVariant::create_custom("blocks", EdgeDirectionality::Directed);
+
+        assert!(custom_variant.is_custom());
+        assert_eq!(custom_variant.get_edge_direction(), "Directed");
+    }
+
+    #[test]
+    fn print_custom_string() {
+        let custom_variant = RelationshipVariant::create_custom("blocks", EdgeDirectionality::Directed);
+
+        assert_eq!(format!("{}", custom_variant), "blocks: Directed")
+    }
+
+    #[test]
+    fn parse_custom_variant_from_alias() {
+        let custom_variants = im::Vector::unit(CustomVariant::create_new(
+            "blocks", EdgeDirectionality::Directed, "blocked by", im::vector!["blocker".to_string()]));
+
+        let parsed_variant =
+            RelationshipVariant::from_str_with_custom_variants("Blocker", &custom_variants).unwrap();
+
+        assert_eq!(parsed_variant, RelationshipVariant::create_custom("blocks", EdgeDirectionality::Directed));
+    }
+
+    #[test]
+    fn built_in_variants_win_over_custom_variants() {
+        let custom_variants = im::Vector::unit(CustomVariant::create_new(
+            "duplicates", EdgeDirectionality::Undirected, "duplicates", im::vector!["related".to_string()]));
+
+        let parsed_variant =
+            RelationshipVariant::from_str_with_custom_variants("related", &custom_variants).unwrap();
+
+        assert_eq!(parsed_variant, RelationshipVariant::create_related());
+    }
+
+    #[test]
+    fn failed_parse_unregistered_custom_variant() {
+        let relationship_error =
+            RelationshipVariant::from_str_with_custom_variants("blocks", &im::Vector::new()).unwrap_err();
+
+        assert_eq!(relationship_error, "invalid relationship variant")
+    }
+
+    #[test]
+    fn custom_serialization_and_deserialization() {
+        let example_variant = RelationshipVariant::create_custom("blocks", EdgeDirectionality::Directed);
+
+        assert_tokens(
+            &example_variant,
+            &[
+                Token::TupleVariant {
+                    name: "RelationshipVariant",
+                    variant: "Custom",
+                    len: 2,
+                },
+                Token::Str("blocks"),
+                Token::UnitVariant {
+                    name: "EdgeDirectionality",
+                    variant: "Directed",
+                },
+                Token::TupleVariantEnd,
+            ],
+        )
+    }
+
     #[test]
     fn serialization_and_deserialization() {
         let example_edge = RelationshipVariant::create_related();
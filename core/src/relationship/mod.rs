@@ -6,12 +6,48 @@ pub use item::Relationship;
 
 pub use item::RelationshipVariant;
 
+pub mod index;
+
+pub use index::{RelationshipIndex, RelationshipIndexCache};
+
+use chrono::{DateTime, Utc};
 use std::{error::Error, str::FromStr};
 use tabled::Table;
 use uuid::Uuid;
 
 use im::Vector;
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ParticipantList {
+    Participant1,
+    Participant2,
+    Either,
+}
+
+impl ParticipantList {
+    pub fn contains(&self, relationship: &Relationship, id: Uuid) -> bool {
+        match self {
+            ParticipantList::Participant1 => relationship.get_participant_1() == id,
+            ParticipantList::Participant2 => relationship.get_participant_2() == id,
+            ParticipantList::Either => {
+                relationship.get_participant_1() == id || relationship.get_participant_2() == id
+            }
+        }
+    }
+}
+
+impl FromStr for ParticipantList {
+    type Err = Box<dyn Error>;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "participant_1" | "p1" | "1" | "list1" => Ok(ParticipantList::Participant1),
+            "participant_2" | "p2" | "2" | "list2" => Ok(ParticipantList::Participant2),
+            "either" | "P1orP2" | "3" | "list1or2" => Ok(ParticipantList::Either),
+            _ => Err("Invalid List Name".into()),
+        }
+    }
+}
+
 pub trait RelationshipListManagement {
     type L: RelationshipListManagement;
     fn append_new_relationship(
@@ -20,9 +56,9 @@ pub trait RelationshipListManagement {
         participant_1: Uuid,
         participant_2: Uuid,
     ) -> Result<Self::L, Box<dyn Error>>;
-    fn append_related_relationship(&self, participant_1: Uuid, participant_2: Uuid) -> Self::L;
-    fn append_sequential_relationship(&self, participant_1: Uuid, participant_2: Uuid) -> Self::L;
-    fn append_parental_relationship(&self, participant_1: Uuid, participant_2: Uuid) -> Self::L;
+    fn append_related_relationship(&self, participant_1: Uuid, participant_2: Uuid) -> Result<Self::L, Box<dyn Error>>;
+    fn append_sequential_relationship(&self, participant_1: Uuid, participant_2: Uuid) -> Result<Self::L, Box<dyn Error>>;
+    fn append_parental_relationship(&self, participant_1: Uuid, participant_2: Uuid) -> Result<Self::L, Box<dyn Error>>;
 
     fn select_relationship_by_id(&self, id: Uuid) -> Result<Relationship, String>;
     fn select_relationship_by_index(&self, index: usize) -> Result<Relationship, Box<dyn Error>>;
@@ -31,6 +67,9 @@ pub trait RelationshipListManagement {
     fn get_relationship_variant(&self, index: usize) -> Result<RelationshipVariant, Box<dyn Error>>;
     fn get_relationship_participant_1(&self, index: usize) -> Result<Uuid, Box<dyn Error>>;
     fn get_relationship_participant_2(&self, index: usize) -> Result<Uuid, Box<dyn Error>>;
+    fn get_relationship_label(&self, index: usize) -> Result<Option<String>, Box<dyn Error>>;
+    fn get_relationship_weight(&self, index: usize) -> Result<Option<f64>, Box<dyn Error>>;
+    fn get_relationship_created(&self, index: usize) -> Result<Option<DateTime<Utc>>, Box<dyn Error>>;
 
     fn remove_at_index(&self, index: usize) -> Result<Self::L, Box<dyn Error>>;
     fn remove_with_id(&self, id: Uuid) -> Result<Self::L, Box<dyn Error>>;
@@ -38,6 +77,9 @@ pub trait RelationshipListManagement {
     fn change_relationship_variant(&self, index: usize, variant: &str) -> Result<Self::L, Box<dyn Error>>;
     fn update_relationship_participant_1(&self, index: usize, new_id: Uuid) -> Result<Self::L, Box<dyn Error>>;
     fn update_relationship_participant_2(&self, index: usize, new_id: Uuid) -> Result<Self::L, Box<dyn Error>>;
+    fn set_relationship_label(&self, index: usize, label: Option<&str>) -> Result<Self::L, Box<dyn Error>>;
+    fn set_relationship_weight(&self, index: usize, weight: Option<f64>) -> Result<Self::L, Box<dyn Error>>;
+    fn set_relationship_created(&self, index: usize, created: Option<DateTime<Utc>>) -> Result<Self::L, Box<dyn Error>>;
 
     fn id_is_present_in_participant_1_list(&self, id: Uuid) -> bool;
     fn id_is_present_in_participant_2_list(&self, id: Uuid) -> bool;
@@ -72,30 +114,30 @@ impl RelationshipListManagement for Vector<Relationship> {
         cloned_list.push_back(new_relationship);
         return Ok(cloned_list);
     }
-    fn append_related_relationship(&self, participant_1: Uuid, participant_2: Uuid) -> Self::L {
+    fn append_related_relationship(&self, participant_1: Uuid, participant_2: Uuid) -> Result<Self::L, Box<dyn Error>> {
         let mut cloned_list = self.clone();
         let new_relationship = Relationship::create_new_related(
             participant_1, participant_2);
 
         cloned_list.push_back(new_relationship);
 
-        return cloned_list;
+        return Ok(cloned_list);
     }
-    fn append_sequential_relationship(&self, participant_1: Uuid, participant_2: Uuid) -> Self::L {
+    fn append_sequential_relationship(&self, participant_1: Uuid, participant_2: Uuid) -> Result<Self::L, Box<dyn Error>> {
         let mut cloned_list = self.clone();
         let new_relationship = Relationship::create_new_sequential(participant_1, participant_2);
 
         cloned_list.push_back(new_relationship);
 
-        return cloned_list;
+        return Ok(cloned_list);
     }
-    fn append_parental_relationship(&self, participant_1: Uuid, participant_2: Uuid) -> Self::L {
+    fn append_parental_relationship(&self, participant_1: Uuid, participant_2: Uuid) -> Result<Self::L, Box<dyn Error>> {
         let mut cloned_list = self.clone();
         let new_relationship = Relationship::create_new_parental(participant_1, participant_2);
 
         cloned_list.push_back(new_relationship);
 
-        return cloned_list;
+        return Ok(cloned_list);
     }
 
     fn select_relationship_by_id(&self, id: Uuid) -> Result<Relationship, String> {
@@ -140,6 +182,15 @@ impl RelationshipListManagement for Vector<Relationship> {
 
         Ok(cloned_relationship.get_participant_2())
     }
+    fn get_relationship_label(&self, index: usize) -> Result<Option<String>, Box<dyn Error>> {
+        Ok(self.select_relationship_by_index(index)?.get_label())
+    }
+    fn get_relationship_weight(&self, index: usize) -> Result<Option<f64>, Box<dyn Error>> {
+        Ok(self.select_relationship_by_index(index)?.get_weight())
+    }
+    fn get_relationship_created(&self, index: usize) -> Result<Option<DateTime<Utc>>, Box<dyn Error>> {
+        Ok(self.select_relationship_by_index(index)?.get_created())
+    }
 
     fn remove_at_index(&self, index: usize) -> Result<Self::L, Box<dyn Error>> {
         match self.select_relationship_by_index(index){
@@ -189,6 +240,21 @@ impl RelationshipListManagement for Vector<Relationship> {
 
         Ok(cloned_list)
     }
+    fn set_relationship_label(&self, index: usize, label: Option<&str>) -> Result<Self::L, Box<dyn Error>> {
+        let updated_relationship = self.select_relationship_by_index(index)?.set_label(label);
+
+        Ok(self.update(index, updated_relationship))
+    }
+    fn set_relationship_weight(&self, index: usize, weight: Option<f64>) -> Result<Self::L, Box<dyn Error>> {
+        let updated_relationship = self.select_relationship_by_index(index)?.set_weight(weight)?;
+
+        Ok(self.update(index, updated_relationship))
+    }
+    fn set_relationship_created(&self, index: usize, created: Option<DateTime<Utc>>) -> Result<Self::L, Box<dyn Error>> {
+        let updated_relationship = self.select_relationship_by_index(index)?.set_created(created);
+
+        Ok(self.update(index, updated_relationship))
+    }
 
     fn id_is_present_in_participant_1_list(&self, id: Uuid) -> bool {
         let query_result = self.iter().find(|relationship| relationship.get_participant_1() == id);
@@ -211,87 +277,53 @@ impl RelationshipListManagement for Vector<Relationship> {
         || self.id_is_present_in_participant_2_list(id)
     }
     fn filter_by_participants(&self,participant_list: String, id: Uuid) -> Result<Self::L, Box<dyn Error>> {
-        match participant_list.as_str(){
-            "participant_1" | "p1" | "1" | "list1" => {
-                Ok(self.get_participant_1_list_for_id(id)?)
-            },
-            "participant_2" | "p2" | "2" | "list2" => {
-                Ok(self.get_participant_2_list_for_id(id)?)
-            },
-            "either" | "P1orP2" | "3" | "list1or2" => {
-                Ok(self.get_either_participant_list_for_id(id)?)
-            },
-            _ => {
-                return Err(Box::new(std::io::Error::new(
-                    std::io::ErrorKind::Other,
-                    "Invalid List Name",
-                )));
-            }
+        match ParticipantList::from_str(&participant_list)? {
+            ParticipantList::Participant1 => Ok(self.get_participant_1_list_for_id(id)?),
+            ParticipantList::Participant2 => Ok(self.get_participant_2_list_for_id(id)?),
+            ParticipantList::Either => Ok(self.get_either_participant_list_for_id(id)?),
         }
-
     }
 
     fn get_participant_1_list_for_id(&self, id: Uuid) -> Result<Self::L, Box<dyn Error>> {
-        match self.id_is_present_in_participant_1_list(id){
-            true => {
-                let mut participant_1_list = Vector::new();
-
-                for relationship in self.iter(){
-                    if relationship.get_participant_1() == id{
-                        participant_1_list.push_back(relationship.clone());
-                    }
-                }
-
-                return Ok(participant_1_list);
-            },
-            false => {
-                return Err(Box::new(std::io::Error::new(
-                    std::io::ErrorKind::Other,
-                    "Unable to find Relationship with given Id in participant 1 list",
-                )));
-            }
+        let participant_1_list: Vector<Relationship> = self.iter()
+            .filter(|relationship| relationship.get_participant_1() == id)
+            .cloned()
+            .collect();
+
+        match participant_1_list.is_empty(){
+            false => Ok(participant_1_list),
+            true => Err(Box::new(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                "Unable to find Relationship with given Id in participant 1 list",
+            ))),
         }
     }
     fn get_participant_2_list_for_id(&self, id: Uuid) -> Result<Self::L, Box<dyn Error>> {
-        match self.id_is_present_in_participant_2_list(id){
-            true => {
-                let mut participant_2_list = Vector::new();
-
-                for relationship in self.iter(){
-                    if relationship.get_participant_2() == id{
-                        participant_2_list.push_back(relationship.clone());
-                    }
-                }
-
-                return Ok(participant_2_list);
-            },
-            false => {
-                return Err(Box::new(std::io::Error::new(
-                    std::io::ErrorKind::Other,
-                    "Unable to find Relationship with given Id in participant 2 list",
-                )));
-            }
+        let participant_2_list: Vector<Relationship> = self.iter()
+            .filter(|relationship| relationship.get_participant_2() == id)
+            .cloned()
+            .collect();
+
+        match participant_2_list.is_empty(){
+            false => Ok(participant_2_list),
+            true => Err(Box::new(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                "Unable to find Relationship with given Id in participant 2 list",
+            ))),
         }
     }
     fn get_either_participant_list_for_id(&self, id: Uuid) -> Result<Self::L, Box<dyn Error>> {
-        match self.id_is_present_in_either_participant_list(id){
-            true => {
-                let mut either_participant_list = Vector::new();
-
-                for relationship in self.iter(){
-                    if relationship.get_participant_1() == id || relationship.get_participant_2() == id{
-                        either_participant_list.push_back(relationship.clone());
-                    }
-                }
-
-                return Ok(either_participant_list);
-            },
-            false => {
-                return Err(Box::new(std::io::Error::new(
-                    std::io::ErrorKind::Other,
-                    "Unable to find Relationship with given Id in either participant list",
-                )));
-            }
+        let either_participant_list: Vector<Relationship> = self.iter()
+            .filter(|relationship| relationship.get_participant_1() == id || relationship.get_participant_2() == id)
+            .cloned()
+            .collect();
+
+        match either_participant_list.is_empty(){
+            false => Ok(either_participant_list),
+            true => Err(Box::new(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                "Unable to find Relationship with given Id in either participant list",
+            ))),
         }
     }
 
@@ -373,4 +405,15 @@ pub mod tests {
             ],
         )
     }
+
+    #[test]
+    fn parse_participant_list_aliases() {
+        assert_eq!(ParticipantList::from_str("list1").unwrap(), ParticipantList::Participant1);
+        assert_eq!(ParticipantList::from_str("p2").unwrap(), ParticipantList::Participant2);
+        assert_eq!(ParticipantList::from_str("P1orP2").unwrap(), ParticipantList::Either);
+        assert_eq!(
+            ParticipantList::from_str("both").unwrap_err().to_string(),
+            "Invalid List Name"
+        );
+    }
 }
@@ -1,42 +1,90 @@
-use crate::relationship::Relationship;
-use crate::RelationshipListManagement;
+use crate::csv_storage::{describe_row_error, get_field, CSVRows, CSVStorage};
+use crate::relationship::item::CustomVariant;
+use crate::relationship::{Relationship, RelationshipVariant};
 
-use std::fs::File;
-use std::io::{BufReader, BufWriter, Write};
-use std::{error::Error, path::Path};
+use std::error::Error;
+use std::io::{Read, Write};
 
+use csv::Writer;
 use im::Vector;
-
-pub trait JSONStorage {
-    type L: RelationshipListManagement;
-    fn write_to_json(&self, file_path: &Path, pretty_print: bool) -> Result<(), Box<dyn Error>>;
-    fn read_from_json(file_path: &Path) -> Result<Self::L, Box<dyn Error>>;
-}
-
-impl JSONStorage for Vector<Relationship> {
-    type L = Vector<Relationship>;
-    fn write_to_json(&self, file_path: &Path, pretty_print: bool) -> Result<(), Box<dyn Error>> {
-        let file = File::create(file_path)?;
-        let mut file_writer = BufWriter::new(file);
-
-        if pretty_print == true {
-            serde_json::to_writer_pretty(&mut file_writer, &self)?;
-            file_writer.flush()?;
-        } else {
-            serde_json::to_writer(&mut file_writer, &self)?;
+use uuid::Uuid;
+
+impl CSVStorage for Vector<Relationship> {
+    fn write_csv<W: Write>(&self, writer: W) -> Result<(), Box<dyn Error>> {
+        let mut csv_writer = Writer::from_writer(writer);
+
+        csv_writer.write_record(["id", "variant", "participant_1", "participant_2", "label", "weight"])?;
+        for relationship in self.iter() {
+            csv_writer.write_record([
+                relationship.get_id().to_string(),
+                relationship.get_variant().to_string(),
+                relationship.get_participant_1().to_string(),
+                relationship.get_participant_2().to_string(),
+                relationship.get_label().unwrap_or_default(),
+                relationship.get_weight().map(|weight| weight.to_string()).unwrap_or_default(),
+            ])?;
         }
+        csv_writer.flush()?;
 
         Ok(())
     }
 
-    fn read_from_json(file_path: &Path) -> Result<Self::L, Box<dyn Error>> {
-        let file = File::open(file_path)?;
-        let file_reader = BufReader::new(file);
+    fn read_csv<R: Read>(reader: R) -> Result<Self, Box<dyn Error>> {
+        read_relationships_from_csv(reader, &Vector::new())
+    }
+}
 
-        let new_list = serde_json::from_reader(file_reader)?;
+pub fn read_relationships_from_csv<R: Read>(
+    reader: R,
+    custom_variants: &Vector<CustomVariant>,
+) -> Result<Vector<Relationship>, Box<dyn Error>> {
+    let rows = CSVRows::read(reader)?;
+    let id_column = rows.require_column(&["id", "uuid"])?;
+    let variant_column = rows.require_column(&["variant"])?;
+    let participant_1_column = rows.require_column(&["participant_1"])?;
+    let participant_2_column = rows.require_column(&["participant_2"])?;
+    let label_column = rows.find_column(&["label"]);
+    let weight_column = rows.find_column(&["weight"]);
+
+    let parse_id = |row: usize, field: &str| {
+        Uuid::parse_str(field).map_err(|_| describe_row_error(row, format!("{} is an Invalid Id", field)))
+    };
+
+    let mut relationship_list = Vector::new();
+    for (row, record) in rows.get_records() {
+        let variant_field = get_field(record, Some(variant_column)).unwrap_or_default();
+        let variant_name = match variant_field.split_once(':') {
+            Some((name, _)) => name.trim(),
+            None => variant_field,
+        };
+        let variant = RelationshipVariant::from_str_with_custom_variants(
+            &variant_name.to_lowercase(),
+            custom_variants,
+        )
+        .map_err(|_| describe_row_error(*row, format!("{} is an Invalid Relationship Variant", variant_field)))?;
+
+        let weight = match get_field(record, weight_column) {
+            Some(weight_field) => Some(weight_field.parse::<f64>().map_err(|_| {
+                describe_row_error(*row, format!("{} is an Invalid Relationship Weight", weight_field))
+            })?),
+            None => None,
+        };
+
+        let relationship = Relationship::create_new_with_variant(
+            variant,
+            parse_id(*row, get_field(record, Some(participant_1_column)).unwrap_or_default())?,
+            parse_id(*row, get_field(record, Some(participant_2_column)).unwrap_or_default())?,
+        )
+        .set_id(parse_id(*row, get_field(record, Some(id_column)).unwrap_or_default())?)
+        .set_label(get_field(record, label_column))
+        .set_weight(weight)
+        .map_err(|error| describe_row_error(*row, error))?
+        .set_created(None);
 
-        Ok(new_list)
+        relationship_list.push_back(relationship);
     }
+
+    Ok(relationship_list)
 }
 
 #[cfg(test)]
@@ -45,6 +93,7 @@ mod test {
 
 
     use super::*;
+    use crate::storage::{read_json_file, write_json_file};
 
     use crate::relationship::tests::create_vector_with_nill_relationship;
 
@@ -55,7 +104,7 @@ mod test {
         let file_path = Path::new("data/test_pretty_relationship.json");
         let mut file_contents = String::new();
 
-        list.write_to_json(file_path, true).unwrap();
+        write_json_file(&list, file_path, true, 0).unwrap();
 
         fs::File::open(file_path)
             .unwrap()
@@ -84,7 +133,7 @@ mod test {
         let file_path = Path::new("data/test_relationship.json");
         let mut file_contents = String::new();
 
-        list.write_to_json(file_path, false).unwrap();
+        write_json_file(&list, file_path, false, 0).unwrap();
 
         fs::File::open(file_path)
             .unwrap()
@@ -99,20 +148,63 @@ mod test {
 
     #[test]
     fn successfully_read_json() {
-        let file_list =
-            Vector::<Relationship>::read_from_json(Path::new("data/test_read_relationship.json"))
-                .unwrap();
+        let file_list: Vector<Relationship> =
+            read_json_file(Path::new("data/test_read_relationship.json")).unwrap();
 
         assert_eq!(file_list, create_vector_with_nill_relationship());
     }
 
     #[test]
-    fn successfully_read_pretty_json() {
-        let file_list = Vector::<Relationship>::read_from_json(Path::new(
-            "data/test_read_pretty_relationship.json",
+    fn successfully_read_relationship_csv() {
+        let file_list = Vector::<Relationship>::read_from_csv(Path::new(
+            "data/successful_relationship_list_read_test.csv",
         ))
         .unwrap();
 
+        assert_eq!(file_list, create_vector_with_nill_relationship());
+    }
+
+    #[test]
+    fn failed_read_relationship_csv_bad_variant() {
+        let error =
+            Vector::<Relationship>::read_from_csv(Path::new("data/test_relationship.csv")).unwrap_err();
+
+        assert_eq!(error.to_string(), "Row 1: Undirected is an Invalid Relationship Variant");
+    }
+
+    #[test]
+    fn read_relationship_csv_with_custom_variant() {
+        let custom_variants = im::vector![CustomVariant::create_new(
+            "blocks",
+            crate::relationship::item::EdgeDirectionality::Directed,
+            "blocked by",
+            Vector::new(),
+        )];
+        let csv_contents = "id,variant,participant_1,participant_2,label,weight\n\
+            00000000-0000-0000-0000-000000000000,blocks: Directed,00000000-0000-0000-0000-000000000000,00000000-0000-0000-0000-000000000000,release,2.5\n";
+
+        let file_list = read_relationships_from_csv(csv_contents.as_bytes(), &custom_variants).unwrap();
+
+        assert_eq!(file_list[0].get_variant().to_string(), "blocks: Directed");
+        assert_eq!(file_list[0].get_label(), Some("release".to_string()));
+        assert_eq!(file_list[0].get_weight(), Some(2.5));
+    }
+
+    #[test]
+    fn write_and_read_relationship_csv() {
+        let list = create_vector_with_nill_relationship();
+        let mut buffer = Vec::new();
+
+        list.write_csv(&mut buffer).unwrap();
+
+        assert_eq!(Vector::<Relationship>::read_csv(buffer.as_slice()).unwrap(), list);
+    }
+
+    #[test]
+    fn successfully_read_pretty_json() {
+        let file_list: Vector<Relationship> =
+            read_json_file(Path::new("data/test_read_pretty_relationship.json")).unwrap();
+
         assert_eq!(file_list, create_vector_with_nill_relationship())
     }
 }
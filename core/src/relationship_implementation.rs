@@ -1,13 +1,264 @@
 use crate::ClearHeadApp;
 
-use crate::relationship::item::RelationshipVariant;
-use crate::relationship::Relationship;
+use crate::relationship::item::{CustomVariant, EdgeDirectionality, RelationshipVariant};
+use crate::relationship::{ParticipantList, Relationship, RelationshipIndex};
 use crate::relationship::RelationshipListManagement;
+use crate::ActionManipulation;
+use chrono::{DateTime, Utc};
 use im::Vector;
 
+use std::collections::HashSet;
 use std::error::Error;
+use std::str::FromStr;
+use tabled::object::Rows;
+use tabled::{Alignment, Header, Modify, Table, Tabled};
 use uuid::Uuid;
 
+#[derive(Tabled)]
+struct RelationshipRow {
+    #[tabled(rename = "Index")]
+    index: usize,
+    #[tabled(rename = "Variant")]
+    variant: String,
+    #[tabled(rename = "From")]
+    from: String,
+    #[tabled(rename = "To")]
+    to: String,
+    #[tabled(rename = "Label")]
+    label: String,
+}
+
+pub(crate) fn collect_descendant_ids(relationship_index: &RelationshipIndex, id: Uuid) -> Vector<Uuid> {
+    let parental_variant = RelationshipVariant::create_parental();
+    let mut descendant_ids: Vector<Uuid> = Vector::new();
+    let mut visited_ids: HashSet<Uuid> = HashSet::from([id]);
+    let mut frontier: Vector<Uuid> = Vector::unit(id);
+
+    while let Some(current_id) = frontier.pop_front() {
+        for child_id in relationship_index.get_outgoing_ids(current_id, &parental_variant) {
+            if visited_ids.insert(child_id) {
+                descendant_ids.push_back(child_id);
+                frontier.push_back(child_id);
+            }
+        }
+    }
+
+    descendant_ids
+}
+
+impl ClearHeadApp {
+    pub fn parse_relationship_variant(
+        &self,
+        variant_str: &str,
+    ) -> Result<RelationshipVariant, Box<dyn Error>> {
+        Ok(RelationshipVariant::from_str_with_custom_variants(
+            variant_str,
+            &self.custom_variants,
+        )?)
+    }
+
+    pub fn register_custom_variant(
+        &self,
+        name: &str,
+        directionality: &str,
+        inverse_label: Option<&str>,
+        aliases: Vector<String>,
+    ) -> Result<ClearHeadApp, Box<dyn Error>> {
+        if name.trim().is_empty() {
+            return Err("a relationship variant needs a name".into());
+        }
+        if aliases.iter().any(|alias| alias.trim().is_empty()) {
+            return Err("a relationship variant alias cannot be empty".into());
+        }
+        if inverse_label.is_some_and(|inverse_label| inverse_label.trim().is_empty()) {
+            return Err("a relationship variant inverse label cannot be empty".into());
+        }
+        let directionality = EdgeDirectionality::from_str(directionality)?;
+        let custom_variant = CustomVariant::create_new(
+            name,
+            directionality,
+            inverse_label.unwrap_or(name),
+            aliases,
+        );
+
+        let mut labels = custom_variant.get_aliases();
+        labels.push_front(custom_variant.get_name());
+        let mut seen_labels = HashSet::new();
+        for label in labels.iter() {
+            if !seen_labels.insert(label.to_ascii_lowercase()) {
+                return Err(format!("{} is listed more than once", label).into());
+            }
+        }
+        let inverse_label = custom_variant.get_inverse_label();
+        if !seen_labels.contains(&inverse_label.to_ascii_lowercase()) {
+            labels.push_back(inverse_label);
+        }
+        for label in labels.iter() {
+            if self.parse_relationship_variant(label).is_ok() {
+                return Err(format!("{} is already a relationship variant", label).into());
+            }
+        }
+
+        let mut cloned_app = self.clone();
+        cloned_app.custom_variants.push_back(custom_variant);
+
+        Ok(cloned_app)
+    }
+
+    pub fn select_custom_variant(&self, name: &str) -> Result<CustomVariant, Box<dyn Error>> {
+        self.custom_variants
+            .iter()
+            .find(|custom_variant| custom_variant.matches(name))
+            .cloned()
+            .ok_or_else(|| format!("{} is not a registered relationship variant", name).into())
+    }
+
+    pub fn remove_custom_variant(&self, name: &str) -> Result<ClearHeadApp, Box<dyn Error>> {
+        let custom_variant = self.select_custom_variant(name)?;
+        let usage_count = self
+            .relationship_list
+            .iter()
+            .filter(|relationship| relationship.get_variant() == custom_variant.get_variant())
+            .count();
+        if usage_count > 0 {
+            return Err(format!(
+                "{} is still used by {} relationship(s)",
+                custom_variant.get_name(),
+                usage_count
+            )
+            .into());
+        }
+
+        let mut cloned_app = self.clone();
+        cloned_app
+            .custom_variants
+            .retain(|registered_variant| registered_variant != &custom_variant);
+
+        Ok(cloned_app)
+    }
+
+    pub fn get_inverse_label(&self, variant: &RelationshipVariant) -> String {
+        match variant {
+            RelationshipVariant::Custom(name, _) => self
+                .select_custom_variant(name)
+                .map(|custom_variant| custom_variant.get_inverse_label())
+                .unwrap_or_else(|_| name.clone()),
+            built_in_variant => built_in_variant.to_string(),
+        }
+    }
+
+    pub fn get_relationship_index(&self) -> RelationshipIndex<'_> {
+        self.relationship_index.get_index(&self.relationship_list)
+    }
+
+    pub fn get_descendant_ids(&self, id: Uuid) -> Vector<Uuid> {
+        collect_descendant_ids(&self.get_relationship_index(), id)
+    }
+
+    pub fn get_participant_name(&self, id: Uuid) -> String {
+        match self.select_action_by_id(id) {
+            Ok(action) => action.get_name(),
+            Err(_) => id.to_string(),
+        }
+    }
+
+    pub fn filter_relationship_indices(
+        &self,
+        variant: Option<&str>,
+        participant: Option<Uuid>,
+        participant_list: &str,
+    ) -> Result<Vec<usize>, Box<dyn Error>> {
+        let filter_variant = match variant {
+            Some(variant) => Some(self.parse_relationship_variant(variant)?),
+            None => None,
+        };
+        let participant_list = ParticipantList::from_str(participant_list)?;
+
+        Ok(self
+            .relationship_list
+            .iter()
+            .enumerate()
+            .filter(|(_, relationship)| match &filter_variant {
+                Some(filter_variant) => relationship.get_variant() == *filter_variant,
+                None => true,
+            })
+            .filter(|(_, relationship)| match participant {
+                Some(id) => participant_list.contains(relationship, id),
+                None => true,
+            })
+            .map(|(index, _)| index)
+            .collect())
+    }
+
+    pub fn get_relationship_overview(&self, indices: &[usize]) -> Result<String, Box<dyn Error>> {
+        let mut rows = Vec::with_capacity(indices.len());
+        for index in indices {
+            let relationship = self.select_relationship_by_index(*index)?;
+            rows.push(RelationshipRow {
+                index: *index,
+                variant: relationship.get_variant().to_string(),
+                from: self.get_participant_name(relationship.get_participant_1()),
+                to: self.get_participant_name(relationship.get_participant_2()),
+                label: relationship.get_label().unwrap_or_default(),
+            });
+        }
+
+        Ok(Table::new(&rows)
+            .with(Header("Relationships"))
+            .with(Modify::new(Rows::first()).with(Alignment::center()))
+            .to_string())
+    }
+
+    pub fn get_relationship_details(&self, index: usize) -> Result<String, Box<dyn Error>> {
+        let relationship = self.select_relationship_by_index(index)?;
+        let mut details = String::new();
+
+        details.push_str(&format!("Variant: {}\n", relationship.get_variant()));
+        details.push_str(&format!(
+            "From: {}\n",
+            self.get_participant_name(relationship.get_participant_1())
+        ));
+        details.push_str(&format!(
+            "To: {}\n",
+            self.get_participant_name(relationship.get_participant_2())
+        ));
+        if relationship.get_variant().is_custom() {
+            details.push_str(&format!(
+                "Inverse: {}\n",
+                self.get_inverse_label(&relationship.get_variant())
+            ));
+        }
+        match relationship.get_label() {
+            Some(label) => details.push_str(&format!("Label: {}\n", label)),
+            None => details.push_str("Label: None\n"),
+        }
+        match relationship.get_weight() {
+            Some(weight) => details.push_str(&format!("Weight: {}\n", weight)),
+            None => details.push_str("Weight: None\n"),
+        }
+        match relationship.get_created() {
+            Some(created) => details.push_str(&format!(
+                "Created: {}\n",
+                created.format("%Y-%m-%d %H:%M:%S")
+            )),
+            None => details.push_str("Created: None\n"),
+        }
+        details.push_str(&format!("Id: {}\n", relationship.get_id()));
+
+        Ok(details)
+    }
+
+    pub fn get_custom_variant_list(&self) -> String {
+        let mut variant_list = String::new();
+
+        for custom_variant in &self.custom_variants {
+            variant_list.push_str(&format!("{}\n", custom_variant));
+        }
+
+        variant_list
+    }
+}
+
 impl RelationshipListManagement for ClearHeadApp {
     type L = ClearHeadApp;
     fn append_new_relationship(
@@ -17,53 +268,50 @@ impl RelationshipListManagement for ClearHeadApp {
         participant_2: Uuid,
     ) -> Result<Self::L, Box<dyn Error>> {
         let mut updated_app = self.clone();
+        let variant = self.parse_relationship_variant(target_variant)?;
 
-        let updated_relationship_list = self.relationship_list.append_new_relationship(
-            target_variant,
+        updated_app.relationship_list.push_back(Relationship::create_new_with_variant(
+            variant,
             participant_1,
             participant_2,
-        )?;
+        ));
 
-        updated_app.relationship_list = updated_relationship_list;
-        Ok(updated_app)
+        self.enforce_constraints(updated_app)
     }
     fn append_related_relationship(
         &self,
         participant_1: Uuid,
         participant_2: Uuid,
-    ) -> ClearHeadApp {
-        let mut cloned_app = self.clone();
+    ) -> Result<ClearHeadApp, Box<dyn Error>> {
+        let mut updated_app = self.clone();
 
-        let updated_relationship_list = self
+        updated_app.relationship_list = self
             .relationship_list
-            .append_related_relationship(participant_1, participant_2);
+            .append_related_relationship(participant_1, participant_2)?;
 
-        cloned_app.relationship_list = updated_relationship_list;
-        return cloned_app;
+        self.enforce_constraints(updated_app)
     }
-    fn append_sequential_relationship(&self, participant_1: Uuid, participant_2: Uuid) -> Self::L {
-        let mut cloned_app = self.clone();
+    fn append_sequential_relationship(&self, participant_1: Uuid, participant_2: Uuid) -> Result<Self::L, Box<dyn Error>> {
+        let mut updated_app = self.clone();
 
-        let updated_relationship_list = self
+        updated_app.relationship_list = self
             .relationship_list
-            .append_sequential_relationship(participant_1, participant_2);
+            .append_sequential_relationship(participant_1, participant_2)?;
 
-        cloned_app.relationship_list = updated_relationship_list;
-        return cloned_app;
+        self.enforce_constraints(updated_app)
     }
     fn append_parental_relationship(
         &self,
         participant_1: Uuid,
         participant_2: Uuid,
-    ) -> ClearHeadApp {
-        let mut cloned_app = self.clone();
+    ) -> Result<ClearHeadApp, Box<dyn Error>> {
+        let mut updated_app = self.clone();
 
-        let updated_relationship_list = self
+        updated_app.relationship_list = self
             .relationship_list
-            .append_parental_relationship(participant_1, participant_2);
+            .append_parental_relationship(participant_1, participant_2)?;
 
-        cloned_app.relationship_list = updated_relationship_list;
-        return cloned_app;
+        self.enforce_constraints(updated_app)
     }
 
     fn select_relationship_by_id(&self, id: Uuid) -> Result<Relationship, String> {
@@ -92,6 +340,18 @@ impl RelationshipListManagement for ClearHeadApp {
             .relationship_list
             .get_relationship_participant_2(index)?)
     }
+    fn get_relationship_label(&self, index: usize) -> Result<Option<String>, Box<dyn Error>> {
+        self.relationship_list.get_relationship_label(index)
+    }
+    fn get_relationship_weight(&self, index: usize) -> Result<Option<f64>, Box<dyn Error>> {
+        self.relationship_list.get_relationship_weight(index)
+    }
+    fn get_relationship_created(
+        &self,
+        index: usize,
+    ) -> Result<Option<DateTime<Utc>>, Box<dyn Error>> {
+        self.relationship_list.get_relationship_created(index)
+    }
 
     fn remove_at_index(&self, index: usize) -> Result<Self::L, Box<dyn Error>> {
         let mut cloned_app = self.clone();
@@ -116,12 +376,13 @@ impl RelationshipListManagement for ClearHeadApp {
         variant: &str,
     ) -> Result<Self::L, Box<dyn Error>> {
         let mut cloned_app = self.clone();
+        let target_relationship = self.relationship_list.select_relationship_by_index(index)?;
+        let variant = self.parse_relationship_variant(variant)?;
 
-        let updated_relationship_list = self
+        cloned_app
             .relationship_list
-            .change_relationship_variant(index, variant)?;
-        cloned_app.relationship_list = updated_relationship_list;
-        Ok(cloned_app)
+            .set(index, target_relationship.set_variant_to(variant));
+        self.enforce_constraints(cloned_app)
     }
     fn update_relationship_participant_1(
         &self,
@@ -134,7 +395,7 @@ impl RelationshipListManagement for ClearHeadApp {
             .relationship_list
             .update_relationship_participant_1(index, new_id)?;
         cloned_app.relationship_list = updated_relationship_list;
-        Ok(cloned_app)
+        self.enforce_constraints(cloned_app)
     }
     fn update_relationship_participant_2(
         &self,
@@ -147,44 +408,93 @@ impl RelationshipListManagement for ClearHeadApp {
             .relationship_list
             .update_relationship_participant_2(index, new_id)?;
         cloned_app.relationship_list = updated_relationship_list;
+        self.enforce_constraints(cloned_app)
+    }
+    fn set_relationship_label(
+        &self,
+        index: usize,
+        label: Option<&str>,
+    ) -> Result<Self::L, Box<dyn Error>> {
+        let mut cloned_app = self.clone();
+
+        cloned_app.relationship_list = self.relationship_list.set_relationship_label(index, label)?;
+        Ok(cloned_app)
+    }
+    fn set_relationship_weight(
+        &self,
+        index: usize,
+        weight: Option<f64>,
+    ) -> Result<Self::L, Box<dyn Error>> {
+        let mut cloned_app = self.clone();
+
+        cloned_app.relationship_list = self.relationship_list.set_relationship_weight(index, weight)?;
+        Ok(cloned_app)
+    }
+    fn set_relationship_created(
+        &self,
+        index: usize,
+        created: Option<DateTime<Utc>>,
+    ) -> Result<Self::L, Box<dyn Error>> {
+        let mut cloned_app = self.clone();
+
+        cloned_app.relationship_list =
+            self.relationship_list.set_relationship_created(index, created)?;
         Ok(cloned_app)
     }
 
     fn id_is_present_in_participant_1_list(&self, id: Uuid) -> bool {
-        self.relationship_list
-            .id_is_present_in_participant_1_list(id)
+        !self.get_relationship_index().get_all_outgoing(id).is_empty()
     }
     fn id_is_present_in_participant_2_list(&self, id: Uuid) -> bool {
-        self.relationship_list
-            .id_is_present_in_participant_2_list(id)
+        !self.get_relationship_index().get_all_incoming(id).is_empty()
     }
     fn id_is_present_in_either_participant_list(&self, id: Uuid) -> bool {
-        self.relationship_list
-            .id_is_present_in_either_participant_list(id)
+        self.get_relationship_index().get_degree(id) > 0
     }
 
     fn get_children_for_id(&self, id: Uuid) -> Result<Vector<Uuid>, Box<dyn Error>> {
-        todo!()
+        if !self.id_is_present_in_participant_1_list(id) {
+            return Err("Unable to find Relationship with given Id in participant 1 list".into());
+        }
+
+        Ok(self
+            .get_relationship_index()
+            .get_outgoing_ids(id, &RelationshipVariant::create_parental())
+            .into_iter()
+            .collect())
     }
     fn get_participant_1_list_for_id(
         &self,
         id: Uuid,
     ) -> Result<Vector<Relationship>, Box<dyn Error>> {
-        Ok(self.relationship_list.get_participant_1_list_for_id(id)?)
+        match self.get_relationship_index().get_all_outgoing(id) {
+            relationships if relationships.is_empty() => {
+                Err("Unable to find Relationship with given Id in participant 1 list".into())
+            }
+            relationships => Ok(relationships.into_iter().cloned().collect()),
+        }
     }
     fn get_participant_2_list_for_id(
         &self,
         id: Uuid,
     ) -> Result<Vector<Relationship>, Box<dyn Error>> {
-        Ok(self.relationship_list.get_participant_2_list_for_id(id)?)
+        match self.get_relationship_index().get_all_incoming(id) {
+            relationships if relationships.is_empty() => {
+                Err("Unable to find Relationship with given Id in participant 2 list".into())
+            }
+            relationships => Ok(relationships.into_iter().cloned().collect()),
+        }
     }
     fn get_either_participant_list_for_id(
         &self,
         id: Uuid,
     ) -> Result<Vector<Relationship>, Box<dyn Error>> {
-        Ok(self
-            .relationship_list
-            .get_either_participant_list_for_id(id)?)
+        match self.get_relationship_index().get_all_connected(id) {
+            relationships if relationships.is_empty() => {
+                Err("Unable to find Relationship with given Id in either participant list".into())
+            }
+            relationships => Ok(relationships.into_iter().cloned().collect()),
+        }
     }
 
     fn filter_by_participants(
@@ -202,17 +512,25 @@ impl RelationshipListManagement for ClearHeadApp {
         Ok(cloned_app)
     }
     fn filter_by_variant(&self, variant: &str) -> Result<Vector<Relationship>, Box<dyn Error>> {
-        todo!()
+        let filter_variant = self.parse_relationship_variant(variant)?;
+
+        Ok(self
+            .relationship_list
+            .iter()
+            .filter(|relationship| relationship.get_variant() == filter_variant)
+            .cloned()
+            .collect())
     }
 
     fn get_relationship_list_as_table(&self) -> String {
-        todo!()
+        self.relationship_list.get_relationship_list_as_table()
     }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::{functionality::tests::failed_relationship_index_error, ClearHeadApp};
+    use chrono::TimeZone;
 
     use super::*;
 
@@ -294,7 +612,7 @@ mod tests {
     fn create_related_direct() {
         let test_app = ClearHeadApp::default();
 
-        let updated_app = test_app.append_related_relationship(Uuid::nil(), Uuid::nil());
+        let updated_app = test_app.append_related_relationship(Uuid::nil(), Uuid::nil()).unwrap();
 
         assert_eq!(
             updated_app.get_relationship_variant(0).unwrap(),
@@ -306,7 +624,7 @@ mod tests {
     fn create_sequential_direct() {
         let test_app = ClearHeadApp::default();
 
-        let updated_app = test_app.append_sequential_relationship(Uuid::nil(), Uuid::nil());
+        let updated_app = test_app.append_sequential_relationship(Uuid::nil(), Uuid::nil()).unwrap();
 
         assert_eq!(
             updated_app.get_relationship_variant(0).unwrap(),
@@ -318,7 +636,7 @@ mod tests {
     fn create_parental_direct() {
         let test_app = ClearHeadApp::default();
 
-        let updated_app = test_app.append_parental_relationship(Uuid::nil(), Uuid::nil());
+        let updated_app = test_app.append_parental_relationship(Uuid::nil(), Uuid::nil()).unwrap();
 
         assert_eq!(
             updated_app.get_relationship_variant(0).unwrap(),
@@ -527,6 +845,93 @@ mod tests {
         );
     }
 
+    #[test]
+    fn set_label() {
+        let test_app = create_app_with_single_relationship("related");
+
+        let updated_app = test_app.set_relationship_label(0, Some("same client")).unwrap();
+
+        assert_eq!(
+            updated_app.get_relationship_label(0).unwrap(),
+            Some("same client".to_string())
+        );
+    }
+
+    #[test]
+    fn failed_set_label() {
+        let empty_app = ClearHeadApp::default();
+
+        let index_error = empty_app.set_relationship_label(0, None).unwrap_err();
+
+        assert_eq!(index_error.to_string(), failed_relationship_index_error());
+    }
+
+    #[test]
+    fn set_weight() {
+        let test_app = create_app_with_single_relationship("related");
+
+        let updated_app = test_app.set_relationship_weight(0, Some(3.0)).unwrap();
+
+        assert_eq!(updated_app.get_relationship_weight(0).unwrap(), Some(3.0));
+    }
+
+    #[test]
+    fn failed_set_infinite_weight() {
+        let test_app = create_app_with_single_relationship("related");
+
+        let weight_error = test_app
+            .set_relationship_weight(0, Some(f64::INFINITY))
+            .unwrap_err();
+
+        assert_eq!(weight_error.to_string(), "inf is an Invalid Relationship Weight");
+    }
+
+    #[test]
+    fn created_is_recorded() {
+        let test_app = create_app_with_single_relationship("related");
+
+        let created = test_app.get_relationship_created(0).unwrap();
+
+        assert!(created.is_some());
+    }
+
+    #[test]
+    fn clear_created() {
+        let test_app = create_app_with_single_relationship("related");
+
+        let updated_app = test_app.set_relationship_created(0, None).unwrap();
+
+        assert_eq!(updated_app.get_relationship_created(0).unwrap(), None);
+    }
+
+    #[test]
+    fn print_relationship_table() {
+        let test_app = create_app_with_single_relationship("related")
+            .set_relationship_label(0, Some("blocks"))
+            .unwrap()
+            .set_relationship_weight(0, Some(2.5))
+            .unwrap()
+            .set_relationship_created(0, Some(Utc.with_ymd_and_hms(2024, 1, 2, 3, 4, 5).unwrap()))
+            .unwrap()
+            .append_new_relationship("parental", Uuid::nil(), Uuid::nil())
+            .unwrap()
+            .set_relationship_created(1, None)
+            .unwrap();
+
+        let table = test_app.get_relationship_list_as_table();
+
+        assert_eq!(
+            table,
+            "+---------------------+--------------------------------------+--------------------------------------+--------+--------+---------------------+\n\
+             | Variant             | Participant 1                        | Participant 2                        | Label  | Weight | Created             |\n\
+             +---------------------+--------------------------------------+--------------------------------------+--------+--------+---------------------+\n\
+             | Related: Undirected | 00000000-0000-0000-0000-000000000000 | 00000000-0000-0000-0000-000000000000 | blocks | 2.5    | 2024-01-02 03:04:05 |\n\
+             +---------------------+--------------------------------------+--------------------------------------+--------+--------+---------------------+\n\
+             | Parental: Directed  | 00000000-0000-0000-0000-000000000000 | 00000000-0000-0000-0000-000000000000 |        |        |                     |\n\
+             +---------------------+--------------------------------------+--------------------------------------+--------+--------+---------------------+"
+        );
+    }
+
     #[test]
     fn update_variant() {
         let test_app = create_app_with_single_relationship("related");
@@ -650,7 +1055,7 @@ mod tests {
     #[test]
     fn get_participant_1_list_for_id() {
         let test_app =
-            ClearHeadApp::default().append_related_relationship(Uuid::nil(), Uuid::new_v4());
+            ClearHeadApp::default().append_related_relationship(Uuid::nil(), Uuid::new_v4()).unwrap();
 
         let result = test_app.get_participant_1_list_for_id(Uuid::nil()).unwrap();
 
@@ -677,7 +1082,7 @@ mod tests {
     #[test]
     fn get_participant_2_list_for_id() {
         let test_app =
-            ClearHeadApp::default().append_related_relationship(Uuid::new_v4(), Uuid::nil());
+            ClearHeadApp::default().append_related_relationship(Uuid::new_v4(), Uuid::nil()).unwrap();
 
         let result = test_app.get_participant_2_list_for_id(Uuid::nil()).unwrap();
 
@@ -701,11 +1106,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn get_children_after_relationship_list_changes() {
+        let mut test_app = ClearHeadApp::default()
+            .append_parental_relationship(Uuid::nil(), Uuid::from_u128(1))
+            .unwrap();
+        assert_eq!(test_app.get_children_for_id(Uuid::nil()).unwrap(), Vector::unit(Uuid::from_u128(1)));
+
+        test_app
+            .relationship_list
+            .push_back(Relationship::create_new_parental(Uuid::nil(), Uuid::from_u128(2)));
+
+        assert_eq!(
+            test_app.get_children_for_id(Uuid::nil()).unwrap(),
+            im::vector![Uuid::from_u128(1), Uuid::from_u128(2)]
+        );
+    }
+
     #[test]
     fn get_either_participant_list_for_id() {
         let test_app = ClearHeadApp::default()
             .append_related_relationship(Uuid::new_v4(), Uuid::nil())
-            .append_related_relationship(Uuid::nil(), Uuid::new_v4());
+            .unwrap()
+            .append_related_relationship(Uuid::nil(), Uuid::new_v4())
+            .unwrap();
 
         let result = test_app
             .get_either_participant_list_for_id(Uuid::nil())
@@ -727,4 +1151,350 @@ mod tests {
             "Unable to find Relationship with given Id in either participant list"
         );
     }
+
+    fn create_app_with_blocks_variant() -> ClearHeadApp {
+        ClearHeadApp::default()
+            .register_custom_variant(
+                "blocks",
+                "directed",
+                Some("blocked by"),
+                im::vector!["blocker".to_string()],
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn register_custom_variant() {
+        let test_app = create_app_with_blocks_variant();
+
+        assert_eq!(test_app.custom_variants.len(), 1);
+        assert_eq!(
+            test_app.parse_relationship_variant("blocker").unwrap(),
+            RelationshipVariant::create_custom("blocks", EdgeDirectionality::Directed)
+        );
+    }
+
+    #[test]
+    fn register_custom_variant_defaults_inverse_label() {
+        let test_app = ClearHeadApp::default()
+            .register_custom_variant("duplicates", "undirected", None, Vector::new())
+            .unwrap();
+
+        assert_eq!(
+            test_app.select_custom_variant("duplicates").unwrap().get_inverse_label(),
+            "duplicates"
+        );
+    }
+
+    #[test]
+    fn failed_register_built_in_name() {
+        let test_app = ClearHeadApp::default();
+
+        let conflict_error = test_app
+            .register_custom_variant("parental", "directed", None, Vector::new())
+            .unwrap_err();
+
+        assert_eq!(conflict_error.to_string(), "parental is already a relationship variant");
+    }
+
+    #[test]
+    fn failed_register_duplicate_alias() {
+        let test_app = create_app_with_blocks_variant();
+
+        let conflict_error = test_app
+            .register_custom_variant("prevents", "directed", None, im::vector!["Blocker".to_string()])
+            .unwrap_err();
+
+        assert_eq!(conflict_error.to_string(), "Blocker is already a relationship variant");
+    }
+
+    #[test]
+    fn failed_register_empty_alias() {
+        let test_app = ClearHeadApp::default();
+
+        let alias_error = test_app
+            .register_custom_variant("blocks", "directed", None, im::vector!["  ".to_string()])
+            .unwrap_err();
+
+        assert_eq!(alias_error.to_string(), "a relationship variant alias cannot be empty");
+    }
+
+    #[test]
+    fn failed_register_empty_inverse_label() {
+        let test_app = ClearHeadApp::default();
+
+        let inverse_error = test_app
+            .register_custom_variant("blocks", "directed", Some(" "), Vector::new())
+            .unwrap_err();
+
+        assert_eq!(inverse_error.to_string(), "a relationship variant inverse label cannot be empty");
+    }
+
+    #[test]
+    fn failed_register_alias_equal_to_name() {
+        let test_app = ClearHeadApp::default();
+
+        let repeat_error = test_app
+            .register_custom_variant("blocks", "directed", None, im::vector![" Blocks ".to_string()])
+            .unwrap_err();
+
+        assert_eq!(repeat_error.to_string(), "Blocks is listed more than once");
+    }
+
+    #[test]
+    fn failed_register_repeated_alias() {
+        let test_app = ClearHeadApp::default();
+
+        let repeat_error = test_app
+            .register_custom_variant(
+                "blocks",
+                "directed",
+                None,
+                im::vector!["blocker".to_string(), "blocker".to_string()],
+            )
+            .unwrap_err();
+
+        assert_eq!(repeat_error.to_string(), "blocker is listed more than once");
+    }
+
+    #[test]
+    fn failed_register_inverse_label_of_existing_variant() {
+        let test_app = create_app_with_blocks_variant();
+
+        let conflict_error = test_app
+            .register_custom_variant("unblocks", "directed", Some("blocker"), Vector::new())
+            .unwrap_err();
+
+        assert_eq!(conflict_error.to_string(), "blocker is already a relationship variant");
+        assert!(ClearHeadApp::default()
+            .register_custom_variant("contains", "directed", Some("child"), Vector::new())
+            .is_err());
+    }
+
+    #[test]
+    fn failed_register_bad_directionality() {
+        let test_app = ClearHeadApp::default();
+
+        let direction_error = test_app
+            .register_custom_variant("blocks", "sideways", None, Vector::new())
+            .unwrap_err();
+
+        assert_eq!(direction_error.to_string(), "sideways is an Invalid Edge Directionality Option");
+    }
+
+    #[test]
+    fn create_custom_relationship() {
+        let test_app = create_app_with_blocks_variant();
+
+        let updated_app = test_app
+            .append_new_relationship("blocks", Uuid::nil(), Uuid::nil())
+            .unwrap();
+
+        assert_eq!(
+            updated_app.get_relationship_variant(0).unwrap().to_string(),
+            "blocks: Directed"
+        );
+    }
+
+    #[test]
+    fn change_to_custom_variant() {
+        let test_app = create_app_with_blocks_variant()
+            .append_related_relationship(Uuid::nil(), Uuid::nil())
+            .unwrap();
+
+        let updated_app = test_app.change_relationship_variant(0, "blocker").unwrap();
+
+        assert_eq!(
+            updated_app.get_relationship_variant(0).unwrap(),
+            RelationshipVariant::create_custom("blocks", EdgeDirectionality::Directed)
+        );
+    }
+
+    #[test]
+    fn filter_by_custom_variant() {
+        let test_app = create_app_with_blocks_variant()
+            .append_related_relationship(Uuid::nil(), Uuid::nil())
+            .unwrap()
+            .append_new_relationship("blocks", Uuid::nil(), Uuid::nil())
+            .unwrap();
+
+        let filtered_list = test_app.filter_by_variant("blocks").unwrap();
+
+        assert_eq!(filtered_list.len(), 1);
+        assert_eq!(filtered_list[0], test_app.relationship_list[1]);
+    }
+
+    #[test]
+    fn get_inverse_label_for_custom_variant() {
+        let test_app = create_app_with_blocks_variant();
+
+        let inverse_label =
+            test_app.get_inverse_label(&test_app.parse_relationship_variant("blocks").unwrap());
+
+        assert_eq!(inverse_label, "blocked by");
+    }
+
+    #[test]
+    fn get_descendant_ids() {
+        let (grandparent, parent, child) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let test_app = ClearHeadApp::default()
+            .append_parental_relationship(grandparent, parent)
+            .unwrap()
+            .append_parental_relationship(parent, child)
+            .unwrap()
+            .append_sequential_relationship(child, Uuid::new_v4())
+            .unwrap();
+
+        let descendant_ids = test_app.get_descendant_ids(grandparent);
+
+        assert_eq!(descendant_ids, im::vector![parent, child]);
+    }
+
+    #[test]
+    fn get_descendant_ids_with_cycle() {
+        let (first, second) = (Uuid::new_v4(), Uuid::new_v4());
+        let test_app = ClearHeadApp::default()
+            .append_parental_relationship(first, second)
+            .unwrap()
+            .append_parental_relationship(second, first)
+            .unwrap();
+
+        let descendant_ids = test_app.get_descendant_ids(first);
+
+        assert_eq!(descendant_ids, Vector::unit(second));
+    }
+
+    #[test]
+    fn remove_unused_custom_variant() {
+        let test_app = create_app_with_blocks_variant();
+
+        let updated_app = test_app.remove_custom_variant("blocks").unwrap();
+
+        assert!(updated_app.custom_variants.is_empty());
+    }
+
+    #[test]
+    fn failed_remove_custom_variant_in_use() {
+        let test_app = create_app_with_blocks_variant()
+            .append_new_relationship("blocks", Uuid::nil(), Uuid::nil())
+            .unwrap();
+
+        let usage_error = test_app.remove_custom_variant("blocks").unwrap_err();
+
+        assert_eq!(usage_error.to_string(), "blocks is still used by 1 relationship(s)");
+    }
+
+    #[test]
+    fn failed_remove_unregistered_variant() {
+        let test_app = ClearHeadApp::default();
+
+        let missing_error = test_app.remove_custom_variant("blocks").unwrap_err();
+
+        assert_eq!(missing_error.to_string(), "blocks is not a registered relationship variant");
+    }
+
+    fn create_named_relationship_app() -> ClearHeadApp {
+        ClearHeadApp::default()
+            .append_default_action()
+            .rename_action(0, "Project".to_string())
+            .unwrap()
+            .append_default_action()
+            .rename_action(1, "Design".to_string())
+            .unwrap()
+            .append_default_action()
+            .rename_action(2, "Inbox".to_string())
+            .unwrap()
+            .create_action_relationship("parental", 0, 1)
+            .unwrap()
+            .create_action_relationship("related", 1, 2)
+            .unwrap()
+            .set_relationship_label(1, Some("same client"))
+            .unwrap()
+            .create_action_relationship("sequential", 0, 2)
+            .unwrap()
+    }
+
+    #[test]
+    fn get_participant_name_falls_back_to_id() {
+        let test_app = create_named_relationship_app();
+
+        assert_eq!(test_app.get_participant_name(test_app.action_list[1].get_id()), "Design");
+        assert_eq!(test_app.get_participant_name(Uuid::nil()), Uuid::nil().to_string());
+    }
+
+    #[test]
+    fn filter_relationship_indices_by_variant_and_participant() {
+        let test_app = create_named_relationship_app();
+        let project_id = test_app.action_list[0].get_id();
+        let inbox_id = test_app.action_list[2].get_id();
+
+        assert_eq!(test_app.filter_relationship_indices(None, None, "either").unwrap(), vec![0, 1, 2]);
+        assert_eq!(test_app.filter_relationship_indices(Some("related"), None, "either").unwrap(), vec![1]);
+        assert_eq!(test_app.filter_relationship_indices(None, Some(project_id), "p1").unwrap(), vec![0, 2]);
+        assert_eq!(
+            test_app.filter_relationship_indices(Some("sequential"), Some(inbox_id), "either").unwrap(),
+            vec![2]
+        );
+    }
+
+    #[test]
+    fn failed_filter_relationship_indices() {
+        let test_app = create_named_relationship_app();
+
+        let list_error = test_app.filter_relationship_indices(None, Some(Uuid::nil()), "p3").unwrap_err();
+        let variant_error = test_app.filter_relationship_indices(Some("blocks"), None, "either").unwrap_err();
+
+        assert_eq!(list_error.to_string(), "Invalid List Name");
+        assert_eq!(variant_error.to_string(), "invalid relationship variant");
+    }
+
+    #[test]
+    fn print_relationship_overview() {
+        let test_app = create_named_relationship_app();
+
+        let overview = test_app.get_relationship_overview(&[1, 2]).unwrap();
+
+        assert_eq!(
+            overview,
+            indoc::indoc!(
+                "
+                +-------+----------------------+---------+-------+-------------+
+                |                        Relationships                         |
+                +-------+----------------------+---------+-------+-------------+
+                | Index | Variant              | From    | To    | Label       |
+                +-------+----------------------+---------+-------+-------------+
+                | 1     | Related: Undirected  | Design  | Inbox | same client |
+                +-------+----------------------+---------+-------+-------------+
+                | 2     | Sequential: Directed | Project | Inbox |             |
+                +-------+----------------------+---------+-------+-------------+"
+            )
+        );
+    }
+
+    #[test]
+    fn print_relationship_details() {
+        let test_app = create_named_relationship_app()
+            .set_relationship_created(1, None)
+            .unwrap();
+
+        let details = test_app.get_relationship_details(1).unwrap();
+
+        assert_eq!(
+            details,
+            format!(
+                "Variant: Related: Undirected\nFrom: Design\nTo: Inbox\nLabel: same client\nWeight: None\nCreated: None\nId: {}\n",
+                test_app.get_relationship_id(1).unwrap()
+            )
+        );
+    }
+
+    #[test]
+    fn failed_relationship_details() {
+        let test_app = ClearHeadApp::default();
+
+        let index_error = test_app.get_relationship_details(0).unwrap_err();
+
+        assert_eq!(index_error.to_string(), failed_relationship_index_error());
+    }
 }
+
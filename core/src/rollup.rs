@@ -0,0 +1,271 @@
+use crate::critical_path::DEFAULT_DURATION;
+use crate::functionality::ClearHeadApp;
+use crate::relationship::{RelationshipIndex, RelationshipVariant};
+use crate::relationship_implementation::collect_descendant_ids;
+use crate::ActionManipulation;
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, PartialEq, Eq, Default, Clone, Copy, Serialize, Deserialize)]
+pub struct RollupSettings {
+    #[serde(default)]
+    pub auto_complete_parents: bool,
+    #[serde(default)]
+    pub weight_by_estimate: bool,
+}
+
+#[derive(Debug, PartialEq, Eq, Default, Clone, Copy)]
+pub struct Progress {
+    pub completed: u32,
+    pub total: u32,
+}
+
+impl RollupSettings {
+    pub fn is_default(&self) -> bool {
+        *self == RollupSettings::default()
+    }
+
+    pub fn set_rollup_option(&self, name: &str, enabled: bool) -> Result<RollupSettings, Box<dyn Error>> {
+        let mut updated_settings = *self;
+
+        match name.trim().to_lowercase().replace('-', "_").as_str() {
+            "auto_complete_parents" => updated_settings.auto_complete_parents = enabled,
+            "weight_by_estimate" => updated_settings.weight_by_estimate = enabled,
+            _ => return Err(format!("{} is not a rollup option", name).into()),
+        }
+
+        Ok(updated_settings)
+    }
+}
+
+impl Progress {
+    pub fn get_percentage(&self) -> u32 {
+        match self.total {
+            0 => 0,
+            total => ((self.completed as f64 / total as f64) * 100.0).round() as u32,
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.total > 0 && self.completed == self.total
+    }
+}
+
+impl Display for Progress {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}/{} ({}%)", self.completed, self.total, self.get_percentage())
+    }
+}
+
+// (weight, completed) for every Action, keyed by id
+pub(crate) type ProgressWeights = HashMap<Uuid, (u32, bool)>;
+
+pub(crate) fn get_indexed_progress(
+    relationship_index: &RelationshipIndex,
+    weights: &ProgressWeights,
+    id: Uuid,
+) -> Progress {
+    collect_descendant_ids(relationship_index, id)
+        .iter()
+        .filter_map(|descendant_id| weights.get(descendant_id))
+        .fold(Progress::default(), |progress, (weight, completed)| Progress {
+            completed: progress.completed + if *completed { *weight } else { 0 },
+            total: progress.total + weight,
+        })
+}
+
+impl ClearHeadApp {
+    pub fn set_rollup_option(&self, name: &str, enabled: bool) -> Result<ClearHeadApp, Box<dyn Error>> {
+        let mut updated_app = self.clone();
+        updated_app.rollup = self.rollup.set_rollup_option(name, enabled)?;
+
+        Ok(updated_app)
+    }
+
+    pub(crate) fn get_progress_weights(&self) -> ProgressWeights {
+        self.action_list
+            .iter()
+            .map(|action| {
+                let weight = match self.rollup.weight_by_estimate {
+                    true => action.get_estimate().unwrap_or(DEFAULT_DURATION),
+                    false => 1,
+                };
+                (action.get_id(), (weight, action.get_completion_status()))
+            })
+            .collect()
+    }
+
+    pub fn get_progress(&self, id: Uuid) -> Progress {
+        get_indexed_progress(&self.get_relationship_index(), &self.get_progress_weights(), id)
+    }
+
+    pub fn get_action_progress(&self, index: usize) -> Result<Progress, Box<dyn Error>> {
+        Ok(self.get_progress(self.get_action_id(index)?))
+    }
+
+    pub(crate) fn roll_up_completion(&self, changed_id: Uuid) -> ClearHeadApp {
+        let mut updated_app = self.clone();
+        if !self.rollup.auto_complete_parents {
+            return updated_app;
+        }
+
+        let relationship_index = self.get_relationship_index();
+        let parental_variant = RelationshipVariant::create_parental();
+        let positions: HashMap<Uuid, usize> = self
+            .action_list
+            .iter()
+            .enumerate()
+            .map(|(index, action)| (action.get_id(), index))
+            .collect();
+        let mut updated_ids: HashSet<Uuid> = HashSet::from([changed_id]);
+        let mut pending_parents: VecDeque<Uuid> = relationship_index
+            .get_incoming_ids(changed_id, &parental_variant)
+            .into_iter()
+            .collect();
+
+        while let Some(parent_id) = pending_parents.pop_front() {
+            let parent_index = match positions.get(&parent_id) {
+                Some(index) => *index,
+                None => continue,
+            };
+            let children_finished = relationship_index
+                .get_outgoing_ids(parent_id, &parental_variant)
+                .iter()
+                .filter_map(|child_id| positions.get(child_id))
+                .all(|child_index| updated_app.action_list[*child_index].get_completion_status());
+            let parent = &updated_app.action_list[parent_index];
+
+            if parent.get_completion_status() != children_finished && updated_ids.insert(parent_id) {
+                let toggled_parent = parent.toggle_completion_status();
+                updated_app.action_list.set(parent_index, toggled_parent);
+                pending_parents.extend(relationship_index.get_incoming_ids(parent_id, &parental_variant));
+            }
+        }
+
+        updated_app
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_app_with_project_tree() -> ClearHeadApp {
+        ClearHeadApp::default()
+            .append_default_action()
+            .rename_action(0, "Project".to_string())
+            .unwrap()
+            .append_default_action()
+            .rename_action(1, "Design".to_string())
+            .unwrap()
+            .append_default_action()
+            .rename_action(2, "Sketch".to_string())
+            .unwrap()
+            .append_default_action()
+            .rename_action(3, "Build".to_string())
+            .unwrap()
+            .change_action_estimate(2, Some(2))
+            .unwrap()
+            .change_action_estimate(3, Some(6))
+            .unwrap()
+            .create_action_relationship("parental", 0, 1)
+            .unwrap()
+            .create_action_relationship("parental", 1, 2)
+            .unwrap()
+            .create_action_relationship("parental", 0, 3)
+            .unwrap()
+    }
+
+    #[test]
+    fn leaf_action_has_no_progress() {
+        let test_app = create_app_with_project_tree();
+
+        let progress = test_app.get_action_progress(3).unwrap();
+
+        assert_eq!(progress, Progress::default());
+        assert_eq!(progress.to_string(), "0/0 (0%)");
+    }
+
+    #[test]
+    fn count_completed_descendants() {
+        let test_app = create_app_with_project_tree()
+            .toggle_action_completion_status(2)
+            .unwrap();
+
+        let progress = test_app.get_action_progress(0).unwrap();
+
+        assert_eq!(progress, Progress { completed: 1, total: 3 });
+        assert_eq!(progress.to_string(), "1/3 (33%)");
+    }
+
+    #[test]
+    fn weight_progress_by_estimate() {
+        let test_app = create_app_with_project_tree()
+            .set_rollup_option("weight-by-estimate", true)
+            .unwrap()
+            .toggle_action_completion_status(2)
+            .unwrap();
+
+        let progress = test_app.get_action_progress(0).unwrap();
+
+        assert_eq!(progress, Progress { completed: 2, total: 9 });
+        assert_eq!(progress.get_percentage(), 22);
+    }
+
+    #[test]
+    fn failed_set_unknown_rollup_option() {
+        let test_app = ClearHeadApp::default();
+
+        let rollup_error = test_app.set_rollup_option("auto_archive", true).unwrap_err();
+
+        assert_eq!(rollup_error.to_string(), "auto_archive is not a rollup option");
+    }
+
+    #[test]
+    fn completion_is_independent_by_default() {
+        let test_app = create_app_with_project_tree()
+            .toggle_action_completion_status(2)
+            .unwrap();
+
+        assert!(!test_app.get_action_completion_status(1).unwrap());
+    }
+
+    #[test]
+    fn auto_complete_parents_when_children_finish() {
+        let test_app = create_app_with_project_tree()
+            .set_rollup_option("auto_complete_parents", true)
+            .unwrap()
+            .toggle_action_completion_status(2)
+            .unwrap();
+
+        assert!(test_app.get_action_completion_status(1).unwrap());
+        assert!(!test_app.get_action_completion_status(0).unwrap());
+
+        let updated_app = test_app.toggle_action_completion_status(3).unwrap();
+
+        assert!(updated_app.get_action_completion_status(0).unwrap());
+        assert!(updated_app.get_action_progress(0).unwrap().is_finished());
+    }
+
+    #[test]
+    fn reopen_parents_when_child_reopens() {
+        let test_app = create_app_with_project_tree()
+            .set_rollup_option("auto_complete_parents", true)
+            .unwrap()
+            .toggle_action_completion_status(2)
+            .unwrap()
+            .toggle_action_completion_status(3)
+            .unwrap();
+
+        let updated_app = test_app.toggle_action_completion_status(2).unwrap();
+
+        assert!(!updated_app.get_action_completion_status(1).unwrap());
+        assert!(!updated_app.get_action_completion_status(0).unwrap());
+        assert!(updated_app.get_action_completion_status(3).unwrap());
+    }
+}
@@ -0,0 +1,403 @@
+use crate::action::Action;
+use crate::functionality::ClearHeadApp;
+use crate::relationship::{Relationship, RelationshipVariant};
+use crate::storage::StorageError;
+use crate::ActionManipulation;
+
+use std::error::Error;
+use std::path::Path;
+
+use chrono::{DateTime, NaiveDate, Utc};
+use im::Vector;
+use rusqlite::{params, Connection, OptionalExtension, Transaction, TransactionBehavior};
+use uuid::Uuid;
+
+pub const SQLITE_SCHEMA_VERSION: i64 = 1;
+
+const SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS actions (
+        id TEXT PRIMARY KEY NOT NULL,
+        position INTEGER NOT NULL,
+        name TEXT NOT NULL,
+        priority TEXT NOT NULL,
+        completed INTEGER NOT NULL,
+        estimate INTEGER,
+        due_date TEXT
+    );
+    CREATE TABLE IF NOT EXISTS relationships (
+        id TEXT PRIMARY KEY NOT NULL,
+        position INTEGER NOT NULL,
+        variant TEXT NOT NULL,
+        participant_1 TEXT NOT NULL,
+        participant_2 TEXT NOT NULL,
+        label TEXT,
+        weight REAL,
+        created TEXT
+    );
+    CREATE INDEX IF NOT EXISTS relationships_participant_1 ON relationships (participant_1);
+    CREATE INDEX IF NOT EXISTS relationships_participant_2 ON relationships (participant_2);
+    CREATE TABLE IF NOT EXISTS settings (
+        key TEXT PRIMARY KEY NOT NULL,
+        value TEXT NOT NULL
+    );
+";
+
+pub trait SQLiteStorage {
+    fn write_to_sqlite(&self, database_path: &Path) -> Result<(), Box<dyn Error>>;
+    fn write_to_sqlite_if_unchanged(&self, database_path: &Path) -> Result<ClearHeadApp, Box<dyn Error>>;
+    fn read_from_sqlite(database_path: &Path) -> Result<ClearHeadApp, Box<dyn Error>>;
+    fn migrate_json_to_sqlite(json_path: &Path, database_path: &Path) -> Result<ClearHeadApp, Box<dyn Error>>;
+}
+
+impl SQLiteStorage for ClearHeadApp {
+    fn write_to_sqlite(&self, database_path: &Path) -> Result<(), Box<dyn Error>> {
+        let mut connection = open_database(database_path)?;
+        let transaction = connection.transaction_with_behavior(TransactionBehavior::Immediate)?;
+
+        write_app(&transaction, self)?;
+
+        Ok(transaction.commit()?)
+    }
+
+    fn write_to_sqlite_if_unchanged(&self, database_path: &Path) -> Result<ClearHeadApp, Box<dyn Error>> {
+        let mut connection = open_database(database_path)?;
+        let transaction = connection.transaction_with_behavior(TransactionBehavior::Immediate)?;
+
+        let stored_revision = read_setting(&transaction, "revision")?.unwrap_or(0);
+        if stored_revision != self.revision {
+            return Err(StorageError::RevisionConflict {
+                expected: self.revision,
+                found: stored_revision,
+            }
+            .into());
+        }
+
+        let mut updated_app = self.clone();
+        updated_app.revision += 1;
+        write_app(&transaction, &updated_app)?;
+        transaction.commit()?;
+
+        Ok(updated_app)
+    }
+
+    fn read_from_sqlite(database_path: &Path) -> Result<ClearHeadApp, Box<dyn Error>> {
+        let connection = open_database(database_path)?;
+
+        Ok(ClearHeadApp {
+            action_list: read_actions(&connection)?,
+            relationship_list: read_relationships(&connection)?,
+            custom_variants: read_setting(&connection, "custom_variants")?.unwrap_or_default(),
+            constraints: read_setting(&connection, "constraints")?.unwrap_or_default(),
+            rollup: read_setting(&connection, "rollup")?.unwrap_or_default(),
+            revision: read_setting(&connection, "revision")?.unwrap_or_default(),
+            ..Default::default()
+        })
+    }
+
+    fn migrate_json_to_sqlite(json_path: &Path, database_path: &Path) -> Result<ClearHeadApp, Box<dyn Error>> {
+        if database_path.exists() {
+            return Err(format!("{} already exists, refusing to overwrite it", database_path.display()).into());
+        }
+
+        let app = <ClearHeadApp as crate::JSONStorage>::read_from_json(json_path)?;
+        app.write_to_sqlite(database_path)?;
+
+        Ok(app)
+    }
+}
+
+fn open_database(database_path: &Path) -> Result<Connection, Box<dyn Error>> {
+    let connection = Connection::open(database_path)?;
+    connection.busy_timeout(crate::lock::DEFAULT_LOCK_TIMEOUT)?;
+
+    let schema_version: i64 = connection.query_row("PRAGMA user_version", [], |row| row.get(0))?;
+    if schema_version > SQLITE_SCHEMA_VERSION {
+        return Err(format!(
+            "Database uses schema version {}, but this build only supports up to version {}",
+            schema_version, SQLITE_SCHEMA_VERSION
+        )
+        .into());
+    }
+    connection.execute_batch(SCHEMA)?;
+    connection.pragma_update(None, "user_version", SQLITE_SCHEMA_VERSION)?;
+
+    Ok(connection)
+}
+
+fn write_app(transaction: &Transaction, app: &ClearHeadApp) -> Result<(), Box<dyn Error>> {
+    transaction.execute("CREATE TEMP TABLE IF NOT EXISTS kept_ids (id TEXT PRIMARY KEY NOT NULL)", [])?;
+    transaction.execute("DELETE FROM kept_ids", [])?;
+
+    {
+        let mut upsert_action = transaction.prepare(
+            "INSERT INTO actions (id, position, name, priority, completed, estimate, due_date)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+             ON CONFLICT (id) DO UPDATE SET position = excluded.position, name = excluded.name,
+                priority = excluded.priority, completed = excluded.completed,
+                estimate = excluded.estimate, due_date = excluded.due_date",
+        )?;
+        let mut keep_id = transaction.prepare("INSERT OR IGNORE INTO kept_ids (id) VALUES (?1)")?;
+        for (position, action) in app.action_list.iter().enumerate() {
+            upsert_action.execute(params![
+                action.get_id().to_string(),
+                position as i64,
+                action.get_name(),
+                action.get_priority(),
+                action.get_completion_status(),
+                action.get_estimate(),
+                action.get_due_date().map(|due_date| due_date.to_string()),
+            ])?;
+            keep_id.execute(params![action.get_id().to_string()])?;
+        }
+    }
+    transaction.execute("DELETE FROM actions WHERE id NOT IN (SELECT id FROM kept_ids)", [])?;
+    transaction.execute("DELETE FROM kept_ids", [])?;
+
+    {
+        let mut upsert_relationship = transaction.prepare(
+            "INSERT INTO relationships (id, position, variant, participant_1, participant_2, label, weight, created)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+             ON CONFLICT (id) DO UPDATE SET position = excluded.position, variant = excluded.variant,
+                participant_1 = excluded.participant_1, participant_2 = excluded.participant_2,
+                label = excluded.label, weight = excluded.weight, created = excluded.created",
+        )?;
+        let mut keep_id = transaction.prepare("INSERT OR IGNORE INTO kept_ids (id) VALUES (?1)")?;
+        for (position, relationship) in app.relationship_list.iter().enumerate() {
+            upsert_relationship.execute(params![
+                relationship.get_id().to_string(),
+                position as i64,
+                serde_json::to_string(&relationship.get_variant())?,
+                relationship.get_participant_1().to_string(),
+                relationship.get_participant_2().to_string(),
+                relationship.get_label(),
+                relationship.get_weight(),
+                relationship.get_created().map(|created| created.to_rfc3339()),
+            ])?;
+            keep_id.execute(params![relationship.get_id().to_string()])?;
+        }
+    }
+    transaction.execute("DELETE FROM relationships WHERE id NOT IN (SELECT id FROM kept_ids)", [])?;
+
+    write_setting(transaction, "custom_variants", &app.custom_variants)?;
+    write_setting(transaction, "constraints", &app.constraints)?;
+    write_setting(transaction, "rollup", &app.rollup)?;
+    write_setting(transaction, "revision", &app.revision)?;
+
+    Ok(())
+}
+
+fn write_setting<T: serde::Serialize>(
+    connection: &Connection,
+    key: &str,
+    value: &T,
+) -> Result<(), Box<dyn Error>> {
+    connection.execute(
+        "INSERT INTO settings (key, value) VALUES (?1, ?2)
+         ON CONFLICT (key) DO UPDATE SET value = excluded.value",
+        params![key, serde_json::to_string(value)?],
+    )?;
+
+    Ok(())
+}
+
+fn read_setting<T: serde::de::DeserializeOwned>(
+    connection: &Connection,
+    key: &str,
+) -> Result<Option<T>, Box<dyn Error>> {
+    let value: Option<String> = connection
+        .query_row("SELECT value FROM settings WHERE key = ?1", params![key], |row| row.get(0))
+        .optional()?;
+
+    match value {
+        Some(value) => Ok(Some(serde_json::from_str(&value)?)),
+        None => Ok(None),
+    }
+}
+
+fn parse_id(id: &str) -> Result<Uuid, Box<dyn Error>> {
+    Uuid::parse_str(id).map_err(|_| format!("{} is an Invalid Id", id).into())
+}
+
+fn read_actions(connection: &Connection) -> Result<Vector<Action>, Box<dyn Error>> {
+    let mut statement = connection.prepare(
+        "SELECT id, name, priority, completed, estimate, due_date FROM actions ORDER BY position",
+    )?;
+    let mut rows = statement.query([])?;
+
+    let mut action_list = Vector::new();
+    while let Some(row) = rows.next()? {
+        let id: String = row.get(0)?;
+        let name: String = row.get(1)?;
+        let priority: String = row.get(2)?;
+        let completed: bool = row.get(3)?;
+        let estimate: Option<u32> = row.get(4)?;
+        let due_date: Option<String> = row.get(5)?;
+
+        let mut action = Action::default()
+            .set_id(parse_id(&id)?)
+            .rename(&name)
+            .change_priority(&priority)?
+            .set_estimate(estimate);
+        if completed {
+            action = action.toggle_completion_status();
+        }
+        if let Some(due_date) = due_date {
+            action = action.set_due_date(Some(due_date.parse::<NaiveDate>()?));
+        }
+
+        action_list.push_back(action);
+    }
+
+    Ok(action_list)
+}
+
+fn read_relationships(connection: &Connection) -> Result<Vector<Relationship>, Box<dyn Error>> {
+    let mut statement = connection.prepare(
+        "SELECT id, variant, participant_1, participant_2, label, weight, created
+         FROM relationships ORDER BY position",
+    )?;
+    let mut rows = statement.query([])?;
+
+    let mut relationship_list = Vector::new();
+    while let Some(row) = rows.next()? {
+        let id: String = row.get(0)?;
+        let variant: String = row.get(1)?;
+        let participant_1: String = row.get(2)?;
+        let participant_2: String = row.get(3)?;
+        let label: Option<String> = row.get(4)?;
+        let weight: Option<f64> = row.get(5)?;
+        let created: Option<String> = row.get(6)?;
+
+        let created = match created {
+            Some(created) => Some(DateTime::parse_from_rfc3339(&created)?.with_timezone(&Utc)),
+            None => None,
+        };
+        let relationship = Relationship::create_new_with_variant(
+            serde_json::from_str::<RelationshipVariant>(&variant)?,
+            parse_id(&participant_1)?,
+            parse_id(&participant_2)?,
+        )
+        .set_id(parse_id(&id)?)
+        .set_label(label.as_deref())
+        .set_weight(weight)?
+        .set_created(created);
+
+        relationship_list.push_back(relationship);
+    }
+
+    Ok(relationship_list)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::functionality::tests::create_temporary_directory;
+    use crate::JSONStorage;
+
+    fn create_populated_app() -> ClearHeadApp {
+        ClearHeadApp::default()
+            .append_default_action()
+            .rename_action(0, "Paint Fence".to_string())
+            .unwrap()
+            .append_default_action()
+            .change_action_priority(1, "high".to_string())
+            .unwrap()
+            .change_action_estimate(1, Some(3))
+            .unwrap()
+            .change_action_due_date(1, Some(NaiveDate::from_ymd_opt(2026, 10, 30).unwrap()))
+            .unwrap()
+            .toggle_action_completion_status(1)
+            .unwrap()
+            .register_custom_variant("blocks", "directed", Some("blocked by"), Vector::new())
+            .unwrap()
+            .create_action_relationship("blocks", 0, 1)
+            .unwrap()
+            .create_action_relationship("parental", 1, 0)
+            .unwrap()
+            .set_graph_constraint("single_parent", true)
+            .unwrap()
+    }
+
+    #[test]
+    fn write_and_read_database() {
+        let database_path = create_temporary_directory("sqlite_round_trip").join("app.db");
+        let test_app = create_populated_app();
+
+        test_app.write_to_sqlite(&database_path).unwrap();
+
+        assert_eq!(ClearHeadApp::read_from_sqlite(&database_path).unwrap(), test_app);
+    }
+
+    #[test]
+    fn rewrite_removes_deleted_rows() {
+        let database_path = create_temporary_directory("sqlite_remove_rows").join("app.db");
+        let test_app = create_populated_app();
+        test_app.write_to_sqlite(&database_path).unwrap();
+
+        let updated_app = test_app.remove_subtree(1).unwrap();
+        updated_app.write_to_sqlite(&database_path).unwrap();
+
+        let stored_app = ClearHeadApp::read_from_sqlite(&database_path).unwrap();
+        assert_eq!(stored_app, updated_app);
+        assert!(stored_app.relationship_list.is_empty());
+    }
+
+    #[test]
+    fn index_relationship_participants() {
+        let database_path = create_temporary_directory("sqlite_indexes").join("app.db");
+        create_populated_app().write_to_sqlite(&database_path).unwrap();
+
+        let connection = Connection::open(&database_path).unwrap();
+        let index_names: Vec<String> = connection
+            .prepare("SELECT name FROM sqlite_master WHERE type = 'index' AND tbl_name = 'relationships' AND sql IS NOT NULL ORDER BY name")
+            .unwrap()
+            .query_map([], |row| row.get(0))
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        assert_eq!(index_names, vec!["relationships_participant_1", "relationships_participant_2"]);
+    }
+
+    #[test]
+    fn failed_checked_write_after_concurrent_change() {
+        let database_path = create_temporary_directory("sqlite_revision_conflict").join("app.db");
+        let stored_app = ClearHeadApp::default().write_to_sqlite_if_unchanged(&database_path).unwrap();
+
+        stored_app.append_default_action().write_to_sqlite_if_unchanged(&database_path).unwrap();
+        let conflict_error = stored_app.write_to_sqlite_if_unchanged(&database_path).unwrap_err();
+
+        assert_eq!(
+            conflict_error.to_string(),
+            "Data file changed while this command ran (expected revision 1, found 2), please retry"
+        );
+    }
+
+    #[test]
+    fn migrate_json_file_to_database() {
+        let database_path = create_temporary_directory("sqlite_migration").join("app.db");
+        let json_path = database_path.with_file_name("app.json");
+        let test_app = create_populated_app();
+        test_app.write_to_json(&json_path, false).unwrap();
+
+        let migrated_app = ClearHeadApp::migrate_json_to_sqlite(&json_path, &database_path).unwrap();
+
+        assert_eq!(migrated_app, test_app);
+        assert_eq!(ClearHeadApp::read_from_sqlite(&database_path).unwrap(), test_app);
+    }
+
+    #[test]
+    fn failed_migrate_into_existing_database() {
+        let database_path = create_temporary_directory("sqlite_existing_migration").join("app.db");
+        let json_path = database_path.with_file_name("app.json");
+        ClearHeadApp::default().write_to_json(&json_path, false).unwrap();
+        ClearHeadApp::default().write_to_sqlite(&database_path).unwrap();
+
+        let migration_error = ClearHeadApp::migrate_json_to_sqlite(&json_path, &database_path).unwrap_err();
+
+        assert_eq!(
+            migration_error.to_string(),
+            format!("{} already exists, refusing to overwrite it", database_path.display())
+        );
+    }
+}
@@ -1,44 +1,215 @@
-use std::fs::File;
+use std::fs::{self, File, OpenOptions};
 use crate::functionality::ClearHeadApp;
+use crate::migration::{load_document, VersionedDocument};
 
+use std::fmt::{self, Display, Formatter};
 use std::io::{BufReader, BufWriter, Write};
+use std::path::PathBuf;
 use std::{error::Error, path::Path};
 
+use serde::de::DeserializeOwned;
+use serde::Serialize;
 use serde_json;
+use serde_json::Value;
+
+pub const DEFAULT_BACKUP_COUNT: usize = 5;
 
 pub trait JSONStorage {
     fn write_to_json(&self, file_path: &Path, pretty_print: bool) -> Result<(), Box<dyn Error>>;
+    fn write_to_json_with_backups(
+        &self,
+        file_path: &Path,
+        pretty_print: bool,
+        backup_count: usize,
+    ) -> Result<(), Box<dyn Error>>;
+    fn write_to_json_if_unchanged(
+        &self,
+        file_path: &Path,
+        pretty_print: bool,
+        backup_count: usize,
+    ) -> Result<ClearHeadApp, Box<dyn Error>>;
     fn read_from_json(file_path: &Path) -> Result<ClearHeadApp, Box<dyn Error>>;
+    fn restore_backup(file_path: &Path, generation: usize, backup_count: usize) -> Result<ClearHeadApp, Box<dyn Error>>;
 }
 
 impl JSONStorage for ClearHeadApp {
     fn write_to_json(&self, file_path: &Path, pretty_print: bool) -> Result<(), Box<dyn Error>> {
-        let file = File::create(file_path)?;
-        let mut file_writer = BufWriter::new(file);
+        self.write_to_json_with_backups(file_path, pretty_print, 0)
+    }
+
+    fn write_to_json_with_backups(
+        &self,
+        file_path: &Path,
+        pretty_print: bool,
+        backup_count: usize,
+    ) -> Result<(), Box<dyn Error>> {
+        write_json_file(&VersionedDocument::new(self), file_path, pretty_print, backup_count)
+    }
+
+    fn write_to_json_if_unchanged(
+        &self,
+        file_path: &Path,
+        pretty_print: bool,
+        backup_count: usize,
+    ) -> Result<ClearHeadApp, Box<dyn Error>> {
+        if file_path.exists() {
+            let stored_revision = ClearHeadApp::read_from_json(file_path)?.revision;
+            if stored_revision != self.revision {
+                return Err(StorageError::RevisionConflict {
+                    expected: self.revision,
+                    found: stored_revision,
+                }
+                .into());
+            }
+        }
+
+        let mut updated_app = self.clone();
+        updated_app.revision += 1;
+        updated_app.write_to_json_with_backups(file_path, pretty_print, backup_count)?;
+
+        Ok(updated_app)
+    }
+
+    fn read_from_json(file_path: &Path) -> Result<ClearHeadApp, Box<dyn Error>> {
+        load_document(read_json_file::<Value>(file_path)?)
+    }
+
+    fn restore_backup(file_path: &Path, generation: usize, backup_count: usize) -> Result<ClearHeadApp, Box<dyn Error>> {
+        let backup_path = get_backup_path(file_path, generation);
+        if generation == 0 || !backup_path.exists() {
+            return Err(format!("No Backup {} for {}", generation, file_path.display()).into());
+        }
+
+        let restored_app = ClearHeadApp::read_from_json(&backup_path)?;
+        let backup_contents = fs::read(&backup_path)?;
+        write_atomically(file_path, backup_count, |file_writer| {
+            file_writer.write_all(&backup_contents)?;
+            Ok(())
+        })?;
+
+        Ok(restored_app)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum StorageError {
+    RevisionConflict { expected: u64, found: u64 },
+}
+
+impl Display for StorageError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            StorageError::RevisionConflict { expected, found } => write!(
+                f,
+                "Data file changed while this command ran (expected revision {}, found {}), please retry",
+                expected, found
+            ),
+        }
+    }
+}
+
+impl Error for StorageError {}
 
-        if pretty_print == true {
-            serde_json::to_writer_pretty(&mut file_writer, &self)?;
-            file_writer.flush()?;
+pub fn write_json_file<T: Serialize + ?Sized>(
+    value: &T,
+    file_path: &Path,
+    pretty_print: bool,
+    backup_count: usize,
+) -> Result<(), Box<dyn Error>> {
+    write_atomically(file_path, backup_count, |file_writer| {
+        if pretty_print {
+            serde_json::to_writer_pretty(file_writer, value)?;
         } else {
-            serde_json::to_writer(&mut file_writer, &self)?;
+            serde_json::to_writer(file_writer, value)?;
         }
+        Ok(())
+    })
+}
+
+pub fn read_json_file<T: DeserializeOwned>(file_path: &Path) -> Result<T, Box<dyn Error>> {
+    let file = File::open(file_path)?;
+    let file_reader = BufReader::new(file);
+
+    Ok(serde_json::from_reader(file_reader)?)
+}
+
+pub fn get_backup_path(file_path: &Path, generation: usize) -> PathBuf {
+    let mut backup_name = file_path.file_name().unwrap_or_default().to_os_string();
+    backup_name.push(format!(".bak.{}", generation));
+
+    file_path.with_file_name(backup_name)
+}
+
+pub fn list_backups(file_path: &Path) -> Vec<PathBuf> {
+    (1..)
+        .map(|generation| get_backup_path(file_path, generation))
+        .take_while(|backup_path| backup_path.exists())
+        .collect()
+}
 
+pub fn write_atomically(
+    file_path: &Path,
+    backup_count: usize,
+    write_contents: impl FnOnce(&mut BufWriter<File>) -> Result<(), Box<dyn Error>>,
+) -> Result<(), Box<dyn Error>> {
+    let mut temporary_name = file_path.file_name().unwrap_or_default().to_os_string();
+    temporary_name.push(".tmp");
+    let temporary_path = file_path.with_file_name(temporary_name);
+
+    let result = (|| -> Result<(), Box<dyn Error>> {
+        let temporary_file = OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(&temporary_path)?;
+        let mut file_writer = BufWriter::new(temporary_file);
+
+        write_contents(&mut file_writer)?;
+        file_writer.flush()?;
+        file_writer.get_ref().sync_all()?;
+
+        rotate_backups(file_path, backup_count)?;
+        fs::rename(&temporary_path, file_path)?;
+
+        if let Some(directory) = file_path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+            if let Ok(directory) = File::open(directory) {
+                let _ = directory.sync_all();
+            }
+        }
         Ok(())
+    })();
+
+    if result.is_err() {
+        let _ = fs::remove_file(&temporary_path);
     }
 
-    fn read_from_json(file_path: &Path) -> Result<ClearHeadApp, Box<dyn Error>> {
-        let file = File::open(file_path)?;
-        let file_reader = BufReader::new(file);
+    result
+}
 
-        let new_list = serde_json::from_reader(file_reader)?;
+fn rotate_backups(file_path: &Path, backup_count: usize) -> Result<(), Box<dyn Error>> {
+    if backup_count == 0 || !file_path.exists() {
+        return Ok(());
+    }
 
-        Ok(new_list)
+    let oldest_backup = get_backup_path(file_path, backup_count);
+    if oldest_backup.exists() {
+        fs::remove_file(&oldest_backup)?;
+    }
+    for generation in (1..backup_count).rev() {
+        let backup_path = get_backup_path(file_path, generation);
+        if backup_path.exists() {
+            fs::rename(&backup_path, get_backup_path(file_path, generation + 1))?;
+        }
     }
+    fs::copy(file_path, get_backup_path(file_path, 1))?;
+
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests{
-    use super::JSONStorage;
+    use super::*;
+    use crate::functionality::tests::create_temporary_directory;
     use crate::ClearHeadApp;
 
     use std::path::Path;
@@ -59,7 +230,7 @@ mod tests{
 
         assert_eq!(
             file_contents,
-            "{\n  \"action_list\": [],\n  \"relationship_list\": []\n}"
+            "{\n  \"format_version\": 2,\n  \"action_list\": [],\n  \"relationship_list\": []\n}"
         );
     
 
@@ -75,4 +246,119 @@ mod tests{
         assert_eq!(app_from_file, test_app);
 
     }
+
+    #[test]
+    fn write_without_leaving_temporary_file() {
+        let file_path = create_temporary_directory("no_temporary_file").join("app.json");
+
+        ClearHeadApp::default().write_to_json(&file_path, false).unwrap();
+
+        assert!(file_path.exists());
+        assert!(!file_path.with_file_name("app.json.tmp").exists());
+        assert!(list_backups(&file_path).is_empty());
+    }
+
+    #[test]
+    fn keep_rolling_backups() {
+        let file_path = create_temporary_directory("rolling_backups").join("app.json");
+        let mut test_app = ClearHeadApp::default();
+
+        for _ in 0..4 {
+            test_app = test_app.append_default_action();
+            test_app.write_to_json_with_backups(&file_path, false, 2).unwrap();
+        }
+
+        assert_eq!(list_backups(&file_path), vec![get_backup_path(&file_path, 1), get_backup_path(&file_path, 2)]);
+        assert_eq!(ClearHeadApp::read_from_json(&file_path).unwrap().action_list.len(), 4);
+        assert_eq!(ClearHeadApp::read_from_json(&get_backup_path(&file_path, 1)).unwrap().action_list.len(), 3);
+        assert_eq!(ClearHeadApp::read_from_json(&get_backup_path(&file_path, 2)).unwrap().action_list.len(), 2);
+    }
+
+    #[test]
+    fn failed_write_keeps_existing_file() {
+        let file_path = create_temporary_directory("failed_write").join("app.json");
+        let test_app = ClearHeadApp::default().append_default_action();
+        test_app.write_to_json(&file_path, false).unwrap();
+
+        let write_error = write_atomically(&file_path, 1, |_| Err("disk full".into())).unwrap_err();
+
+        assert_eq!(write_error.to_string(), "disk full");
+        assert_eq!(ClearHeadApp::read_from_json(&file_path).unwrap(), test_app);
+        assert!(!file_path.with_file_name("app.json.tmp").exists());
+        assert!(list_backups(&file_path).is_empty());
+    }
+
+    #[test]
+    fn restore_previous_backup() {
+        let file_path = create_temporary_directory("restore_backup").join("app.json");
+        let original_app = ClearHeadApp::default().append_default_action();
+        original_app.write_to_json_with_backups(&file_path, true, 3).unwrap();
+        let changed_app = original_app.append_default_action();
+        changed_app.write_to_json_with_backups(&file_path, true, 3).unwrap();
+
+        let restored_app = ClearHeadApp::restore_backup(&file_path, 1, 3).unwrap();
+
+        assert_eq!(restored_app, original_app);
+        assert_eq!(ClearHeadApp::read_from_json(&file_path).unwrap(), original_app);
+        assert_eq!(ClearHeadApp::read_from_json(&get_backup_path(&file_path, 1)).unwrap(), changed_app);
+    }
+
+    #[test]
+    fn restore_keeps_configured_backup_count() {
+        let file_path = create_temporary_directory("restore_backup_count").join("app.json");
+        let mut test_app = ClearHeadApp::default();
+        for _ in 0..3 {
+            test_app = test_app.append_default_action();
+            test_app.write_to_json_with_backups(&file_path, true, 2).unwrap();
+        }
+
+        let restored_app = ClearHeadApp::restore_backup(&file_path, 2, 2).unwrap();
+
+        assert_eq!(restored_app.action_list.len(), 1);
+        assert_eq!(list_backups(&file_path).len(), 2);
+        assert_eq!(ClearHeadApp::read_from_json(&get_backup_path(&file_path, 1)).unwrap(), test_app);
+    }
+
+    #[test]
+    fn failed_restore_missing_backup() {
+        let file_path = create_temporary_directory("missing_backup").join("app.json");
+        ClearHeadApp::default().write_to_json(&file_path, false).unwrap();
+
+        let restore_error = ClearHeadApp::restore_backup(&file_path, 2, 3).unwrap_err();
+
+        assert_eq!(restore_error.to_string(), format!("No Backup 2 for {}", file_path.display()));
+    }
+
+    #[test]
+    fn increment_revision_on_checked_write() {
+        let file_path = create_temporary_directory("increment_revision").join("app.json");
+        let test_app = ClearHeadApp::default();
+
+        let saved_app = test_app.write_to_json_if_unchanged(&file_path, false, 0).unwrap();
+        let resaved_app = saved_app.append_default_action().write_to_json_if_unchanged(&file_path, false, 0).unwrap();
+
+        assert_eq!(saved_app.revision, 1);
+        assert_eq!(resaved_app.revision, 2);
+        assert_eq!(ClearHeadApp::read_from_json(&file_path).unwrap(), resaved_app);
+    }
+
+    #[test]
+    fn failed_checked_write_after_concurrent_change() {
+        let file_path = create_temporary_directory("revision_conflict").join("app.json");
+        ClearHeadApp::default().write_to_json_if_unchanged(&file_path, false, 0).unwrap();
+        let first_reader = ClearHeadApp::read_from_json(&file_path).unwrap();
+        let second_reader = ClearHeadApp::read_from_json(&file_path).unwrap();
+
+        first_reader.append_default_action().write_to_json_if_unchanged(&file_path, false, 0).unwrap();
+        let conflict_error = second_reader
+            .append_default_action()
+            .write_to_json_if_unchanged(&file_path, false, 0)
+            .unwrap_err();
+
+        assert_eq!(
+            conflict_error.to_string(),
+            "Data file changed while this command ran (expected revision 1, found 2), please retry"
+        );
+        assert_eq!(ClearHeadApp::read_from_json(&file_path).unwrap().action_list.len(), 1);
+    }
 }
@@ -0,0 +1,407 @@
+use crate::functionality::ClearHeadApp;
+use crate::relationship::{Relationship, RelationshipVariant};
+use crate::ActionManipulation;
+
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+
+use im::Vector;
+use uuid::Uuid;
+
+impl ClearHeadApp {
+    pub fn get_subtree_ids(&self, root_index: usize) -> Result<HashSet<Uuid>, Box<dyn Error>> {
+        let root_id = self.get_action_id(root_index)?;
+        let mut subtree_ids: HashSet<Uuid> = self.get_descendant_ids(root_id).into_iter().collect();
+        subtree_ids.insert(root_id);
+
+        Ok(subtree_ids)
+    }
+
+    pub fn move_subtree(
+        &self,
+        root_index: usize,
+        new_parent_index: Option<usize>,
+    ) -> Result<ClearHeadApp, Box<dyn Error>> {
+        let root_id = self.get_action_id(root_index)?;
+        let subtree_ids = self.get_subtree_ids(root_index)?;
+        let new_parent_id = match new_parent_index {
+            Some(index) => Some(self.get_action_id(index)?),
+            None => None,
+        };
+
+        if let Some(parent_id) = new_parent_id {
+            if subtree_ids.contains(&parent_id) {
+                return Err(format!(
+                    "{} cannot be moved under its own subtree",
+                    self.get_action_name(root_index)?
+                )
+                .into());
+            }
+        }
+
+        let mut updated_app = self.clone();
+        updated_app.relationship_list = self
+            .relationship_list
+            .iter()
+            .filter(|relationship| {
+                !(relationship.get_variant() == RelationshipVariant::create_parental()
+                    && relationship.get_participant_2() == root_id)
+            })
+            .cloned()
+            .collect();
+
+        if let Some(parent_id) = new_parent_id {
+            updated_app
+                .relationship_list
+                .push_back(Relationship::create_new_parental(parent_id, root_id));
+        }
+
+        self.enforce_constraints(updated_app)
+    }
+
+    pub fn copy_subtree(
+        &self,
+        root_index: usize,
+        new_parent_index: Option<usize>,
+    ) -> Result<ClearHeadApp, Box<dyn Error>> {
+        let root_id = self.get_action_id(root_index)?;
+        let subtree_ids = self.get_subtree_ids(root_index)?;
+        let new_parent_id = match new_parent_index {
+            Some(index) => Some(self.get_action_id(index)?),
+            None => None,
+        };
+
+        let mut updated_app = self.clone();
+        let mut copied_ids: HashMap<Uuid, Uuid> = HashMap::new();
+        for action in self
+            .action_list
+            .iter()
+            .filter(|action| subtree_ids.contains(&action.get_id()))
+        {
+            let copied_action = action.create_copy();
+            copied_ids.insert(action.get_id(), copied_action.get_id());
+            updated_app.action_list.push_back(copied_action);
+        }
+
+        for relationship in self.relationship_list.iter() {
+            let copied_participant_1 = copied_ids.get(&relationship.get_participant_1());
+            let copied_participant_2 = copied_ids.get(&relationship.get_participant_2());
+
+            match (copied_participant_1, copied_participant_2) {
+                (Some(participant_1), Some(participant_2)) => updated_app
+                    .relationship_list
+                    .push_back(relationship.create_copy(*participant_1, *participant_2)),
+                (None, Some(participant_2))
+                    if new_parent_id.is_none()
+                        && relationship.get_participant_2() == root_id
+                        && relationship.get_variant() == RelationshipVariant::create_parental() =>
+                {
+                    updated_app.relationship_list.push_back(
+                        relationship.create_copy(relationship.get_participant_1(), *participant_2),
+                    )
+                }
+                _ => (),
+            }
+        }
+
+        if let Some(parent_id) = new_parent_id {
+            updated_app
+                .relationship_list
+                .push_back(Relationship::create_new_parental(parent_id, copied_ids[&root_id]));
+        }
+
+        self.enforce_constraints(updated_app)
+    }
+
+    pub fn remove_subtree(&self, root_index: usize) -> Result<ClearHeadApp, Box<dyn Error>> {
+        let subtree_ids = self.get_subtree_ids(root_index)?;
+
+        let mut updated_app = self.clone();
+        updated_app.action_list = self
+            .action_list
+            .iter()
+            .filter(|action| !subtree_ids.contains(&action.get_id()))
+            .cloned()
+            .collect::<Vector<_>>();
+        updated_app.relationship_list = self
+            .relationship_list
+            .iter()
+            .filter(|relationship| {
+                !subtree_ids.contains(&relationship.get_participant_1())
+                    && !subtree_ids.contains(&relationship.get_participant_2())
+            })
+            .cloned()
+            .collect();
+
+        Ok(updated_app)
+    }
+
+    pub fn move_subtree_to_app(
+        &self,
+        root_index: usize,
+        target_app: &ClearHeadApp,
+        new_parent_index: Option<usize>,
+    ) -> Result<(ClearHeadApp, ClearHeadApp), Box<dyn Error>> {
+        let root_id = self.get_action_id(root_index)?;
+        let subtree_ids = self.get_subtree_ids(root_index)?;
+        let new_parent_id = match new_parent_index {
+            Some(index) => Some(target_app.get_action_id(index)?),
+            None => None,
+        };
+
+        if let Some(duplicate_id) = subtree_ids
+            .iter()
+            .find(|id| target_app.select_action_by_id(**id).is_ok())
+        {
+            return Err(format!("an Action with Id {} already exists in the target", duplicate_id).into());
+        }
+
+        let mut updated_target = target_app.clone();
+        updated_target.action_list.append(
+            self.action_list
+                .iter()
+                .filter(|action| subtree_ids.contains(&action.get_id()))
+                .cloned()
+                .collect(),
+        );
+
+        for relationship in self.relationship_list.iter().filter(|relationship| {
+            subtree_ids.contains(&relationship.get_participant_1())
+                && subtree_ids.contains(&relationship.get_participant_2())
+        }) {
+            if let RelationshipVariant::Custom(name, _) = relationship.get_variant() {
+                if let Some(custom_variant) = self
+                    .custom_variants
+                    .iter()
+                    .find(|custom_variant| custom_variant.get_name() == name)
+                    .filter(|custom_variant| !updated_target.custom_variants.contains(custom_variant))
+                {
+                    updated_target = updated_target.register_custom_variant(
+                        &custom_variant.get_name(),
+                        &custom_variant.get_directionality().to_string(),
+                        Some(&custom_variant.get_inverse_label()),
+                        custom_variant.get_aliases(),
+                    )?;
+                }
+            }
+            updated_target.relationship_list.push_back(relationship.clone());
+        }
+
+        if let Some(parent_id) = new_parent_id {
+            updated_target
+                .relationship_list
+                .push_back(Relationship::create_new_parental(parent_id, root_id));
+        }
+
+        Ok((
+            self.remove_subtree(root_index)?,
+            target_app.enforce_constraints(updated_target)?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::functionality::tests::failed_action_index_error;
+    use crate::RelationshipListManagement;
+
+    fn create_app_with_project_tree() -> ClearHeadApp {
+        let app = ClearHeadApp::default()
+            .append_default_action()
+            .rename_action(0, "Project".to_string())
+            .unwrap()
+            .append_default_action()
+            .rename_action(1, "Design".to_string())
+            .unwrap()
+            .append_default_action()
+            .rename_action(2, "Sketch".to_string())
+            .unwrap()
+            .append_default_action()
+            .rename_action(3, "Inbox".to_string())
+            .unwrap();
+
+        app.create_action_relationship("parental", 0, 1)
+            .unwrap()
+            .create_action_relationship("parental", 1, 2)
+            .unwrap()
+            .create_action_relationship("sequential", 2, 3)
+            .unwrap()
+    }
+
+    #[test]
+    fn collect_subtree_ids() {
+        let test_app = create_app_with_project_tree();
+
+        let subtree_ids = test_app.get_subtree_ids(1).unwrap();
+
+        assert_eq!(
+            subtree_ids,
+            HashSet::from([test_app.action_list[1].get_id(), test_app.action_list[2].get_id()])
+        );
+    }
+
+    #[test]
+    fn move_subtree_under_new_parent() {
+        let test_app = create_app_with_project_tree();
+
+        let moved_app = test_app.move_subtree(1, Some(3)).unwrap();
+
+        assert_eq!(
+            moved_app.get_descendant_ids(moved_app.action_list[3].get_id()),
+            Vector::from(vec![moved_app.action_list[1].get_id(), moved_app.action_list[2].get_id()])
+        );
+        assert!(moved_app.get_descendant_ids(moved_app.action_list[0].get_id()).is_empty());
+        assert_eq!(moved_app.relationship_list.len(), 3);
+    }
+
+    #[test]
+    fn move_subtree_to_top_level() {
+        let test_app = create_app_with_project_tree();
+
+        let moved_app = test_app.move_subtree(1, None).unwrap();
+
+        assert!(moved_app.get_descendant_ids(moved_app.action_list[0].get_id()).is_empty());
+        assert_eq!(moved_app.relationship_list.len(), 2);
+    }
+
+    #[test]
+    fn failed_move_subtree_into_itself() {
+        let test_app = create_app_with_project_tree();
+
+        let cycle_error = test_app.move_subtree(0, Some(2)).unwrap_err();
+
+        assert_eq!(cycle_error.to_string(), "Project cannot be moved under its own subtree");
+    }
+
+    #[test]
+    fn copy_subtree_with_remapped_edges() {
+        let test_app = create_app_with_project_tree();
+
+        let copied_app = test_app.copy_subtree(1, None).unwrap();
+
+        assert_eq!(copied_app.action_list.len(), 6);
+        assert_eq!(copied_app.get_action_name(4).unwrap(), "Design");
+        assert_eq!(copied_app.get_action_name(5).unwrap(), "Sketch");
+        assert_ne!(copied_app.get_action_id(4).unwrap(), test_app.get_action_id(1).unwrap());
+        assert_eq!(
+            copied_app.get_descendant_ids(copied_app.get_action_id(4).unwrap()),
+            Vector::unit(copied_app.get_action_id(5).unwrap())
+        );
+        assert_eq!(
+            copied_app.get_descendant_ids(copied_app.get_action_id(0).unwrap()).len(),
+            4
+        );
+        assert_eq!(copied_app.relationship_list.len(), 5);
+    }
+
+    #[test]
+    fn copy_subtree_under_new_parent() {
+        let test_app = create_app_with_project_tree();
+
+        let copied_app = test_app.copy_subtree(1, Some(3)).unwrap();
+
+        assert_eq!(
+            copied_app.get_relationship_participant_1(4).unwrap(),
+            copied_app.get_action_id(3).unwrap()
+        );
+        assert_eq!(
+            copied_app.get_relationship_participant_2(4).unwrap(),
+            copied_app.get_action_id(4).unwrap()
+        );
+        assert_eq!(copied_app.get_descendant_ids(copied_app.get_action_id(0).unwrap()).len(), 2);
+    }
+
+    #[test]
+    fn remove_subtree_with_edges() {
+        let test_app = create_app_with_project_tree();
+
+        let pruned_app = test_app.remove_subtree(1).unwrap();
+
+        assert_eq!(pruned_app.action_list.len(), 2);
+        assert_eq!(pruned_app.get_action_name(1).unwrap(), "Inbox");
+        assert!(pruned_app.relationship_list.is_empty());
+    }
+
+    #[test]
+    fn move_subtree_to_other_app() {
+        let test_app = create_app_with_project_tree();
+        let target_app = ClearHeadApp::default().append_default_action();
+
+        let (source_app, updated_target) = test_app.move_subtree_to_app(1, &target_app, Some(0)).unwrap();
+
+        assert_eq!(source_app.action_list.len(), 2);
+        assert!(source_app.relationship_list.is_empty());
+        assert_eq!(updated_target.action_list.len(), 3);
+        assert_eq!(updated_target.get_action_name(1).unwrap(), "Design");
+        assert_eq!(
+            updated_target.get_descendant_ids(updated_target.get_action_id(0).unwrap()),
+            im::vector![test_app.action_list[1].get_id(), test_app.action_list[2].get_id()]
+        );
+        assert_eq!(updated_target.relationship_list.len(), 2);
+    }
+
+    #[test]
+    fn move_subtree_with_custom_variant() {
+        let test_app = create_app_with_project_tree()
+            .register_custom_variant("blocks", "directed", None, Vector::new())
+            .unwrap()
+            .create_action_relationship("blocks", 2, 1)
+            .unwrap();
+
+        let (_, updated_target) = test_app.move_subtree_to_app(1, &ClearHeadApp::default(), None).unwrap();
+
+        assert_eq!(updated_target.custom_variants, test_app.custom_variants);
+        assert_eq!(updated_target.relationship_list.len(), 2);
+    }
+
+    #[test]
+    fn move_subtree_with_custom_variant_already_in_target() {
+        let target_app = ClearHeadApp::default()
+            .register_custom_variant("blocks", "directed", None, Vector::new())
+            .unwrap();
+        let test_app = create_app_with_project_tree()
+            .register_custom_variant("blocks", "directed", None, Vector::new())
+            .unwrap()
+            .create_action_relationship("blocks", 2, 1)
+            .unwrap();
+
+        let (_, updated_target) = test_app.move_subtree_to_app(1, &target_app, None).unwrap();
+
+        assert_eq!(updated_target.custom_variants, target_app.custom_variants);
+    }
+
+    #[test]
+    fn failed_move_subtree_with_conflicting_custom_variant() {
+        let target_app = ClearHeadApp::default()
+            .register_custom_variant("blocks", "undirected", None, Vector::new())
+            .unwrap();
+        let test_app = create_app_with_project_tree()
+            .register_custom_variant("blocks", "directed", None, Vector::new())
+            .unwrap()
+            .create_action_relationship("blocks", 2, 1)
+            .unwrap();
+
+        let move_error = test_app.move_subtree_to_app(1, &target_app, None).unwrap_err();
+
+        assert_eq!(move_error.to_string(), "blocks is already a relationship variant");
+    }
+
+    #[test]
+    fn failed_move_subtree_into_same_app() {
+        let test_app = create_app_with_project_tree();
+
+        let move_error = test_app.move_subtree_to_app(1, &test_app, None).unwrap_err();
+
+        assert!(move_error.to_string().ends_with("already exists in the target"));
+    }
+
+    #[test]
+    fn failed_subtree_operation_invalid_index() {
+        let test_app = create_app_with_project_tree();
+
+        let index_error = test_app.remove_subtree(7).unwrap_err();
+
+        assert_eq!(index_error.to_string(), failed_action_index_error(7));
+    }
+}
@@ -1,6 +1,7 @@
 use tabled::Table;
 use uuid::Uuid;
 use im::Vector;
+use chrono::NaiveDate;
 
 use indoc::indoc;
 
@@ -88,6 +89,8 @@ fn action_print_successful_test() {
         priority: Optional,
         completed: false,
         id: {},
+        estimate: None,
+        due_date: None,
     }},
 ]",action_list[0].get_id().simple()));
     }
@@ -219,3 +222,40 @@ fn get_completion_status(){
 
     assert_eq!(completion_status, false);
 }
+
+#[test]
+fn action_estimate() {
+    let single_action_list = create_single_action_list();
+
+    let estimated_list = single_action_list.change_action_estimate(0, Some(3)).unwrap();
+
+    assert_eq!(estimated_list.get_action_estimate(0).unwrap(), Some(3));
+}
+
+#[test]
+fn failed_action_estimate() {
+    let empty_list = Vector::new();
+
+    let index_error = empty_list.change_action_estimate(0, Some(3)).unwrap_err();
+
+    assert_eq!(index_error.to_string(), invalid_index_error_string(0));
+}
+
+#[test]
+fn action_due_date() {
+    let single_action_list = create_single_action_list();
+    let due_date = NaiveDate::from_ymd_opt(2022, 10, 1).unwrap();
+
+    let scheduled_list = single_action_list.change_action_due_date(0, Some(due_date)).unwrap();
+
+    assert_eq!(scheduled_list.get_action_due_date(0).unwrap(), Some(due_date));
+}
+
+#[test]
+fn failed_action_due_date() {
+    let empty_list = Vector::new();
+
+    let index_error = empty_list.change_action_due_date(0, None).unwrap_err();
+
+    assert_eq!(index_error.to_string(), invalid_index_error_string(0));
+}
@@ -1,5 +1,6 @@
 use clear_head_todo_core::relationship::item::edge_direction::EdgeDirectionality;
 
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 use im::Vector;
 use clear_head_todo_core::relationship::item::Relationship;
@@ -85,7 +86,7 @@ fn failed_create_from_string() {
 fn add_related_to_list() {
     let relationship_list: Vector<Relationship> = Vector::new();
 
-    let modified_list = relationship_list.append_related_relationship(Uuid::nil(), Uuid::nil());
+    let modified_list = relationship_list.append_related_relationship(Uuid::nil(), Uuid::nil()).unwrap();
 
     assert_eq!(modified_list[0].get_variant() , RelationshipVariant::create_related());
 }
@@ -94,7 +95,7 @@ fn add_related_to_list() {
 fn add_sequential_to_list() {
     let relationship_list: Vector<Relationship> = Vector::new();
 
-    let modified_list = relationship_list.append_sequential_relationship(Uuid::nil(), Uuid::nil());
+    let modified_list = relationship_list.append_sequential_relationship(Uuid::nil(), Uuid::nil()).unwrap();
 
     assert_eq!(modified_list[0].get_variant(),RelationshipVariant::create_sequential());
 }
@@ -103,7 +104,7 @@ fn add_sequential_to_list() {
 fn add_parental_to_list() {
     let relationship_list: Vector<Relationship> = Vector::new();
 
-    let modified_list = relationship_list.append_parental_relationship(Uuid::nil(), Uuid::nil());
+    let modified_list = relationship_list.append_parental_relationship(Uuid::nil(), Uuid::nil()).unwrap();
 
     assert_eq!(modified_list[0].get_variant() , RelationshipVariant::create_parental());
 }
@@ -208,7 +209,7 @@ fn failed_get_participant_1() {
 #[test]
 fn successfully_get_participant_2() {
     let test_list: Vector<Relationship> = Vector::new();
-    let single_relationship_list = test_list.append_parental_relationship(Uuid::nil(), Uuid::nil());
+    let single_relationship_list = test_list.append_parental_relationship(Uuid::nil(), Uuid::nil()).unwrap();
 
     let participant_2 = single_relationship_list
         .get_relationship_participant_2(0)
@@ -229,7 +230,7 @@ fn failed_get_participant_2() {
 #[test]
 fn successfully_remove_from_id() {
     let relationship_list: Vector<Relationship> = Vector::new();
-    let single_relationship_list = relationship_list.append_related_relationship(Uuid::nil(), Uuid::nil());
+    let single_relationship_list = relationship_list.append_related_relationship(Uuid::nil(), Uuid::nil()).unwrap();
 
     let empty_list = single_relationship_list
         .remove_with_id(single_relationship_list[0].get_id())
@@ -250,7 +251,7 @@ fn failed_remove_from_id() {
 #[test]
 fn change_related_to_parental() {
     let relationship_list: Vector<Relationship> = Vector::new();
-    let test_list = relationship_list.append_related_relationship(Uuid::nil(), Uuid::nil());
+    let test_list = relationship_list.append_related_relationship(Uuid::nil(), Uuid::nil()).unwrap();
 
     let updated_list = test_list.change_relationship_variant(0, "parental").unwrap();
 
@@ -263,7 +264,7 @@ fn change_related_to_parental() {
 #[test]
 fn change_parental_to_related() {
     let relationship_list: Vector<Relationship> = Vector::new();
-    let test_list = relationship_list.append_parental_relationship(Uuid::nil(), Uuid::nil());
+    let test_list = relationship_list.append_parental_relationship(Uuid::nil(), Uuid::nil()).unwrap();
 
     let updated_list = test_list.change_relationship_variant(0, "related").unwrap();
 
@@ -276,7 +277,7 @@ fn change_parental_to_related() {
 #[test]
 fn failed_change_variant() {
     let relationship_list: Vector<Relationship> = Vector::new();
-    let test_list = relationship_list.append_related_relationship(Uuid::nil(), Uuid::nil());
+    let test_list = relationship_list.append_related_relationship(Uuid::nil(), Uuid::nil()).unwrap();
 
     let failed_output = test_list.change_relationship_variant(0, "bad variant").unwrap_err();
 
@@ -286,7 +287,7 @@ fn failed_change_variant() {
 #[test]
 fn update_participant_1_id() {
     let relationship_list: Vector<Relationship> = Vector::new();
-    let test_list = relationship_list.append_related_relationship(Uuid::nil(), Uuid::nil());
+    let test_list = relationship_list.append_related_relationship(Uuid::nil(), Uuid::nil()).unwrap();
 
     let updated_list = test_list
         .update_relationship_participant_1(0, Uuid::new_v4())
@@ -309,7 +310,7 @@ fn failed_id_update_participant_1() {
 #[test]
 fn update_participant_2_id() {
     let relationship_list: Vector<Relationship> = Vector::new();
-    let test_list = relationship_list.append_related_relationship(Uuid::nil(), Uuid::nil());
+    let test_list = relationship_list.append_related_relationship(Uuid::nil(), Uuid::nil()).unwrap();
 
     let updated_list = test_list
         .update_relationship_participant_2(0, Uuid::new_v4())
@@ -448,8 +449,8 @@ fn get_either_participant_list_only_2_for_id() {
 #[test]
 fn get_either_participant_list_for_id_one_in_each(){
     let empty_list: Vector<Relationship> = Vector::new();
-    let single_relationship_list = empty_list.append_related_relationship(Uuid::new_v4(), Uuid::nil());
-    let double_relationship_list = single_relationship_list.append_related_relationship(Uuid::nil(), Uuid::new_v4());
+    let single_relationship_list = empty_list.append_related_relationship(Uuid::new_v4(), Uuid::nil()).unwrap();
+    let double_relationship_list = single_relationship_list.append_related_relationship(Uuid::nil(), Uuid::new_v4()).unwrap();
 
     let query_result = double_relationship_list
         .get_either_participant_list_for_id(Uuid::nil()).unwrap();
@@ -481,7 +482,7 @@ fn get_children_for_id() {
 #[test]
 fn get_parental_relationship_list(){
     let single_relationship_list = create_relationship_list_with_single_relationship("parental");
-    let double_relationship_list = single_relationship_list.append_related_relationship(Uuid::nil(), Uuid::nil());
+    let double_relationship_list = single_relationship_list.append_related_relationship(Uuid::nil(), Uuid::nil()).unwrap();
 
     let query_result = double_relationship_list
         .filter_by_variant("parental").unwrap();
@@ -492,7 +493,7 @@ fn get_parental_relationship_list(){
 #[test]
 fn get_sequential_relationship_list(){
     let single_relationship_list = create_relationship_list_with_single_relationship("sequential");
-    let double_relationship_list = single_relationship_list.append_related_relationship(Uuid::nil(), Uuid::nil());
+    let double_relationship_list = single_relationship_list.append_related_relationship(Uuid::nil(), Uuid::nil()).unwrap();
 
     let query_result = double_relationship_list
         .filter_by_variant("sequential").unwrap();
@@ -503,7 +504,7 @@ fn get_sequential_relationship_list(){
 #[test]
 fn get_related_relationship_list(){
     let single_relationship_list = create_relationship_list_with_single_relationship("related");
-    let double_relationship_list = single_relationship_list.append_parental_relationship(Uuid::nil(), Uuid::nil());
+    let double_relationship_list = single_relationship_list.append_parental_relationship(Uuid::nil(), Uuid::nil()).unwrap();
 
     let query_result = double_relationship_list
         .filter_by_variant("related").unwrap();
@@ -514,7 +515,7 @@ fn get_related_relationship_list(){
 #[test]
 fn failed_filter_relationship_list_test(){
     let single_relationship_list = create_relationship_list_with_single_relationship("related");
-    let double_relationship_list = single_relationship_list.append_parental_relationship(Uuid::nil(), Uuid::nil());
+    let double_relationship_list = single_relationship_list.append_parental_relationship(Uuid::nil(), Uuid::nil()).unwrap();
 
     let query_result = double_relationship_list
         .filter_by_variant("bad variant").unwrap_err();
@@ -526,7 +527,8 @@ fn failed_filter_relationship_list_test(){
 fn filter_by_participant_1_list(){
     let single_relationship_list = create_relationship_list_with_single_related_relationship();
     let double_relationship_list = single_relationship_list
-        .append_related_relationship(Uuid::new_v4(), Uuid::nil());
+        .append_related_relationship(Uuid::new_v4(), Uuid::nil())
+        .unwrap();
 
     let query_result = double_relationship_list
         .filter_by_participants("p1".to_string(), Uuid::nil()).unwrap();
@@ -538,7 +540,8 @@ fn filter_by_participant_1_list(){
 fn filter_by_participant_2_list(){
     let single_relationship_list = create_relationship_list_with_single_related_relationship();
     let double_relationship_list = single_relationship_list
-        .append_related_relationship(Uuid::nil(), Uuid::new_v4());
+        .append_related_relationship(Uuid::nil(), Uuid::new_v4())
+        .unwrap();
 
     let query_result = double_relationship_list
         .filter_by_participants("p2".to_string(), Uuid::nil()).unwrap();
@@ -550,7 +553,8 @@ fn filter_by_participant_2_list(){
 fn filter_by_either_participant_list(){
     let single_relationship_list = create_relationship_list_with_single_related_relationship();
     let double_relationship_list = single_relationship_list
-        .append_related_relationship(Uuid::nil(), Uuid::new_v4());
+        .append_related_relationship(Uuid::nil(), Uuid::new_v4())
+        .unwrap();
 
     let query_result = double_relationship_list
         .filter_by_participants("3".to_string(), Uuid::nil()).unwrap();
@@ -562,7 +566,8 @@ fn filter_by_either_participant_list(){
 fn failed_filter_by_participant_list_bad_list(){
     let single_relationship_list = create_relationship_list_with_single_related_relationship();
     let double_relationship_list = single_relationship_list
-        .append_related_relationship(Uuid::nil(), Uuid::new_v4());
+        .append_related_relationship(Uuid::nil(), Uuid::new_v4())
+        .unwrap();
 
     let query_result = double_relationship_list
         .filter_by_participants("bad".to_string(), Uuid::nil()).unwrap_err();
@@ -583,15 +588,57 @@ fn empty_filter_by_participant_list_no_ip(){
 #[test]
 fn print_relationship_table() {
     let single_relationship_list = 
-    create_relationship_list_with_single_related_relationship();
+    create_relationship_list_with_single_related_relationship()
+        .set_relationship_created(0, None).unwrap();
+
+    let query_result = single_relationship_list.get_relationship_list_as_table();
+
+    assert_eq!(query_result, indoc!("
+        +---------------------+--------------------------------------+--------------------------------------+-------+--------+---------+
+        | Variant             | Participant 1                        | Participant 2                        | Label | Weight | Created |
+        +---------------------+--------------------------------------+--------------------------------------+-------+--------+---------+
+        | Related: Undirected | 00000000-0000-0000-0000-000000000000 | 00000000-0000-0000-0000-000000000000 |       |        |         |
+        +---------------------+--------------------------------------+--------------------------------------+-------+--------+---------+"));
+}
+
+#[test]
+fn print_relationship_table_with_metadata() {
+    let created = DateTime::parse_from_rfc3339("2022-08-01T12:30:00Z").unwrap().with_timezone(&Utc);
+    let single_relationship_list =
+    create_relationship_list_with_single_related_relationship()
+        .set_relationship_label(0, Some("same client")).unwrap()
+        .set_relationship_weight(0, Some(1.5)).unwrap()
+        .set_relationship_created(0, Some(created)).unwrap();
 
     let query_result = single_relationship_list.get_relationship_list_as_table();
 
     assert_eq!(query_result, indoc!("
-        +---------------------+--------------------------------------+--------------------------------------+
-        | Variant             | Participant 1                        | Participant 2                        |
-        +---------------------+--------------------------------------+--------------------------------------+
-        | Related: Undirected | 00000000-0000-0000-0000-000000000000 | 00000000-0000-0000-0000-000000000000 |
-        +---------------------+--------------------------------------+--------------------------------------+"));
+        +---------------------+--------------------------------------+--------------------------------------+-------------+--------+---------------------+
+        | Variant             | Participant 1                        | Participant 2                        | Label       | Weight | Created             |
+        +---------------------+--------------------------------------+--------------------------------------+-------------+--------+---------------------+
+        | Related: Undirected | 00000000-0000-0000-0000-000000000000 | 00000000-0000-0000-0000-000000000000 | same client | 1.5    | 2022-08-01 12:30:00 |
+        +---------------------+--------------------------------------+--------------------------------------+-------------+--------+---------------------+"));
+}
+
+#[test]
+fn set_relationship_metadata() {
+    let single_relationship_list = create_relationship_list_with_single_related_relationship();
+
+    let updated_list = single_relationship_list
+        .set_relationship_label(0, Some("same client")).unwrap()
+        .set_relationship_weight(0, Some(2.0)).unwrap();
+
+    assert_eq!(updated_list.get_relationship_label(0).unwrap(), Some("same client".to_string()));
+    assert_eq!(updated_list.get_relationship_weight(0).unwrap(), Some(2.0));
+    assert!(updated_list.get_relationship_created(0).unwrap().is_some());
+}
+
+#[test]
+fn failed_set_relationship_metadata() {
+    let empty_list: Vector<Relationship> = Vector::new();
+
+    let index_error = empty_list.set_relationship_label(0, Some("label")).unwrap_err();
+
+    assert_eq!(index_error.to_string(), invalid_index_error_string());
 }
 